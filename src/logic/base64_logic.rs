@@ -1,26 +1,513 @@
-use base64::{Engine as _, engine::general_purpose};
+use base64::{Engine as _, engine::{general_purpose, GeneralPurpose}, read::DecoderReader, write::EncoderWriter};
+use percent_encoding::percent_decode_str;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
 #[derive(Debug, PartialEq)]
 pub enum Base64Error {
     InvalidBase64,
-    InvalidUtf8,
+    InvalidDataUri,
+    Io(String),
+    Cancelled,
+}
+
+/// Alphabet, padding and line wrapping combinations in common use
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Base64Variant {
+    #[default]
+    Standard,
+    StandardNoPad,
+    UrlSafe,
+    UrlSafeNoPad,
+    Mime,
+}
+
+impl std::fmt::Display for Base64Variant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Base64Variant::Standard => write!(f, "Standard"),
+            Base64Variant::StandardNoPad => write!(f, "Standard (no padding)"),
+            Base64Variant::UrlSafe => write!(f, "URL-safe"),
+            Base64Variant::UrlSafeNoPad => write!(f, "URL-safe (no padding)"),
+            Base64Variant::Mime => write!(f, "MIME (76 columns)"),
+        }
+    }
+}
+
+impl Base64Variant {
+    pub const ALL: [Base64Variant; 5] = [
+        Base64Variant::Standard,
+        Base64Variant::StandardNoPad,
+        Base64Variant::UrlSafe,
+        Base64Variant::UrlSafeNoPad,
+        Base64Variant::Mime,
+    ];
+
+    fn engine(self) -> &'static GeneralPurpose {
+        match self {
+            Base64Variant::Standard | Base64Variant::Mime => &general_purpose::STANDARD,
+            Base64Variant::StandardNoPad => &general_purpose::STANDARD_NO_PAD,
+            Base64Variant::UrlSafe => &general_purpose::URL_SAFE,
+            Base64Variant::UrlSafeNoPad => &general_purpose::URL_SAFE_NO_PAD,
+        }
+    }
+}
+
+/// Result of decoding without knowing the variant up front
+#[derive(Debug, PartialEq)]
+pub struct DetectedBase64 {
+    pub variant: Base64Variant,
+    pub bytes: Vec<u8>,
+}
+
+/// Contents of an RFC 2397 `data:` URI
+#[derive(Debug, PartialEq)]
+pub struct DataUri {
+    /// Media type without parameters, lowercased
+    pub mime_type: String,
+    pub bytes: Vec<u8>,
+}
+
+/// Shared between a streaming job and the UI to report progress and request cancellation
+#[derive(Debug, Default)]
+pub struct StreamControl {
+    processed: AtomicU64,
+    cancelled: AtomicBool,
+}
+
+impl StreamControl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Input bytes consumed so far
+    pub fn processed(&self) -> u64 {
+        self.processed.load(Ordering::Relaxed)
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Counts consumed bytes and aborts the stream once cancellation is requested
+struct ProgressReader<'a, R> {
+    inner: R,
+    control: &'a StreamControl,
+}
+
+impl<R: Read> Read for ProgressReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.control.is_cancelled() {
+            return Err(io::Error::other("cancelled"));
+        }
+        let read = self.inner.read(buf)?;
+        self.control.processed.fetch_add(read as u64, Ordering::Relaxed);
+        Ok(read)
+    }
+}
+
+/// Drops ASCII whitespace so wrapped Base64 can be streamed into the decoder
+struct WhitespaceFilter<R> {
+    inner: R,
+}
+
+impl<R: Read> Read for WhitespaceFilter<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            let read = self.inner.read(buf)?;
+            if read == 0 {
+                return Ok(0);
+            }
+            let mut kept = 0;
+            for index in 0..read {
+                if !buf[index].is_ascii_whitespace() {
+                    buf[kept] = buf[index];
+                    kept += 1;
+                }
+            }
+            if kept > 0 {
+                return Ok(kept);
+            }
+        }
+    }
+}
+
+/// Inserts a CRLF after every `MIME_LINE_LENGTH` characters
+struct LineWrapWriter<W> {
+    inner: W,
+    column: usize,
+}
+
+impl<W: Write> Write for LineWrapWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut remaining = buf;
+        while !remaining.is_empty() {
+            if self.column == Base64Logic::MIME_LINE_LENGTH {
+                self.inner.write_all(b"\r\n")?;
+                self.column = 0;
+            }
+            let take = remaining.len().min(Base64Logic::MIME_LINE_LENGTH - self.column);
+            self.inner.write_all(&remaining[..take])?;
+            self.column += take;
+            remaining = &remaining[take..];
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// How decoded bytes should be presented
+#[derive(Debug, PartialEq)]
+pub enum DecodedContent {
+    Text(String),
+    Binary { mime_type: &'static str, hex_dump: String },
 }
 
 pub struct Base64Logic;
 
 impl Base64Logic {
-    /// Encode text to Base64
-    pub fn encode(input: &str) -> String {
-        general_purpose::STANDARD.encode(input.as_bytes())
+    /// Line length mandated by RFC 2045 for MIME bodies
+    pub const MIME_LINE_LENGTH: usize = 76;
+    /// Bytes shown in a hex dump before it is cut short
+    pub const HEX_DUMP_LIMIT: usize = 4096;
+    /// Magic byte prefixes and the MIME type they identify
+    const MAGIC_NUMBERS: [(&'static [u8], &'static str); 16] = [
+        (b"\x89PNG\r\n\x1a\n", "image/png"),
+        (b"\xff\xd8\xff", "image/jpeg"),
+        (b"GIF87a", "image/gif"),
+        (b"GIF89a", "image/gif"),
+        (b"BM", "image/bmp"),
+        (b"\x00\x00\x01\x00", "image/x-icon"),
+        (b"icns", "image/icns"),
+        (b"%PDF-", "application/pdf"),
+        (b"PK\x03\x04", "application/zip"),
+        (b"\x1f\x8b", "application/gzip"),
+        (b"7z\xbc\xaf\x27\x1c", "application/x-7z-compressed"),
+        (b"\x7fELF", "application/x-elf"),
+        (b"MZ", "application/vnd.microsoft.portable-executable"),
+        (b"\x00asm", "application/wasm"),
+        (b"OggS", "audio/ogg"),
+        (b"ID3", "audio/mpeg"),
+    ];
+
+    /// MIME types paired with their conventional file extension
+    const FILE_TYPES: [(&'static str, &'static str); 25] = [
+        ("image/png", "png"),
+        ("image/jpeg", "jpg"),
+        ("image/gif", "gif"),
+        ("image/bmp", "bmp"),
+        ("image/webp", "webp"),
+        ("image/x-icon", "ico"),
+        ("image/icns", "icns"),
+        ("image/svg+xml", "svg"),
+        ("application/pdf", "pdf"),
+        ("application/zip", "zip"),
+        ("application/gzip", "gz"),
+        ("application/x-7z-compressed", "7z"),
+        ("application/wasm", "wasm"),
+        ("application/pkix-cert", "der"),
+        ("application/x-pem-file", "pem"),
+        ("application/json", "json"),
+        ("application/xml", "xml"),
+        ("audio/ogg", "ogg"),
+        ("audio/mpeg", "mp3"),
+        ("audio/wav", "wav"),
+        ("text/plain", "txt"),
+        ("text/html", "html"),
+        ("text/css", "css"),
+        ("text/javascript", "js"),
+        ("text/csv", "csv"),
+    ];
+
+    /// Encode bytes to Base64 using the variant's alphabet, padding and wrapping
+    pub fn encode(input: &[u8], variant: Base64Variant) -> String {
+        let encoded = variant.engine().encode(input);
+        if variant != Base64Variant::Mime {
+            return encoded;
+        }
+
+        // Base64 output is ASCII, so splitting on byte boundaries is safe
+        encoded
+            .as_bytes()
+            .chunks(Self::MIME_LINE_LENGTH)
+            .map(|line| std::str::from_utf8(line).unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join("\r\n")
+    }
+
+    /// Decode Base64 to bytes, ignoring whitespace and line breaks
+    pub fn decode(input: &str, variant: Base64Variant) -> Result<Vec<u8>, Base64Error> {
+        let compact = Self::strip_whitespace(input);
+        variant.engine().decode(compact)
+            .map_err(|_| Base64Error::InvalidBase64)
+    }
+
+    /// Try each variant in turn and report the first that decodes
+    pub fn decode_auto(input: &str) -> Result<DetectedBase64, Base64Error> {
+        let compact = Self::strip_whitespace(input);
+        let wrapped = input.trim().contains('\n');
+
+        let candidates = [
+            Base64Variant::Standard,
+            Base64Variant::StandardNoPad,
+            Base64Variant::UrlSafe,
+            Base64Variant::UrlSafeNoPad,
+        ];
+        let (variant, bytes) = candidates
+            .into_iter()
+            .find_map(|variant| variant.engine().decode(&compact).ok().map(|decoded| (variant, decoded)))
+            .ok_or(Base64Error::InvalidBase64)?;
+
+        let variant = if wrapped && variant == Base64Variant::Standard { Base64Variant::Mime } else { variant };
+        Ok(DetectedBase64 { variant, bytes })
+    }
+
+    /// Show readable UTF-8 as text and anything else as a hex dump
+    pub fn inspect(bytes: &[u8]) -> DecodedContent {
+        match std::str::from_utf8(bytes) {
+            Ok(text) if !text.contains('\0') => DecodedContent::Text(text.to_string()),
+            _ => DecodedContent::Binary {
+                mime_type: Self::sniff_mime(bytes).unwrap_or("application/octet-stream"),
+                hex_dump: Self::hex_dump(bytes),
+            },
+        }
+    }
+
+    /// Identify common file formats from their leading bytes
+    pub fn sniff_mime(bytes: &[u8]) -> Option<&'static str> {
+        if bytes.len() >= 12 && &bytes[..4] == b"RIFF" {
+            return match &bytes[8..12] {
+                b"WEBP" => Some("image/webp"),
+                b"WAVE" => Some("audio/wav"),
+                _ => None,
+            };
+        }
+        // DER-encoded certificates and keys start with a SEQUENCE with a two-byte length
+        if bytes.starts_with(&[0x30, 0x82]) {
+            return Some("application/pkix-cert");
+        }
+        if let Some((_, mime_type)) = Self::MAGIC_NUMBERS.iter().find(|(magic, _)| bytes.starts_with(magic)) {
+            return Some(mime_type);
+        }
+
+        let head = String::from_utf8_lossy(&bytes[..bytes.len().min(512)]).to_lowercase();
+        let head = head.trim_start_matches('\u{feff}').trim_start();
+        if head.starts_with("<svg") || (head.starts_with("<?xml") && head.contains("<svg")) {
+            return Some("image/svg+xml");
+        }
+        if head.starts_with("-----begin ") {
+            return Some("application/x-pem-file");
+        }
+        None
+    }
+
+    /// File extension conventionally used for a MIME type
+    pub fn extension_for(mime_type: &str) -> &'static str {
+        Self::FILE_TYPES
+            .iter()
+            .find(|(known, _)| *known == mime_type)
+            .map(|(_, extension)| *extension)
+            .unwrap_or("bin")
+    }
+
+    /// MIME type for a file, from its contents first and its extension second
+    ///
+    /// Signatures shorter than four bytes, such as `BM` or `MZ`, also begin plenty of
+    /// text files, so they only decide the type when the extension is unknown.
+    pub fn mime_for_file(file_name: &str, bytes: &[u8]) -> &'static str {
+        let sniffed = Self::sniff_mime(bytes);
+        let weak = bytes.starts_with(&[0x30, 0x82])
+            || Self::MAGIC_NUMBERS.iter().any(|(magic, _)| magic.len() < 4 && bytes.starts_with(magic));
+        if let Some(mime_type) = sniffed.filter(|_| !weak) {
+            return mime_type;
+        }
+        let extension = file_name.rsplit_once('.').map(|(_, extension)| extension.to_lowercase());
+        Self::FILE_TYPES
+            .iter()
+            .find(|(_, known)| Some(*known) == extension.as_deref())
+            .map(|(mime_type, _)| *mime_type)
+            .or(sniffed)
+            .unwrap_or("application/octet-stream")
+    }
+
+    /// Build a `data:` URI; these always use the standard padded alphabet
+    pub fn data_uri(mime_type: &str, bytes: &[u8]) -> String {
+        format!("data:{};base64,{}", mime_type, Self::encode(bytes, Base64Variant::Standard))
+    }
+
+    /// Whether the input looks like a `data:` URI rather than bare Base64
+    pub fn is_data_uri(input: &str) -> bool {
+        input.trim_start().get(..5).is_some_and(|scheme| scheme.eq_ignore_ascii_case("data:"))
+    }
+
+    /// Parse a `data:` URI with either Base64 or percent-encoded contents
+    pub fn parse_data_uri(input: &str) -> Result<DataUri, Base64Error> {
+        let input = input.trim();
+        if !Self::is_data_uri(input) {
+            return Err(Base64Error::InvalidDataUri);
+        }
+        let (header, data) = input[5..].split_once(',').ok_or(Base64Error::InvalidDataUri)?;
+
+        let mut parameters = header.split(';');
+        let mime_type = parameters.next().unwrap_or_default().trim().to_lowercase();
+        let is_base64 = parameters.any(|parameter| parameter.trim().eq_ignore_ascii_case("base64"));
+
+        let bytes = if is_base64 {
+            // Some producers percent-encode the Base64 alphabet's '+', '/' and '='
+            let data = percent_decode_str(data).decode_utf8().map_err(|_| Base64Error::InvalidDataUri)?;
+            Self::decode(&data, Base64Variant::Standard)?
+        } else {
+            percent_decode_str(data).collect()
+        };
+
+        Ok(DataUri {
+            mime_type: if mime_type.is_empty() { "text/plain".to_string() } else { mime_type },
+            bytes,
+        })
+    }
+
+    /// Size of the buffer used when streaming between files
+    const STREAM_CHUNK_SIZE: usize = 64 * 1024;
+
+    /// Encode everything `reader` yields into `writer` without holding it in memory
+    pub fn encode_stream<R: Read, W: Write>(
+        reader: R,
+        writer: W,
+        variant: Base64Variant,
+        control: &StreamControl,
+    ) -> Result<(), Base64Error> {
+        let mut reader = ProgressReader { inner: reader, control };
+        let result = if variant == Base64Variant::Mime {
+            Self::copy_encoded(&mut reader, LineWrapWriter { inner: writer, column: 0 }, variant)
+        } else {
+            Self::copy_encoded(&mut reader, writer, variant)
+        };
+        result.map_err(|err| Self::stream_error(err, control))
+    }
+
+    /// Decode Base64 from `reader` into `writer`, skipping whitespace and line breaks
+    pub fn decode_stream<R: Read, W: Write>(
+        reader: R,
+        mut writer: W,
+        variant: Base64Variant,
+        control: &StreamControl,
+    ) -> Result<(), Base64Error> {
+        let filtered = WhitespaceFilter { inner: ProgressReader { inner: reader, control } };
+        let mut decoder = DecoderReader::new(filtered, variant.engine());
+        io::copy(&mut decoder, &mut writer)
+            .and_then(|_| writer.flush())
+            .map_err(|err| Self::stream_error(err, control))
+    }
+
+    /// Stream-encode one file into another, removing the output if the job fails
+    pub fn encode_file(input: &Path, output: &Path, variant: Base64Variant, control: &StreamControl) -> Result<(), Base64Error> {
+        Self::stream_file(input, output, control, |reader, writer| Self::encode_stream(reader, writer, variant, control))
+    }
+
+    /// Stream-decode one file into another, removing the output if the job fails
+    pub fn decode_file(input: &Path, output: &Path, variant: Base64Variant, control: &StreamControl) -> Result<(), Base64Error> {
+        Self::stream_file(input, output, control, |reader, writer| Self::decode_stream(reader, writer, variant, control))
+    }
+
+    /// Human-readable byte count using binary units
+    pub fn format_size(bytes: u64) -> String {
+        const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+        let mut value = bytes as f64;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        if unit == 0 {
+            format!("{} B", bytes)
+        } else {
+            format!("{:.1} {}", value, UNITS[unit])
+        }
+    }
+
+    fn copy_encoded<R: Read, W: Write>(reader: &mut R, writer: W, variant: Base64Variant) -> io::Result<()> {
+        let mut encoder = EncoderWriter::new(writer, variant.engine());
+        let mut buffer = vec![0; Self::STREAM_CHUNK_SIZE];
+        loop {
+            let read = reader.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            encoder.write_all(&buffer[..read])?;
+        }
+        encoder.finish()?.flush()
+    }
+
+    fn stream_file(
+        input: &Path,
+        output: &Path,
+        control: &StreamControl,
+        run: impl FnOnce(BufReader<File>, &mut BufWriter<File>) -> Result<(), Base64Error>,
+    ) -> Result<(), Base64Error> {
+        let reader = File::open(input).map_err(|err| Self::stream_error(err, control))?;
+        // Creating the output truncates it, which would destroy the input before it is read
+        if matches!((std::fs::canonicalize(input), std::fs::canonicalize(output)), (Ok(source), Ok(target)) if source == target) {
+            return Err(Base64Error::Io("Output file is the same as the input file".to_string()));
+        }
+        let writer = File::create(output).map_err(|err| Self::stream_error(err, control))?;
+        let mut writer = BufWriter::with_capacity(Self::STREAM_CHUNK_SIZE, writer);
+
+        let result = run(BufReader::with_capacity(Self::STREAM_CHUNK_SIZE, reader), &mut writer);
+        drop(writer);
+        if result.is_err() {
+            let _ = std::fs::remove_file(output);
+        }
+        result
+    }
+
+    fn stream_error(err: io::Error, control: &StreamControl) -> Base64Error {
+        if control.is_cancelled() {
+            Base64Error::Cancelled
+        } else if err.kind() == io::ErrorKind::InvalidData {
+            Base64Error::InvalidBase64
+        } else {
+            Base64Error::Io(err.to_string())
+        }
     }
-    
-    /// Decode Base64 to text
-    pub fn decode(input: &str) -> Result<String, Base64Error> {
-        let decoded = general_purpose::STANDARD.decode(input)
-            .map_err(|_| Base64Error::InvalidBase64)?;
-        
-        String::from_utf8(decoded)
-            .map_err(|_| Base64Error::InvalidUtf8)
+
+    /// Offset, hex and ASCII columns in the style of `hexdump -C`
+    pub fn hex_dump(bytes: &[u8]) -> String {
+        let shown = &bytes[..bytes.len().min(Self::HEX_DUMP_LIMIT)];
+        let mut dump = String::new();
+
+        for (row, chunk) in shown.chunks(16).enumerate() {
+            let mut hex = String::new();
+            for (index, byte) in chunk.iter().enumerate() {
+                if index == 8 {
+                    hex.push(' ');
+                }
+                hex.push_str(&format!("{:02x} ", byte));
+            }
+            let ascii: String = chunk
+                .iter()
+                .map(|&byte| if byte.is_ascii_graphic() || byte == b' ' { byte as char } else { '.' })
+                .collect();
+            dump.push_str(&format!("{:08x}  {:<49} |{}|\n", row * 16, hex, ascii));
+        }
+
+        if bytes.len() > shown.len() {
+            dump.push_str(&format!("... {} more bytes\n", bytes.len() - shown.len()));
+        }
+        dump
+    }
+
+    fn strip_whitespace(input: &str) -> String {
+        input.chars().filter(|c| !c.is_ascii_whitespace()).collect()
     }
 }
 
@@ -30,33 +517,272 @@ mod tests {
 
     #[test]
     fn test_encode_basic() {
-        let result = Base64Logic::encode("Hello World");
+        let result = Base64Logic::encode(b"Hello World", Base64Variant::Standard);
         assert_eq!(result, "SGVsbG8gV29ybGQ=");
     }
 
     #[test]
     fn test_encode_empty() {
-        let result = Base64Logic::encode("");
+        let result = Base64Logic::encode(b"", Base64Variant::Standard);
         assert_eq!(result, "");
     }
 
     #[test]
     fn test_decode_basic() {
-        let result = Base64Logic::decode("SGVsbG8gV29ybGQ=").unwrap();
-        assert_eq!(result, "Hello World");
+        let result = Base64Logic::decode("SGVsbG8gV29ybGQ=", Base64Variant::Standard).unwrap();
+        assert_eq!(result, b"Hello World");
     }
 
     #[test]
     fn test_decode_invalid_base64() {
-        let result = Base64Logic::decode("invalid!!!base64");
+        let result = Base64Logic::decode("invalid!!!base64", Base64Variant::Standard);
         assert_eq!(result, Err(Base64Error::InvalidBase64));
     }
 
     #[test]
     fn test_round_trip() {
         let original = "The quick brown fox jumps over the lazy dog";
-        let encoded = Base64Logic::encode(original);
-        let decoded = Base64Logic::decode(&encoded).unwrap();
-        assert_eq!(original, decoded);
+        let encoded = Base64Logic::encode(original.as_bytes(), Base64Variant::Standard);
+        let decoded = Base64Logic::decode(&encoded, Base64Variant::Standard).unwrap();
+        assert_eq!(original.as_bytes(), decoded);
+    }
+
+    #[test]
+    fn test_encode_url_safe() {
+        // ">>>?" encodes to a '+' which the URL-safe alphabet replaces with '-'
+        assert_eq!(Base64Logic::encode(b">>>?", Base64Variant::Standard), "Pj4+Pw==");
+        assert_eq!(Base64Logic::encode(b">>>?", Base64Variant::UrlSafe), "Pj4-Pw==");
+        assert_eq!(Base64Logic::encode(b">>>?", Base64Variant::UrlSafeNoPad), "Pj4-Pw");
+        assert_eq!(Base64Logic::encode(b">>>?", Base64Variant::StandardNoPad), "Pj4+Pw");
+    }
+
+    #[test]
+    fn test_encode_mime_wraps_lines() {
+        let input = "a".repeat(100);
+        let encoded = Base64Logic::encode(input.as_bytes(), Base64Variant::Mime);
+        let lines: Vec<&str> = encoded.split("\r\n").collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].len(), 76);
+        assert_eq!(Base64Logic::decode(&encoded, Base64Variant::Mime).unwrap(), input.as_bytes());
+    }
+
+    #[test]
+    fn test_decode_with_rejects_wrong_variant() {
+        assert_eq!(Base64Logic::decode("Pj4-Pw", Base64Variant::UrlSafeNoPad).unwrap(), b">>>?");
+        assert_eq!(Base64Logic::decode("Pj4-Pw", Base64Variant::Standard), Err(Base64Error::InvalidBase64));
+        assert_eq!(Base64Logic::decode("Pj4+Pw==", Base64Variant::StandardNoPad), Err(Base64Error::InvalidBase64));
+    }
+
+    #[test]
+    fn test_decode_tolerates_whitespace() {
+        assert_eq!(Base64Logic::decode(" SGVsbG8g\nV29ybGQ=\n", Base64Variant::Standard).unwrap(), b"Hello World");
+    }
+
+    #[test]
+    fn test_decode_auto() {
+        let cases = [
+            ("Pj4+Pw==", Base64Variant::Standard),
+            ("Pj4+Pw", Base64Variant::StandardNoPad),
+            ("Pj4-Pw==", Base64Variant::UrlSafe),
+            ("Pj4-Pw", Base64Variant::UrlSafeNoPad),
+        ];
+        for (input, variant) in cases {
+            let detected = Base64Logic::decode_auto(input).unwrap();
+            assert_eq!(detected, DetectedBase64 { variant, bytes: b">>>?".to_vec() });
+        }
+    }
+
+    #[test]
+    fn test_decode_auto_mime() {
+        let encoded = Base64Logic::encode("mime ".repeat(30).as_bytes(), Base64Variant::Mime);
+        let detected = Base64Logic::decode_auto(&encoded).unwrap();
+        assert_eq!(detected.variant, Base64Variant::Mime);
+        assert_eq!(detected.bytes, "mime ".repeat(30).into_bytes());
+    }
+
+    #[test]
+    fn test_decode_auto_invalid() {
+        assert_eq!(Base64Logic::decode_auto("not base64!"), Err(Base64Error::InvalidBase64));
+    }
+
+    #[test]
+    fn test_decode_binary() {
+        let bytes = Base64Logic::decode("AP8Q", Base64Variant::Standard).unwrap();
+        assert_eq!(bytes, vec![0x00, 0xff, 0x10]);
+        assert_eq!(Base64Logic::encode(&bytes, Base64Variant::Standard), "AP8Q");
+    }
+
+    #[test]
+    fn test_inspect_text() {
+        assert_eq!(Base64Logic::inspect("héllo".as_bytes()), DecodedContent::Text("héllo".to_string()));
+    }
+
+    #[test]
+    fn test_inspect_binary() {
+        let png = b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR";
+        match Base64Logic::inspect(png) {
+            DecodedContent::Binary { mime_type, hex_dump } => {
+                assert_eq!(mime_type, "image/png");
+                assert_eq!(
+                    hex_dump,
+                    "00000000  89 50 4e 47 0d 0a 1a 0a  00 00 00 0d 49 48 44 52  |.PNG........IHDR|\n"
+                );
+            }
+            other => panic!("expected binary content, got {:?}", other),
+        }
+
+        match Base64Logic::inspect(&[0x08, 0x96, 0x01]) {
+            DecodedContent::Binary { mime_type, .. } => assert_eq!(mime_type, "application/octet-stream"),
+            other => panic!("expected binary content, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_sniff_mime() {
+        assert_eq!(Base64Logic::sniff_mime(b"\xff\xd8\xff\xe0"), Some("image/jpeg"));
+        assert_eq!(Base64Logic::sniff_mime(b"RIFF\x00\x00\x00\x00WEBPVP8 "), Some("image/webp"));
+        assert_eq!(Base64Logic::sniff_mime(&[0x30, 0x82, 0x03, 0x0a]), Some("application/pkix-cert"));
+        assert_eq!(Base64Logic::sniff_mime(b"<?xml version=\"1.0\"?><svg/>"), Some("image/svg+xml"));
+        assert_eq!(Base64Logic::sniff_mime(b"-----BEGIN CERTIFICATE-----"), Some("application/x-pem-file"));
+        assert_eq!(Base64Logic::sniff_mime(b"plain"), None);
+        assert_eq!(Base64Logic::extension_for("image/png"), "png");
+        assert_eq!(Base64Logic::extension_for("application/octet-stream"), "bin");
+    }
+
+    #[test]
+    fn test_hex_dump_partial_row_and_limit() {
+        let dump = Base64Logic::hex_dump(b"Hi\n");
+        assert_eq!(dump, format!("00000000  {:<49} |Hi.|\n", "48 69 0a "));
+
+        let large = vec![0u8; Base64Logic::HEX_DUMP_LIMIT + 10];
+        let dump = Base64Logic::hex_dump(&large);
+        assert_eq!(dump.lines().count(), Base64Logic::HEX_DUMP_LIMIT / 16 + 1);
+        assert!(dump.ends_with("... 10 more bytes\n"));
+    }
+
+    #[test]
+    fn test_data_uri_round_trip() {
+        let png = b"\x89PNG\r\n\x1a\n";
+        let uri = Base64Logic::data_uri("image/png", png);
+        assert_eq!(uri, "data:image/png;base64,iVBORw0KGgo=");
+        assert_eq!(
+            Base64Logic::parse_data_uri(&uri).unwrap(),
+            DataUri { mime_type: "image/png".to_string(), bytes: png.to_vec() }
+        );
+    }
+
+    #[test]
+    fn test_parse_data_uri_variants() {
+        let plain = Base64Logic::parse_data_uri("data:,Hello%2C%20World").unwrap();
+        assert_eq!(plain.mime_type, "text/plain");
+        assert_eq!(plain.bytes, b"Hello, World");
+
+        let with_charset = Base64Logic::parse_data_uri(" DATA:Text/Plain;charset=utf-8;base64,SGk= ").unwrap();
+        assert_eq!(with_charset.mime_type, "text/plain");
+        assert_eq!(with_charset.bytes, b"Hi");
+
+        let escaped = Base64Logic::parse_data_uri("data:application/octet-stream;base64,Pj4%2BPw%3D%3D").unwrap();
+        assert_eq!(escaped.bytes, b">>>?");
+    }
+
+    #[test]
+    fn test_parse_data_uri_invalid() {
+        assert_eq!(Base64Logic::parse_data_uri("SGk="), Err(Base64Error::InvalidDataUri));
+        assert_eq!(Base64Logic::parse_data_uri("data:image/png;base64"), Err(Base64Error::InvalidDataUri));
+        assert_eq!(Base64Logic::parse_data_uri("data:image/png;base64,!!"), Err(Base64Error::InvalidBase64));
+    }
+
+    #[test]
+    fn test_mime_for_file() {
+        assert_eq!(Base64Logic::mime_for_file("photo.dat", b"\xff\xd8\xff\xe0"), "image/jpeg");
+        assert_eq!(Base64Logic::mime_for_file("data.JSON", b"{}"), "application/json");
+        assert_eq!(Base64Logic::mime_for_file("blob", b"\x01\x02"), "application/octet-stream");
+        // Text that happens to start like a short signature keeps its extension's type
+        assert_eq!(Base64Logic::mime_for_file("notes.txt", b"BMW service dates"), "text/plain");
+        assert_eq!(Base64Logic::mime_for_file("names.csv", b"MZ,Mozambique"), "text/csv");
+        assert_eq!(Base64Logic::mime_for_file("image", b"BM\x36\x00"), "image/bmp");
+        assert_eq!(Base64Logic::mime_for_file("photo.txt", b"\x89PNG\r\n\x1a\n"), "image/png");
+    }
+
+    #[test]
+    fn test_encode_stream_matches_in_memory() {
+        let input: Vec<u8> = (0..200_000u32).map(|i| (i * 7 % 256) as u8).collect();
+        for variant in Base64Variant::ALL {
+            let control = StreamControl::new();
+            let mut output = Vec::new();
+            Base64Logic::encode_stream(input.as_slice(), &mut output, variant, &control).unwrap();
+            assert_eq!(String::from_utf8(output).unwrap(), Base64Logic::encode(&input, variant), "{}", variant);
+            assert_eq!(control.processed(), input.len() as u64);
+        }
+    }
+
+    #[test]
+    fn test_decode_stream_round_trip() {
+        let input: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+        for variant in Base64Variant::ALL {
+            let encoded = Base64Logic::encode(&input, variant);
+            let mut output = Vec::new();
+            Base64Logic::decode_stream(encoded.as_bytes(), &mut output, variant, &StreamControl::new()).unwrap();
+            assert_eq!(output, input, "{}", variant);
+        }
+    }
+
+    #[test]
+    fn test_decode_stream_invalid() {
+        let mut output = Vec::new();
+        let result = Base64Logic::decode_stream(&b"SGVs!!bG8="[..], &mut output, Base64Variant::Standard, &StreamControl::new());
+        assert_eq!(result, Err(Base64Error::InvalidBase64));
+    }
+
+    #[test]
+    fn test_stream_cancelled() {
+        let control = StreamControl::new();
+        control.cancel();
+        let mut output = Vec::new();
+        let result = Base64Logic::encode_stream(&b"data"[..], &mut output, Base64Variant::Standard, &control);
+        assert_eq!(result, Err(Base64Error::Cancelled));
+        let result = Base64Logic::decode_stream(&b"ZGF0YQ=="[..], &mut output, Base64Variant::Standard, &control);
+        assert_eq!(result, Err(Base64Error::Cancelled));
+    }
+
+    #[test]
+    fn test_stream_files() {
+        let dir = std::env::temp_dir().join(format!("effless-base64-stream-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let (plain, encoded, decoded) = (dir.join("plain.bin"), dir.join("plain.b64"), dir.join("decoded.bin"));
+        std::fs::write(&plain, b"\x00\x01streaming\xff").unwrap();
+
+        Base64Logic::encode_file(&plain, &encoded, Base64Variant::Mime, &StreamControl::new()).unwrap();
+        Base64Logic::decode_file(&encoded, &decoded, Base64Variant::Mime, &StreamControl::new()).unwrap();
+        assert_eq!(std::fs::read(&decoded).unwrap(), std::fs::read(&plain).unwrap());
+
+        // A failed job leaves no partial output behind
+        std::fs::write(&encoded, b"not base64!").unwrap();
+        let result = Base64Logic::decode_file(&encoded, &decoded, Base64Variant::Standard, &StreamControl::new());
+        assert_eq!(result, Err(Base64Error::InvalidBase64));
+        assert!(!decoded.exists());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_stream_same_file() {
+        let dir = std::env::temp_dir().join(format!("effless-base64-same-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let plain = dir.join("plain.bin");
+        std::fs::write(&plain, b"keep me").unwrap();
+
+        let result = Base64Logic::encode_file(&plain, &dir.join(".").join("plain.bin"), Base64Variant::Standard, &StreamControl::new());
+        assert!(matches!(result, Err(Base64Error::Io(_))));
+        assert_eq!(std::fs::read(&plain).unwrap(), b"keep me");
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(Base64Logic::format_size(512), "512 B");
+        assert_eq!(Base64Logic::format_size(1536), "1.5 KB");
+        assert_eq!(Base64Logic::format_size(300 * 1024 * 1024), "300.0 MB");
     }
 }
\ No newline at end of file
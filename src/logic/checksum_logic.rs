@@ -0,0 +1,413 @@
+use crate::logic::hash_logic::{HashAlgorithm, HashError, HashLogic};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, PartialEq)]
+pub enum ChecksumError {
+    InvalidLine(usize),
+    UnknownAlgorithm(String),
+    EmptyManifest,
+    Io(String),
+}
+
+/// Layout of a checksum manifest line
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ManifestFormat {
+    /// `sha256sum` style: `<hex>  <path>`
+    #[default]
+    Gnu,
+    /// `shasum --tag` / BSD style: `SHA256 (<path>) = <hex>`
+    Bsd,
+}
+
+impl std::fmt::Display for ManifestFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ManifestFormat::Gnu => write!(f, "GNU (sha256sum)"),
+            ManifestFormat::Bsd => write!(f, "BSD (--tag)"),
+        }
+    }
+}
+
+impl ManifestFormat {
+    pub const ALL: [ManifestFormat; 2] = [ManifestFormat::Gnu, ManifestFormat::Bsd];
+}
+
+/// One file listed in a manifest
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManifestEntry {
+    pub path: String,
+    pub algorithm: HashAlgorithm,
+    pub expected: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EntryStatus {
+    Ok,
+    Failed,
+    Missing,
+}
+
+impl std::fmt::Display for EntryStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EntryStatus::Ok => write!(f, "OK"),
+            EntryStatus::Failed => write!(f, "FAILED"),
+            EntryStatus::Missing => write!(f, "MISSING"),
+        }
+    }
+}
+
+pub struct ChecksumLogic;
+
+impl ChecksumLogic {
+    /// Algorithms that can appear in a manifest
+    pub const ALGORITHMS: [HashAlgorithm; 6] = [
+        HashAlgorithm::Sha256,
+        HashAlgorithm::Sha512,
+        HashAlgorithm::Sha384,
+        HashAlgorithm::Sha224,
+        HashAlgorithm::Sha512_256,
+        HashAlgorithm::Md5,
+    ];
+
+    /// Parse a GNU or BSD manifest, skipping blank lines and `#` comments
+    ///
+    /// GNU lines carry no algorithm name, so it comes from `hint` (usually the
+    /// manifest file name) or else from the digest length.
+    pub fn parse_manifest(text: &str, hint: Option<HashAlgorithm>) -> Result<Vec<ManifestEntry>, ChecksumError> {
+        let mut entries = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let entry = match Self::parse_bsd_line(line, index + 1)? {
+                Some(entry) => entry,
+                None => Self::parse_gnu_line(line, index + 1, hint)?,
+            };
+            entries.push(entry);
+        }
+
+        if entries.is_empty() {
+            return Err(ChecksumError::EmptyManifest);
+        }
+        Ok(entries)
+    }
+
+    fn parse_gnu_line(line: &str, number: usize, hint: Option<HashAlgorithm>) -> Result<ManifestEntry, ChecksumError> {
+        // A leading backslash marks a file name with escaped `\\` or `\n`
+        let (escaped, line) = match line.strip_prefix('\\') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        let (digest, rest) = line.split_once(' ').ok_or(ChecksumError::InvalidLine(number))?;
+        // Second separator character is ' ' for text mode and '*' for binary mode
+        let path = rest
+            .strip_prefix(' ')
+            .or_else(|| rest.strip_prefix('*'))
+            .ok_or(ChecksumError::InvalidLine(number))?;
+        let expected = Self::parse_hex(digest).ok_or(ChecksumError::InvalidLine(number))?;
+        let algorithm = hint
+            .filter(|algorithm| HashLogic::digest(*algorithm, b"").len() == expected.len())
+            .or_else(|| Self::algorithm_for_length(expected.len()))
+            .ok_or(ChecksumError::InvalidLine(number))?;
+        if path.is_empty() {
+            return Err(ChecksumError::InvalidLine(number));
+        }
+
+        let path = if escaped { Self::unescape_path(path) } else { path.to_string() };
+        Ok(ManifestEntry { path, algorithm, expected })
+    }
+
+    fn parse_bsd_line(line: &str, number: usize) -> Result<Option<ManifestEntry>, ChecksumError> {
+        let Some((tag, rest)) = line.split_once(" (") else {
+            return Ok(None);
+        };
+        if tag.is_empty() || tag.contains(' ') || !rest.contains(") = ") {
+            return Ok(None);
+        }
+        let algorithm = Self::algorithm_for_tag(tag).ok_or_else(|| ChecksumError::UnknownAlgorithm(tag.to_string()))?;
+        let (path, digest) = rest.rsplit_once(") = ").ok_or(ChecksumError::InvalidLine(number))?;
+        let expected = Self::parse_hex(digest.trim()).ok_or(ChecksumError::InvalidLine(number))?;
+        if expected.len() != HashLogic::digest(algorithm, b"").len() {
+            return Err(ChecksumError::InvalidLine(number));
+        }
+
+        Ok(Some(ManifestEntry { path: path.to_string(), algorithm, expected }))
+    }
+
+    /// Guess the algorithm from a manifest name such as `SHA256SUMS` or `app.tar.gz.sha512`
+    pub fn algorithm_hint(file_name: &str) -> Option<HashAlgorithm> {
+        let name = file_name.to_ascii_lowercase().replace(['-', '_'], "");
+        // `sha512256` must be tried before its `sha512` prefix
+        [
+            ("sha512256", HashAlgorithm::Sha512_256),
+            ("sha224", HashAlgorithm::Sha224),
+            ("sha256", HashAlgorithm::Sha256),
+            ("sha384", HashAlgorithm::Sha384),
+            ("sha512", HashAlgorithm::Sha512),
+            ("md5", HashAlgorithm::Md5),
+        ]
+        .into_iter()
+        .find(|(needle, _)| name.contains(needle))
+        .map(|(_, algorithm)| algorithm)
+    }
+
+    fn algorithm_for_length(length: usize) -> Option<HashAlgorithm> {
+        // SHA-512/256 shares its length with SHA-256 and is only picked via a hint or BSD tag
+        match length {
+            16 => Some(HashAlgorithm::Md5),
+            28 => Some(HashAlgorithm::Sha224),
+            32 => Some(HashAlgorithm::Sha256),
+            48 => Some(HashAlgorithm::Sha384),
+            64 => Some(HashAlgorithm::Sha512),
+            _ => None,
+        }
+    }
+
+    fn algorithm_for_tag(tag: &str) -> Option<HashAlgorithm> {
+        match tag.to_ascii_uppercase().replace('-', "").as_str() {
+            "MD5" => Some(HashAlgorithm::Md5),
+            "SHA224" => Some(HashAlgorithm::Sha224),
+            "SHA256" => Some(HashAlgorithm::Sha256),
+            "SHA384" => Some(HashAlgorithm::Sha384),
+            "SHA512" => Some(HashAlgorithm::Sha512),
+            "SHA512/256" | "SHA512_256" => Some(HashAlgorithm::Sha512_256),
+            _ => None,
+        }
+    }
+
+    fn tag_for(algorithm: HashAlgorithm) -> &'static str {
+        match algorithm {
+            HashAlgorithm::Md5 => "MD5",
+            HashAlgorithm::Sha224 => "SHA224",
+            HashAlgorithm::Sha256 => "SHA256",
+            HashAlgorithm::Sha384 => "SHA384",
+            HashAlgorithm::Sha512 => "SHA512",
+            HashAlgorithm::Sha512_256 => "SHA512/256",
+        }
+    }
+
+    fn parse_hex(input: &str) -> Option<Vec<u8>> {
+        if input.is_empty() || !input.len().is_multiple_of(2) || !input.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return None;
+        }
+        (0..input.len())
+            .step_by(2)
+            .map(|index| u8::from_str_radix(&input[index..index + 2], 16).ok())
+            .collect()
+    }
+
+    fn unescape_path(path: &str) -> String {
+        let mut unescaped = String::with_capacity(path.len());
+        let mut chars = path.chars();
+        while let Some(c) = chars.next() {
+            match (c, chars.clone().next()) {
+                ('\\', Some('\\')) => {
+                    unescaped.push('\\');
+                    chars.next();
+                }
+                ('\\', Some('n')) => {
+                    unescaped.push('\n');
+                    chars.next();
+                }
+                _ => unescaped.push(c),
+            }
+        }
+        unescaped
+    }
+
+    /// Resolve a manifest path against the directory the manifest lives in
+    pub fn resolve(base_dir: &Path, path: &str) -> PathBuf {
+        base_dir.join(path)
+    }
+
+    /// Hash one listed file and compare it with the expected digest
+    pub fn verify_entry(base_dir: &Path, entry: &ManifestEntry) -> EntryStatus {
+        let path = Self::resolve(base_dir, &entry.path);
+        if matches!(path.try_exists(), Ok(false)) || path.is_dir() {
+            return EntryStatus::Missing;
+        }
+        match HashLogic::digest_file(&path, &[entry.algorithm]) {
+            Ok(results) if results[0].digest == entry.expected => EntryStatus::Ok,
+            _ => EntryStatus::Failed,
+        }
+    }
+
+    /// Every regular file under `dir`, as sorted `/`-separated relative paths
+    pub fn list_files(dir: &Path) -> Result<Vec<String>, ChecksumError> {
+        let mut files = Vec::new();
+        let mut pending = vec![dir.to_path_buf()];
+        while let Some(current) = pending.pop() {
+            let entries = std::fs::read_dir(&current).map_err(|err| ChecksumError::Io(err.to_string()))?;
+            for entry in entries {
+                let entry = entry.map_err(|err| ChecksumError::Io(err.to_string()))?;
+                let file_type = entry.file_type().map_err(|err| ChecksumError::Io(err.to_string()))?;
+                let path = entry.path();
+                if file_type.is_dir() {
+                    pending.push(path);
+                } else if file_type.is_file()
+                    && let Ok(relative) = path.strip_prefix(dir)
+                {
+                    let parts: Vec<String> = relative
+                        .components()
+                        .map(|component| component.as_os_str().to_string_lossy().into_owned())
+                        .collect();
+                    files.push(parts.join("/"));
+                }
+            }
+        }
+        files.sort();
+        Ok(files)
+    }
+
+    /// Hash one file for a generated manifest
+    pub fn hash_entry(base_dir: &Path, path: &str, algorithm: HashAlgorithm) -> Result<ManifestEntry, ChecksumError> {
+        let results = HashLogic::digest_file(&Self::resolve(base_dir, path), &[algorithm]).map_err(|err| match err {
+            HashError::Io(message) => ChecksumError::Io(format!("{}: {}", path, message)),
+        })?;
+        Ok(ManifestEntry { path: path.to_string(), algorithm, expected: results[0].digest.clone() })
+    }
+
+    /// Render entries as manifest text, one line per file
+    pub fn format_manifest(entries: &[ManifestEntry], format: ManifestFormat) -> String {
+        entries
+            .iter()
+            .map(|entry| {
+                let hex = HashLogic::to_hex(&entry.expected);
+                match format {
+                    ManifestFormat::Gnu if entry.path.contains(['\\', '\n']) => {
+                        let escaped = entry.path.replace('\\', "\\\\").replace('\n', "\\n");
+                        format!("\\{}  {}\n", hex, escaped)
+                    }
+                    ManifestFormat::Gnu => format!("{}  {}\n", hex, entry.path),
+                    ManifestFormat::Bsd => format!("{} ({}) = {}\n", Self::tag_for(entry.algorithm), entry.path, hex),
+                }
+            })
+            .collect()
+    }
+
+    /// Conventional manifest file name, e.g. `SHA256SUMS`
+    pub fn default_file_name(algorithm: HashAlgorithm) -> String {
+        format!("{}SUMS", Self::tag_for(algorithm).replace('/', "_"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ABC_SHA256: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("effless-checksum-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_parse_gnu_manifest() {
+        let text = format!("# release\n{}  app.tar.gz\r\n\n{} *bin/app.exe\n", ABC_SHA256, ABC_SHA256);
+        let entries = ChecksumLogic::parse_manifest(&text, None).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].path, "app.tar.gz");
+        assert_eq!(entries[0].algorithm, HashAlgorithm::Sha256);
+        assert_eq!(entries[1].path, "bin/app.exe");
+        assert_eq!(HashLogic::to_hex(&entries[1].expected), ABC_SHA256);
+    }
+
+    #[test]
+    fn test_parse_gnu_escaped_path() {
+        let text = format!("\\{}  dir\\\\new\\nline", ABC_SHA256);
+        let entries = ChecksumLogic::parse_manifest(&text, None).unwrap();
+        assert_eq!(entries[0].path, "dir\\new\nline");
+        assert_eq!(ChecksumLogic::format_manifest(&entries, ManifestFormat::Gnu), format!("{}\n", text));
+    }
+
+    #[test]
+    fn test_parse_bsd_manifest() {
+        let text = format!("SHA256 (my file (1).txt) = {}\nMD5 (a) = 900150983cd24fb0d6963f7d28e17f72\n", ABC_SHA256);
+        let entries = ChecksumLogic::parse_manifest(&text, None).unwrap();
+        assert_eq!(entries[0].path, "my file (1).txt");
+        assert_eq!(entries[0].algorithm, HashAlgorithm::Sha256);
+        assert_eq!(entries[1].algorithm, HashAlgorithm::Md5);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(ChecksumLogic::parse_manifest("# only comments\n", None), Err(ChecksumError::EmptyManifest));
+        assert_eq!(ChecksumLogic::parse_manifest("xyz  file", None), Err(ChecksumError::InvalidLine(1)));
+        assert_eq!(
+            ChecksumLogic::parse_manifest(&format!("\n{}", ABC_SHA256), None),
+            Err(ChecksumError::InvalidLine(2))
+        );
+        assert_eq!(
+            ChecksumLogic::parse_manifest("WHIRLPOOL (a) = 00", None),
+            Err(ChecksumError::UnknownAlgorithm("WHIRLPOOL".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_algorithm_hint() {
+        assert_eq!(ChecksumLogic::algorithm_hint("SHA256SUMS"), Some(HashAlgorithm::Sha256));
+        assert_eq!(ChecksumLogic::algorithm_hint("app.tar.gz.sha512"), Some(HashAlgorithm::Sha512));
+        assert_eq!(ChecksumLogic::algorithm_hint("SHA512_256SUMS"), Some(HashAlgorithm::Sha512_256));
+        assert_eq!(ChecksumLogic::algorithm_hint("md5sum.txt"), Some(HashAlgorithm::Md5));
+        assert_eq!(ChecksumLogic::algorithm_hint("CHECKSUMS"), None);
+
+        // A hint that disagrees with the digest length falls back to the length
+        let entries = ChecksumLogic::parse_manifest(&format!("{}  a", ABC_SHA256), Some(HashAlgorithm::Md5)).unwrap();
+        assert_eq!(entries[0].algorithm, HashAlgorithm::Sha256);
+        let entries = ChecksumLogic::parse_manifest(&format!("{}  a", ABC_SHA256), Some(HashAlgorithm::Sha512_256)).unwrap();
+        assert_eq!(entries[0].algorithm, HashAlgorithm::Sha512_256);
+    }
+
+    #[test]
+    fn test_verify_entries() {
+        let dir = temp_dir("verify");
+        std::fs::write(dir.join("good.txt"), "abc").unwrap();
+        std::fs::write(dir.join("bad.txt"), "abd").unwrap();
+        let text = format!("{0}  good.txt\n{0}  bad.txt\n{0}  gone.txt\n", ABC_SHA256);
+        let statuses: Vec<EntryStatus> = ChecksumLogic::parse_manifest(&text, None)
+            .unwrap()
+            .iter()
+            .map(|entry| ChecksumLogic::verify_entry(&dir, entry))
+            .collect();
+        assert_eq!(statuses, vec![EntryStatus::Ok, EntryStatus::Failed, EntryStatus::Missing]);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_generate_manifest_round_trip() {
+        let dir = temp_dir("generate");
+        std::fs::create_dir_all(dir.join("nested")).unwrap();
+        std::fs::write(dir.join("b.txt"), "abc").unwrap();
+        std::fs::write(dir.join("nested/a.txt"), "xyz").unwrap();
+
+        let files = ChecksumLogic::list_files(&dir).unwrap();
+        assert_eq!(files, vec!["b.txt".to_string(), "nested/a.txt".to_string()]);
+
+        for format in ManifestFormat::ALL {
+            let entries: Vec<ManifestEntry> = files
+                .iter()
+                .map(|path| ChecksumLogic::hash_entry(&dir, path, HashAlgorithm::Sha256).unwrap())
+                .collect();
+            let text = ChecksumLogic::format_manifest(&entries, format);
+            let parsed = ChecksumLogic::parse_manifest(&text, None).unwrap();
+            assert_eq!(parsed, entries);
+            assert!(parsed.iter().all(|entry| ChecksumLogic::verify_entry(&dir, entry) == EntryStatus::Ok));
+        }
+        assert_eq!(
+            ChecksumLogic::format_manifest(&[ChecksumLogic::hash_entry(&dir, "b.txt", HashAlgorithm::Sha256).unwrap()], ManifestFormat::Bsd),
+            format!("SHA256 (b.txt) = {}\n", ABC_SHA256)
+        );
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_default_file_name() {
+        assert_eq!(ChecksumLogic::default_file_name(HashAlgorithm::Sha256), "SHA256SUMS");
+        assert_eq!(ChecksumLogic::default_file_name(HashAlgorithm::Md5), "MD5SUMS");
+    }
+}
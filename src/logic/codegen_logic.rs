@@ -0,0 +1,629 @@
+use serde_json::Value;
+use std::collections::HashSet;
+use crate::logic::shape_logic::{ObjectShape, Shape, ShapeLogic};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CodeLanguage {
+    #[default]
+    Rust,
+    TypeScript,
+    Go,
+}
+
+impl std::fmt::Display for CodeLanguage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CodeLanguage::Rust => write!(f, "Rust (serde)"),
+            CodeLanguage::TypeScript => write!(f, "TypeScript"),
+            CodeLanguage::Go => write!(f, "Go"),
+        }
+    }
+}
+
+impl CodeLanguage {
+    pub const ALL: [CodeLanguage; 3] = [CodeLanguage::Rust, CodeLanguage::TypeScript, CodeLanguage::Go];
+}
+
+/// A resolved type, shared by every language
+#[derive(Debug, Clone, PartialEq)]
+enum TypeRef {
+    /// Never seen, only null, or a mix of incompatible types
+    Any,
+    Bool,
+    Integer,
+    Unsigned,
+    Float,
+    String,
+    Array(Box<Slot>),
+    Struct(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Slot {
+    ty: TypeRef,
+    nullable: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Field {
+    key: String,
+    slot: Slot,
+    /// Missing from some of the objects seen at this position
+    optional: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct StructDef {
+    name: String,
+    fields: Vec<Field>,
+}
+
+/// Named structs in declaration order, plus the type of the root document
+#[derive(Debug, PartialEq)]
+struct Model {
+    structs: Vec<StructDef>,
+    root: Slot,
+    /// Name of the root type alias, used when the root is not a struct
+    root_name: String,
+}
+
+struct ModelBuilder {
+    structs: Vec<StructDef>,
+    names: HashSet<String>,
+}
+
+impl ModelBuilder {
+    fn resolve(&mut self, shape: &Shape, name_hint: &str) -> Slot {
+        let ty = if shape.kind_count() != 1 {
+            TypeRef::Any
+        } else if shape.boolean {
+            TypeRef::Bool
+        } else if shape.float || (shape.unsigned && shape.negative) {
+            // No integer type holds both negatives and values above `i64::MAX`
+            TypeRef::Float
+        } else if shape.unsigned {
+            TypeRef::Unsigned
+        } else if shape.integer {
+            TypeRef::Integer
+        } else if shape.string.is_some() {
+            TypeRef::String
+        } else if let Some(element) = &shape.array {
+            TypeRef::Array(Box::new(self.resolve(element, &CodegenLogic::singular(name_hint))))
+        } else if let Some(object) = &shape.object {
+            TypeRef::Struct(self.resolve_struct(object, name_hint))
+        } else {
+            TypeRef::Any
+        };
+        Slot { ty, nullable: shape.null }
+    }
+
+    fn resolve_struct(&mut self, object: &ObjectShape, name_hint: &str) -> usize {
+        // Reserve the slot first so parents are declared before their children
+        let name = self.unique_name(name_hint);
+        let position = self.structs.len();
+        self.structs.push(StructDef { name, fields: Vec::new() });
+        let fields = object
+            .properties
+            .iter()
+            .map(|(key, shape, count)| Field {
+                key: key.clone(),
+                slot: self.resolve(shape, &CodegenLogic::pascal_case(key)),
+                optional: *count < object.seen,
+            })
+            .collect();
+        self.structs[position].fields = fields;
+        position
+    }
+
+    fn unique_name(&mut self, hint: &str) -> String {
+        let base = if hint.is_empty() { "Type".to_string() } else { hint.to_string() };
+        let mut name = base.clone();
+        let mut suffix = 2;
+        while !self.names.insert(name.clone()) {
+            name = format!("{}{}", base, suffix);
+            suffix += 1;
+        }
+        name
+    }
+}
+
+const RUST_KEYWORDS: [&str; 51] = [
+    "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop",
+    "match", "mod", "move", "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while", "async", "await", "dyn", "abstract", "become", "box", "do", "final", "macro", "override",
+    "priv", "typeof", "unsized", "virtual", "yield", "try",
+];
+
+/// Type names the generated code already refers to, or that Rust's prelude and keywords claim
+const RESERVED_TYPE_NAMES: [&str; 43] = [
+    "Self", "Option", "Some", "None", "Result", "Ok", "Err", "String", "Vec", "Box", "Serialize", "Deserialize", "Debug", "Clone",
+    "Copy", "Default", "Drop", "Eq", "PartialEq", "Ord", "PartialOrd", "Hash", "Send", "Sync", "Sized", "Unpin", "Fn", "FnMut",
+    "FnOnce", "From", "Into", "TryFrom", "TryInto", "AsRef", "AsMut", "ToOwned", "ToString", "Iterator", "IntoIterator",
+    "DoubleEndedIterator", "ExactSizeIterator", "Extend", "FromIterator",
+];
+
+/// Words Go style keeps fully upper-case in identifiers
+const GO_INITIALISMS: [&str; 20] = [
+    "acl", "api", "cpu", "css", "dns", "html", "http", "https", "id", "ip", "json", "sql", "ssh", "tcp", "ttl", "ui", "uri", "url", "uuid", "xml",
+];
+
+pub struct CodegenLogic;
+
+impl CodegenLogic {
+    /// Type declarations that can hold every sample, named from `root_name` and the keys beneath it
+    pub fn generate(samples: &[Value], root_name: &str, language: CodeLanguage) -> String {
+        let model = Self::model(samples, root_name);
+        match language {
+            CodeLanguage::Rust => Self::emit_rust(&model),
+            CodeLanguage::TypeScript => Self::emit_typescript(&model),
+            CodeLanguage::Go => Self::emit_go(&model),
+        }
+    }
+
+    fn model(samples: &[Value], root_name: &str) -> Model {
+        let shape = ShapeLogic::observe(samples);
+        // Generated names get a suffix rather than shadow a type the output itself uses
+        let names = RESERVED_TYPE_NAMES.iter().map(|name| name.to_string()).collect();
+        let mut builder = ModelBuilder { structs: Vec::new(), names };
+        let root_name = Self::root_type_name(root_name);
+        // A root that is not a plain object becomes an alias, which needs the name to itself
+        let alias_name = if shape.kind_count() != 1 || shape.object.is_none() {
+            builder.unique_name(&root_name)
+        } else {
+            root_name.clone()
+        };
+        let root = builder.resolve(&shape, &root_name);
+        Model { structs: builder.structs, root, root_name: alias_name }
+    }
+
+    fn root_type_name(root_name: &str) -> String {
+        match Self::pascal_case(root_name) {
+            name if name.is_empty() => "Root".to_string(),
+            name => name,
+        }
+    }
+
+    /// The root gets a type alias unless it is itself a struct
+    fn root_alias(model: &Model) -> Option<&Slot> {
+        match model.root.ty {
+            TypeRef::Struct(_) => None,
+            _ => Some(&model.root),
+        }
+    }
+
+    fn emit_rust(model: &Model) -> String {
+        let mut output = String::from("use serde::{Deserialize, Serialize};\n");
+        if let Some(root) = Self::root_alias(model) {
+            output.push_str(&format!("\npub type {} = {};\n", model.root_name, Self::rust_type(model, root)));
+        }
+        for definition in &model.structs {
+            output.push_str(&format!("\n#[derive(Debug, Clone, Serialize, Deserialize)]\npub struct {} {{\n", definition.name));
+            let mut used = HashSet::new();
+            for field in &definition.fields {
+                let name = Self::dedupe(&mut used, Self::rust_field_name(&field.key));
+                let mut attributes = Vec::new();
+                if name.trim_start_matches("r#") != field.key {
+                    attributes.push(format!("rename = {}", Value::String(field.key.clone())));
+                }
+                if field.optional && field.slot.ty == TypeRef::Any {
+                    attributes.push("default".to_string());
+                } else if field.optional {
+                    attributes.push("default, skip_serializing_if = \"Option::is_none\"".to_string());
+                }
+                if !attributes.is_empty() {
+                    output.push_str(&format!("    #[serde({})]\n", attributes.join(", ")));
+                }
+                let slot = Slot { ty: field.slot.ty.clone(), nullable: field.slot.nullable || field.optional };
+                output.push_str(&format!("    pub {}: {},\n", name, Self::rust_type(model, &slot)));
+            }
+            output.push_str("}\n");
+        }
+        output
+    }
+
+    fn rust_type(model: &Model, slot: &Slot) -> String {
+        let inner = match &slot.ty {
+            // `Value` already represents null, so it is never wrapped in an Option
+            TypeRef::Any => return "serde_json::Value".to_string(),
+            TypeRef::Bool => "bool".to_string(),
+            TypeRef::Integer => "i64".to_string(),
+            TypeRef::Unsigned => "u64".to_string(),
+            TypeRef::Float => "f64".to_string(),
+            TypeRef::String => "String".to_string(),
+            TypeRef::Array(element) => format!("Vec<{}>", Self::rust_type(model, element)),
+            TypeRef::Struct(index) => model.structs[*index].name.clone(),
+        };
+        if slot.nullable { format!("Option<{}>", inner) } else { inner }
+    }
+
+    fn rust_field_name(key: &str) -> String {
+        let mut name = Self::snake_case(key);
+        if name.is_empty() {
+            name = "field".to_string();
+        } else if name.starts_with(|c: char| c.is_ascii_digit()) {
+            name = format!("field_{}", name);
+        }
+        match name.as_str() {
+            // These cannot be raw identifiers
+            "self" | "super" | "crate" => format!("{}_", name),
+            keyword if RUST_KEYWORDS.contains(&keyword) => format!("r#{}", name),
+            _ => name,
+        }
+    }
+
+    fn emit_typescript(model: &Model) -> String {
+        let mut blocks = Vec::new();
+        if let Some(root) = Self::root_alias(model) {
+            blocks.push(format!("export type {} = {};\n", model.root_name, Self::typescript_type(model, root)));
+        }
+        for definition in &model.structs {
+            let mut block = format!("export interface {} {{\n", definition.name);
+            for field in &definition.fields {
+                let is_identifier = field.key.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_' || c == '$')
+                    && field.key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+                let name = if is_identifier { field.key.clone() } else { Value::String(field.key.clone()).to_string() };
+                let optional = if field.optional { "?" } else { "" };
+                block.push_str(&format!("  {}{}: {};\n", name, optional, Self::typescript_type(model, &field.slot)));
+            }
+            block.push_str("}\n");
+            blocks.push(block);
+        }
+        blocks.join("\n")
+    }
+
+    fn typescript_type(model: &Model, slot: &Slot) -> String {
+        let inner = match &slot.ty {
+            TypeRef::Any => return "unknown".to_string(),
+            TypeRef::Bool => "boolean".to_string(),
+            TypeRef::Integer | TypeRef::Unsigned | TypeRef::Float => "number".to_string(),
+            TypeRef::String => "string".to_string(),
+            TypeRef::Array(element) => match Self::typescript_type(model, element) {
+                element if element.contains(' ') => format!("({})[]", element),
+                element => format!("{}[]", element),
+            },
+            TypeRef::Struct(index) => model.structs[*index].name.clone(),
+        };
+        if slot.nullable { format!("{} | null", inner) } else { inner }
+    }
+
+    fn emit_go(model: &Model) -> String {
+        let mut blocks = Vec::new();
+        if let Some(root) = Self::root_alias(model) {
+            blocks.push(format!("type {} {}\n", model.root_name, Self::go_type(model, root)));
+        }
+        for definition in &model.structs {
+            let mut used = HashSet::new();
+            let rows: Vec<(String, String, String)> = definition
+                .fields
+                .iter()
+                .map(|field| {
+                    let name = Self::dedupe(&mut used, Self::go_field_name(&field.key));
+                    let slot = Slot { ty: field.slot.ty.clone(), nullable: field.slot.nullable || field.optional };
+                    let options = if field.optional { ",omitempty" } else { "" };
+                    let tag = format!("`json:{}`", Value::String(format!("{}{}", field.key, options)));
+                    (name, Self::go_type(model, &slot), tag)
+                })
+                .collect();
+
+            // Align names and types into columns the way gofmt does
+            let name_width = rows.iter().map(|(name, _, _)| name.chars().count()).max().unwrap_or(0);
+            let type_width = rows.iter().map(|(_, ty, _)| ty.chars().count()).max().unwrap_or(0);
+            let mut block = format!("type {} struct {{\n", definition.name);
+            for (name, ty, tag) in rows {
+                block.push_str(&format!("\t{:name_width$} {:type_width$} {}\n", name, ty, tag));
+            }
+            block.push_str("}\n");
+            blocks.push(block);
+        }
+        blocks.join("\n")
+    }
+
+    fn go_type(model: &Model, slot: &Slot) -> String {
+        let inner = match &slot.ty {
+            // Interfaces and slices are already nil-able, so they never get a pointer
+            TypeRef::Any => return "any".to_string(),
+            TypeRef::Array(element) => return format!("[]{}", Self::go_type(model, element)),
+            TypeRef::Bool => "bool".to_string(),
+            TypeRef::Integer => "int64".to_string(),
+            TypeRef::Unsigned => "uint64".to_string(),
+            TypeRef::Float => "float64".to_string(),
+            TypeRef::String => "string".to_string(),
+            TypeRef::Struct(index) => model.structs[*index].name.clone(),
+        };
+        if slot.nullable { format!("*{}", inner) } else { inner }
+    }
+
+    fn go_field_name(key: &str) -> String {
+        let name: String = Self::words(key)
+            .iter()
+            .map(|word| {
+                let lower = word.to_lowercase();
+                if GO_INITIALISMS.contains(&lower.as_str()) { lower.to_uppercase() } else { Self::capitalize(&lower) }
+            })
+            .collect();
+        if name.starts_with(|c: char| c.is_ascii_alphabetic()) { name } else { format!("Field{}", name) }
+    }
+
+    /// Append a number to a name already used in the same struct
+    fn dedupe(used: &mut HashSet<String>, name: String) -> String {
+        let mut candidate = name.clone();
+        let mut suffix = 2;
+        while !used.insert(candidate.clone()) {
+            candidate = format!("{}{}", name, suffix);
+            suffix += 1;
+        }
+        candidate
+    }
+
+    /// Split an arbitrary key into words at separators and case changes: `userID2Name` → user, ID2, Name
+    fn words(key: &str) -> Vec<String> {
+        let chars: Vec<char> = key.chars().collect();
+        let mut words = Vec::new();
+        let mut current = String::new();
+        for (index, &c) in chars.iter().enumerate() {
+            if !c.is_alphanumeric() {
+                if !current.is_empty() {
+                    words.push(std::mem::take(&mut current));
+                }
+                continue;
+            }
+            if c.is_uppercase() && !current.is_empty() {
+                let previous = chars[index - 1];
+                let next_is_lower = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+                // `userName` splits before N; `HTTPServer` splits before the S that starts `Server`
+                if previous.is_lowercase() || previous.is_ascii_digit() || (previous.is_uppercase() && next_is_lower) {
+                    words.push(std::mem::take(&mut current));
+                }
+            }
+            current.push(c);
+        }
+        if !current.is_empty() {
+            words.push(current);
+        }
+        words
+    }
+
+    fn snake_case(key: &str) -> String {
+        Self::words(key).iter().map(|word| word.to_lowercase()).collect::<Vec<_>>().join("_")
+    }
+
+    fn pascal_case(key: &str) -> String {
+        let name: String = Self::words(key).iter().map(|word| Self::capitalize(&word.to_lowercase())).collect();
+        if name.starts_with(|c: char| c.is_ascii_digit()) { format!("Type{}", name) } else { name }
+    }
+
+    fn capitalize(word: &str) -> String {
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        }
+    }
+
+    /// Type name for the elements of a collection: `Users` → `User`, `Categories` → `Category`, `Data` → `DataItem`
+    fn singular(name: &str) -> String {
+        if let Some(stem) = name.strip_suffix("ies").filter(|stem| !stem.is_empty()) {
+            format!("{}y", stem)
+        } else if let Some(stem) = ["sses", "xes", "ches", "shes"].iter().find_map(|suffix| {
+            name.strip_suffix(suffix).map(|stem| format!("{}{}", stem, &suffix[..suffix.len() - 2]))
+        }) {
+            stem
+        } else if name.len() > 1 && name.ends_with('s') && !name.ends_with("ss") {
+            name[..name.len() - 1].to_string()
+        } else {
+            format!("{}Item", name)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn model_of(sample: Value) -> Model {
+        CodegenLogic::model(&[sample], "Root")
+    }
+
+    fn field<'a>(model: &'a Model, struct_name: &str, key: &str) -> &'a Field {
+        let definition = model.structs.iter().find(|definition| definition.name == struct_name).unwrap();
+        definition.fields.iter().find(|field| field.key == key).unwrap()
+    }
+
+    fn slot(ty: TypeRef, nullable: bool) -> Slot {
+        Slot { ty, nullable }
+    }
+
+    #[test]
+    fn test_walker_scalars() {
+        let model = model_of(json!({
+            "a": true, "b": 1, "c": 1.5, "d": "x", "e": null, "f": 18446744073709551615u64, "g": [1, "x"],
+            "h": [1, 18446744073709551615u64], "i": [-1, 18446744073709551615u64]
+        }));
+        let types: Vec<&TypeRef> = model.structs[0].fields.iter().map(|field| &field.slot.ty).collect();
+        assert_eq!(
+            types,
+            vec![
+                &TypeRef::Bool,
+                &TypeRef::Integer,
+                &TypeRef::Float,
+                &TypeRef::String,
+                &TypeRef::Any,
+                &TypeRef::Unsigned,
+                &TypeRef::Array(Box::new(slot(TypeRef::Any, false))),
+                &TypeRef::Array(Box::new(slot(TypeRef::Unsigned, false))),
+                &TypeRef::Array(Box::new(slot(TypeRef::Float, false))),
+            ]
+        );
+        assert!(field(&model, "Root", "e").slot.nullable);
+        assert_eq!(model.root, slot(TypeRef::Struct(0), false));
+    }
+
+    #[test]
+    fn test_walker_merges_array_elements() {
+        let model = model_of(json!({
+            "users": [
+                { "id": 1, "score": 2, "nickname": "a" },
+                { "id": 2, "score": 2.5, "nickname": null, "manager": { "id": 9 } }
+            ]
+        }));
+        let names: Vec<&str> = model.structs.iter().map(|definition| definition.name.as_str()).collect();
+        assert_eq!(names, vec!["Root", "User", "Manager"]);
+        assert_eq!(field(&model, "Root", "users").slot.ty, TypeRef::Array(Box::new(slot(TypeRef::Struct(1), false))));
+        assert_eq!(field(&model, "User", "score").slot, slot(TypeRef::Float, false));
+        assert_eq!(field(&model, "User", "nickname").slot, slot(TypeRef::String, true));
+        assert!(!field(&model, "User", "nickname").optional);
+        assert!(field(&model, "User", "manager").optional);
+        assert!(!field(&model, "User", "id").optional);
+    }
+
+    #[test]
+    fn test_walker_merges_samples_and_names() {
+        let model = CodegenLogic::model(&[json!({ "item": { "a": 1 } }), json!({ "item": { "b": 2 }, "extra": [[1]] })], "api response");
+        assert_eq!(model.structs[0].name, "ApiResponse");
+        assert_eq!(model.structs[1].name, "Item");
+        assert!(field(&model, "Item", "a").optional && field(&model, "Item", "b").optional);
+        let nested = TypeRef::Array(Box::new(slot(TypeRef::Array(Box::new(slot(TypeRef::Integer, false))), false)));
+        assert_eq!(field(&model, "ApiResponse", "extra").slot.ty, nested);
+
+        // Same key at two depths gets distinct struct names
+        let model = model_of(json!({ "data": { "data": { "x": 1 } } }));
+        let names: Vec<&str> = model.structs.iter().map(|definition| definition.name.as_str()).collect();
+        assert_eq!(names, vec!["Root", "Data", "Data2"]);
+    }
+
+    #[test]
+    fn test_words_and_cases() {
+        assert_eq!(CodegenLogic::words("userID2Name"), vec!["user", "ID2", "Name"]);
+        assert_eq!(CodegenLogic::snake_case("HTTPServer-url"), "http_server_url");
+        assert_eq!(CodegenLogic::snake_case("already_snake"), "already_snake");
+        assert_eq!(CodegenLogic::pascal_case("line items"), "LineItems");
+        assert_eq!(CodegenLogic::singular("Categories"), "Category");
+        assert_eq!(CodegenLogic::singular("Addresses"), "Address");
+        assert_eq!(CodegenLogic::singular("Boxes"), "Box");
+        assert_eq!(CodegenLogic::singular("Data"), "DataItem");
+        assert_eq!(CodegenLogic::rust_field_name("type"), "r#type");
+        assert_eq!(CodegenLogic::rust_field_name("self"), "self_");
+        assert_eq!(CodegenLogic::rust_field_name("2fa"), "field_2fa");
+        assert_eq!(CodegenLogic::rust_field_name("$"), "field");
+        assert_eq!(CodegenLogic::go_field_name("userId"), "UserID");
+        assert_eq!(CodegenLogic::go_field_name("api_url"), "APIURL");
+        assert_eq!(CodegenLogic::go_field_name("3d"), "Field3d");
+    }
+
+    const SAMPLE: &str = r#"[
+        {"id": 1, "userName": "ann", "type": "admin", "email": null, "tags": ["a"], "profile": {"avatar-url": "x"}},
+        {"id": 2, "userName": "bob", "type": "user", "email": "b@x.io", "tags": [], "profile": {"avatar-url": "y"}, "age": 30}
+    ]"#;
+
+    fn generate(language: CodeLanguage) -> String {
+        let value: Value = serde_json::from_str(SAMPLE).unwrap();
+        CodegenLogic::generate(&[value], "User", language)
+    }
+
+    #[test]
+    fn test_emit_rust() {
+        assert_eq!(
+            generate(CodeLanguage::Rust),
+            r#"use serde::{Deserialize, Serialize};
+
+pub type User = Vec<UserItem>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserItem {
+    pub id: i64,
+    #[serde(rename = "userName")]
+    pub user_name: String,
+    pub r#type: String,
+    pub email: Option<String>,
+    pub tags: Vec<String>,
+    pub profile: Profile,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub age: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Profile {
+    #[serde(rename = "avatar-url")]
+    pub avatar_url: String,
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_emit_typescript() {
+        assert_eq!(
+            generate(CodeLanguage::TypeScript),
+            r#"export type User = UserItem[];
+
+export interface UserItem {
+  id: number;
+  userName: string;
+  type: string;
+  email: string | null;
+  tags: string[];
+  profile: Profile;
+  age?: number;
+}
+
+export interface Profile {
+  "avatar-url": string;
+}
+"#
+        );
+        let scalar_root = CodegenLogic::generate(&[json!(1), json!(null)], "Count", CodeLanguage::TypeScript);
+        assert_eq!(scalar_root, "export type Count = number | null;\n");
+        let nullable_items = CodegenLogic::generate(&[json!({ "xs": [1, null] })], "Root", CodeLanguage::TypeScript);
+        assert!(nullable_items.contains("  xs: (number | null)[];\n"));
+    }
+
+    #[test]
+    fn test_emit_go() {
+        assert_eq!(
+            generate(CodeLanguage::Go),
+            "type User []UserItem\n\n\
+             type UserItem struct {\n\
+             \tID       int64    `json:\"id\"`\n\
+             \tUserName string   `json:\"userName\"`\n\
+             \tType     string   `json:\"type\"`\n\
+             \tEmail    *string  `json:\"email\"`\n\
+             \tTags     []string `json:\"tags\"`\n\
+             \tProfile  Profile  `json:\"profile\"`\n\
+             \tAge      *int64   `json:\"age,omitempty\"`\n\
+             }\n\n\
+             type Profile struct {\n\
+             \tAvatarURL string `json:\"avatar-url\"`\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn test_reserved_type_names() {
+        let rust = CodegenLogic::generate(
+            &[json!({ "string": { "a": "x" }, "vec": { "a": [1] }, "option": { "a": null }, "serialize": { "a": true } })],
+            "Root",
+            CodeLanguage::Rust,
+        );
+        assert!(rust.contains("    pub string: String2,\n    pub vec: Vec2,\n    pub option: Option2,\n    pub serialize: Serialize2,\n"));
+        assert!(rust.contains("pub struct String2 {\n    pub a: String,\n}"));
+        assert!(rust.contains("pub struct Vec2 {\n    pub a: Vec<i64>,\n}"));
+        assert!(rust.contains("pub struct Option2 {\n    pub a: serde_json::Value,\n}"));
+        assert!(!rust.contains("struct String {") && !rust.contains("struct Vec {") && !rust.contains("struct Option {"));
+
+        let alias = CodegenLogic::generate(&[json!([1])], "vec", CodeLanguage::Rust);
+        assert!(alias.contains("pub type Vec2 = Vec<i64>;"));
+    }
+
+    #[test]
+    fn test_duplicate_field_names() {
+        let rust = CodegenLogic::generate(&[json!({ "userId": 1, "user_id": 2 })], "Root", CodeLanguage::Rust);
+        assert!(rust.contains("    #[serde(rename = \"userId\")]\n    pub user_id: i64,\n"));
+        assert!(rust.contains("    #[serde(rename = \"user_id\")]\n    pub user_id2: i64,\n"));
+
+        // An array element named like the root alias gets a distinct name
+        let rust = CodegenLogic::generate(&[json!([{ "items": [{ "a": 1 }] }])], "Item", CodeLanguage::Rust);
+        assert!(rust.contains("pub type Item = Vec<ItemItem>;\n"));
+        assert!(rust.contains("pub struct Item2 {\n"));
+    }
+}
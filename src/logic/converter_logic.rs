@@ -0,0 +1,734 @@
+use crate::logic::json_logic::{JsonError, JsonIndent, JsonLogic};
+use crate::logic::json_path_logic::{JsonPathLogic, PathSegment};
+use serde_json::{Map, Number, Value};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DataFormat {
+    #[default]
+    Json,
+    Yaml,
+    Toml,
+    Csv,
+}
+
+impl std::fmt::Display for DataFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataFormat::Json => write!(f, "JSON"),
+            DataFormat::Yaml => write!(f, "YAML"),
+            DataFormat::Toml => write!(f, "TOML"),
+            DataFormat::Csv => write!(f, "CSV"),
+        }
+    }
+}
+
+impl DataFormat {
+    pub const ALL: [DataFormat; 4] = [DataFormat::Json, DataFormat::Yaml, DataFormat::Toml, DataFormat::Csv];
+
+    /// File extension used when saving
+    pub fn extension(self) -> &'static str {
+        match self {
+            DataFormat::Json => "json",
+            DataFormat::Yaml => "yaml",
+            DataFormat::Toml => "toml",
+            DataFormat::Csv => "csv",
+        }
+    }
+
+    /// Guess the format from a file name's extension
+    pub fn from_file_name(name: &str) -> Option<DataFormat> {
+        let (_, extension) = name.rsplit_once('.')?;
+        match extension.to_ascii_lowercase().as_str() {
+            "json" => Some(DataFormat::Json),
+            "yaml" | "yml" => Some(DataFormat::Yaml),
+            "toml" => Some(DataFormat::Toml),
+            "csv" | "tsv" => Some(DataFormat::Csv),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CsvDelimiter {
+    #[default]
+    Comma,
+    Semicolon,
+    Tab,
+}
+
+impl std::fmt::Display for CsvDelimiter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CsvDelimiter::Comma => write!(f, "Comma"),
+            CsvDelimiter::Semicolon => write!(f, "Semicolon"),
+            CsvDelimiter::Tab => write!(f, "Tab"),
+        }
+    }
+}
+
+impl CsvDelimiter {
+    pub const ALL: [CsvDelimiter; 3] = [CsvDelimiter::Comma, CsvDelimiter::Semicolon, CsvDelimiter::Tab];
+
+    fn as_byte(self) -> u8 {
+        match self {
+            CsvDelimiter::Comma => b',',
+            CsvDelimiter::Semicolon => b';',
+            CsvDelimiter::Tab => b'\t',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConvertOptions {
+    pub csv_delimiter: CsvDelimiter,
+    /// Read CSV cells as numbers, booleans and nulls where they look like one
+    pub infer_csv_types: bool,
+    pub json_indent: JsonIndent,
+}
+
+impl Default for ConvertOptions {
+    fn default() -> Self {
+        Self { csv_delimiter: CsvDelimiter::Comma, infer_csv_types: true, json_indent: JsonIndent::TwoSpaces }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConvertError {
+    /// The input is not valid in its declared format
+    Parse { format: DataFormat, message: String },
+    /// The document's shape cannot be expressed in the target format
+    Unsupported { format: DataFormat, message: String },
+}
+
+impl std::fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConvertError::Parse { format, message } => write!(f, "Invalid {}: {}", format, message),
+            ConvertError::Unsupported { format, message } => write!(f, "Cannot write {}: {}", format, message),
+        }
+    }
+}
+
+/// Converted text plus every place where information was dropped or reinterpreted
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conversion {
+    pub output: String,
+    pub warnings: Vec<String>,
+}
+
+pub struct ConverterLogic;
+
+impl ConverterLogic {
+    /// Convert between formats by way of a JSON value
+    pub fn convert(input: &str, from: DataFormat, to: DataFormat, options: ConvertOptions) -> Result<Conversion, ConvertError> {
+        let mut warnings = Vec::new();
+        let value = Self::read(input, from, options, &mut warnings)?;
+        let output = Self::write(&value, to, options, &mut warnings)?;
+        Ok(Conversion { output, warnings })
+    }
+
+    fn read(input: &str, format: DataFormat, options: ConvertOptions, warnings: &mut Vec<String>) -> Result<Value, ConvertError> {
+        let parse_error = |message: String| ConvertError::Parse { format, message };
+        match format {
+            DataFormat::Json => JsonLogic::parse(input).map_err(|JsonError::Syntax { line, column, message }| {
+                parse_error(format!("line {}, column {}: {}", line, column, message))
+            }),
+            DataFormat::Yaml => {
+                let mut yaml: serde_yaml::Value = serde_yaml::from_str(input).map_err(|err| parse_error(err.to_string()))?;
+                yaml.apply_merge().map_err(|err| parse_error(err.to_string()))?;
+                Ok(Self::from_yaml(&yaml, &mut Vec::new(), warnings))
+            }
+            DataFormat::Toml => {
+                let table: toml::Table = input.parse().map_err(|err: toml::de::Error| parse_error(err.to_string()))?;
+                Ok(Self::from_toml(&toml::Value::Table(table), &mut Vec::new(), warnings))
+            }
+            DataFormat::Csv => Self::from_csv(input, options, warnings).map_err(|err| parse_error(err.to_string())),
+        }
+    }
+
+    fn write(value: &Value, format: DataFormat, options: ConvertOptions, warnings: &mut Vec<String>) -> Result<String, ConvertError> {
+        let unsupported = |message: String| ConvertError::Unsupported { format, message };
+        match format {
+            DataFormat::Json => Ok(JsonLogic::to_pretty(value, options.json_indent)),
+            DataFormat::Yaml => {
+                let yaml = Self::to_yaml(value, &mut Vec::new(), warnings);
+                serde_yaml::to_string(&yaml).map_err(|err| unsupported(err.to_string()))
+            }
+            DataFormat::Toml => {
+                let Value::Object(_) = value else {
+                    return Err(unsupported(format!("a TOML document must be a table, not {}", JsonLogic::type_name(value))));
+                };
+                match Self::to_toml(value, &mut Vec::new(), warnings) {
+                    Some(toml::Value::Table(table)) => toml::to_string_pretty(&table).map_err(|err| unsupported(err.to_string())),
+                    _ => Ok(String::new()),
+                }
+            }
+            DataFormat::Csv => Self::to_csv(value, options, warnings).map_err(unsupported),
+        }
+    }
+
+    fn warn(warnings: &mut Vec<String>, location: &[PathSegment], message: &str) {
+        warnings.push(format!("{}: {}", JsonPathLogic::format_path(location), message));
+    }
+
+    /// Integer or float closest to a JSON number, noting when it does not fit
+    fn number_parts(number: &Number) -> Result<NumberPart, &'static str> {
+        if let Some(integer) = number.as_i64() {
+            return Ok(NumberPart::Signed(integer));
+        }
+        if let Some(integer) = number.as_u64() {
+            return Ok(NumberPart::Unsigned(integer));
+        }
+        let text = number.to_string();
+        match number.as_f64() {
+            Some(float) if float.is_finite() => {
+                let is_integer = !text.contains(['.', 'e', 'E']);
+                let mantissa = text.split(['e', 'E']).next().unwrap_or_default();
+                let digits = mantissa.trim_start_matches('-').trim_start_matches(['0', '.']).chars().filter(char::is_ascii_digit).count();
+                if is_integer {
+                    Err("integer is too large and was converted to a float")
+                } else if digits > 17 {
+                    Err("number has more precision than a 64-bit float and was rounded")
+                } else {
+                    Ok(NumberPart::Float(float))
+                }
+            }
+            _ => Err("number is out of range for a 64-bit float"),
+        }
+    }
+
+    fn number_as_f64(number: &Number) -> f64 {
+        number.as_f64().unwrap_or(f64::NAN)
+    }
+
+    fn from_f64(float: f64, location: &[PathSegment], warnings: &mut Vec<String>) -> Value {
+        match Number::from_f64(float) {
+            Some(number) => Value::Number(number),
+            None => {
+                Self::warn(warnings, location, &format!("JSON has no {}; written as null", float));
+                Value::Null
+            }
+        }
+    }
+
+    fn from_yaml(yaml: &serde_yaml::Value, location: &mut Vec<PathSegment>, warnings: &mut Vec<String>) -> Value {
+        match yaml {
+            serde_yaml::Value::Null => Value::Null,
+            serde_yaml::Value::Bool(flag) => Value::Bool(*flag),
+            serde_yaml::Value::Number(number) => {
+                if let Some(integer) = number.as_i64() {
+                    Value::from(integer)
+                } else if let Some(integer) = number.as_u64() {
+                    Value::from(integer)
+                } else {
+                    Self::from_f64(number.as_f64().unwrap_or(f64::NAN), location, warnings)
+                }
+            }
+            serde_yaml::Value::String(text) => Value::String(text.clone()),
+            serde_yaml::Value::Sequence(items) => Value::Array(
+                items
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| {
+                        location.push(PathSegment::Index(index));
+                        let value = Self::from_yaml(item, location, warnings);
+                        location.pop();
+                        value
+                    })
+                    .collect(),
+            ),
+            serde_yaml::Value::Mapping(mapping) => {
+                let mut map = Map::new();
+                for (key, item) in mapping {
+                    let key = match key {
+                        serde_yaml::Value::String(key) => key.clone(),
+                        serde_yaml::Value::Number(number) => number.to_string(),
+                        serde_yaml::Value::Bool(flag) => flag.to_string(),
+                        serde_yaml::Value::Null => "null".to_string(),
+                        other => {
+                            let text = serde_yaml::to_string(other).unwrap_or_default().trim_end().to_string();
+                            location.push(PathSegment::Key(text.clone()));
+                            Self::warn(warnings, location, "complex mapping key was converted to a string");
+                            location.pop();
+                            text
+                        }
+                    };
+                    location.push(PathSegment::Key(key.clone()));
+                    let value = Self::from_yaml(item, location, warnings);
+                    location.pop();
+                    map.insert(key, value);
+                }
+                Value::Object(map)
+            }
+            serde_yaml::Value::Tagged(tagged) => {
+                Self::warn(warnings, location, &format!("YAML tag {} was dropped", tagged.tag));
+                Self::from_yaml(&tagged.value, location, warnings)
+            }
+        }
+    }
+
+    fn to_yaml(value: &Value, location: &mut Vec<PathSegment>, warnings: &mut Vec<String>) -> serde_yaml::Value {
+        match value {
+            Value::Null => serde_yaml::Value::Null,
+            Value::Bool(flag) => serde_yaml::Value::Bool(*flag),
+            Value::Number(number) => match Self::number_parts(number) {
+                Ok(NumberPart::Signed(integer)) => serde_yaml::Value::from(integer),
+                Ok(NumberPart::Unsigned(integer)) => serde_yaml::Value::from(integer),
+                Ok(NumberPart::Float(float)) => serde_yaml::Value::from(float),
+                Err(message) => {
+                    Self::warn(warnings, location, message);
+                    serde_yaml::Value::from(Self::number_as_f64(number))
+                }
+            },
+            Value::String(text) => serde_yaml::Value::String(text.clone()),
+            Value::Array(items) => serde_yaml::Value::Sequence(
+                items
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| {
+                        location.push(PathSegment::Index(index));
+                        let yaml = Self::to_yaml(item, location, warnings);
+                        location.pop();
+                        yaml
+                    })
+                    .collect(),
+            ),
+            Value::Object(map) => serde_yaml::Value::Mapping(
+                map.iter()
+                    .map(|(key, item)| {
+                        location.push(PathSegment::Key(key.clone()));
+                        let yaml = Self::to_yaml(item, location, warnings);
+                        location.pop();
+                        (serde_yaml::Value::String(key.clone()), yaml)
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    fn from_toml(toml: &toml::Value, location: &mut Vec<PathSegment>, warnings: &mut Vec<String>) -> Value {
+        match toml {
+            toml::Value::String(text) => Value::String(text.clone()),
+            toml::Value::Integer(integer) => Value::from(*integer),
+            toml::Value::Float(float) => Self::from_f64(*float, location, warnings),
+            toml::Value::Boolean(flag) => Value::Bool(*flag),
+            toml::Value::Datetime(datetime) => {
+                Self::warn(warnings, location, "TOML datetime was converted to a string");
+                Value::String(datetime.to_string())
+            }
+            toml::Value::Array(items) => Value::Array(
+                items
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| {
+                        location.push(PathSegment::Index(index));
+                        let value = Self::from_toml(item, location, warnings);
+                        location.pop();
+                        value
+                    })
+                    .collect(),
+            ),
+            toml::Value::Table(table) => Value::Object(
+                table
+                    .iter()
+                    .map(|(key, item)| {
+                        location.push(PathSegment::Key(key.clone()));
+                        let value = Self::from_toml(item, location, warnings);
+                        location.pop();
+                        (key.clone(), value)
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    /// TOML value for a JSON value, or `None` for a null that has to be dropped
+    fn to_toml(value: &Value, location: &mut Vec<PathSegment>, warnings: &mut Vec<String>) -> Option<toml::Value> {
+        Some(match value {
+            Value::Null => {
+                Self::warn(warnings, location, "TOML has no null; the value was dropped");
+                return None;
+            }
+            Value::Bool(flag) => toml::Value::Boolean(*flag),
+            Value::Number(number) => match Self::number_parts(number) {
+                Ok(NumberPart::Signed(integer)) => toml::Value::Integer(integer),
+                Ok(NumberPart::Unsigned(integer)) => {
+                    Self::warn(warnings, location, "integer is larger than TOML allows and was converted to a float");
+                    toml::Value::Float(integer as f64)
+                }
+                Ok(NumberPart::Float(float)) => toml::Value::Float(float),
+                Err(message) => {
+                    Self::warn(warnings, location, message);
+                    toml::Value::Float(Self::number_as_f64(number))
+                }
+            },
+            Value::String(text) => toml::Value::String(text.clone()),
+            Value::Array(items) => toml::Value::Array(
+                items
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, item)| {
+                        location.push(PathSegment::Index(index));
+                        let toml = Self::to_toml(item, location, warnings);
+                        location.pop();
+                        toml
+                    })
+                    .collect(),
+            ),
+            Value::Object(map) => toml::Value::Table(
+                map.iter()
+                    .filter_map(|(key, item)| {
+                        location.push(PathSegment::Key(key.clone()));
+                        let toml = Self::to_toml(item, location, warnings);
+                        location.pop();
+                        Some((key.clone(), toml?))
+                    })
+                    .collect(),
+            ),
+        })
+    }
+
+    /// Rows of an array of objects, with nested keys flattened to dotted column names
+    fn to_csv(value: &Value, options: ConvertOptions, warnings: &mut Vec<String>) -> Result<String, String> {
+        let rows: Vec<&Map<String, Value>> = match value {
+            Value::Object(map) => vec![map],
+            Value::Array(items) => items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    item.as_object()
+                        .ok_or_else(|| format!("CSV needs an array of objects, but item {} is {}", index, JsonLogic::type_name(item)))
+                })
+                .collect::<Result<_, _>>()?,
+            other => return Err(format!("CSV needs an array of objects, not {}", JsonLogic::type_name(other))),
+        };
+
+        let mut headers: Vec<String> = Vec::new();
+        let mut header_index = std::collections::HashMap::new();
+        let mut flattened_rows = Vec::with_capacity(rows.len());
+        let mut retyped_columns = Vec::new();
+        let mut text_columns = Vec::new();
+        let mut dotted_keys = Vec::new();
+        for row in &rows {
+            let mut cells = Vec::new();
+            Self::flatten(row, String::new(), &mut cells, &mut dotted_keys);
+            for (column, _) in &cells {
+                if !header_index.contains_key(column) {
+                    header_index.insert(column.clone(), headers.len());
+                    headers.push(column.clone());
+                }
+            }
+            flattened_rows.push(cells);
+        }
+
+        if headers.is_empty() {
+            return Ok(String::new());
+        }
+
+        let mut writer = csv::WriterBuilder::new().delimiter(options.csv_delimiter.as_byte()).from_writer(Vec::new());
+        writer.write_record(&headers).map_err(|err| err.to_string())?;
+        for cells in flattened_rows {
+            let mut record = vec![String::new(); headers.len()];
+            for (column, cell) in cells {
+                let text = match cell {
+                    Value::Null => String::new(),
+                    Value::String(text) => {
+                        if options.infer_csv_types && !matches!(Self::infer_cell(text), Value::String(_)) && !retyped_columns.contains(&column) {
+                            retyped_columns.push(column.clone());
+                        }
+                        text.clone()
+                    }
+                    Value::Array(_) | Value::Object(_) => {
+                        if !text_columns.contains(&column) {
+                            text_columns.push(column.clone());
+                        }
+                        JsonLogic::to_minified(cell)
+                    }
+                    other => JsonLogic::to_minified(other),
+                };
+                record[header_index[&column]] = text;
+            }
+            writer.write_record(&record).map_err(|err| err.to_string())?;
+        }
+
+        for column in retyped_columns {
+            warnings.push(format!("Column {}: some strings look like numbers, booleans or empty cells and will read back as typed values", column));
+        }
+        for column in text_columns {
+            warnings.push(format!("Column {}: empty arrays or objects were written as JSON text", column));
+        }
+        for key in dotted_keys {
+            warnings.push(format!("Key {}: contains '.', so it will read back as a nested key", key));
+        }
+
+        let bytes = writer.into_inner().map_err(|err| err.to_string())?;
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    /// Leaf values of an object keyed by dotted paths such as `owner.tags.0`
+    fn flatten<'a>(map: &'a Map<String, Value>, prefix: String, cells: &mut Vec<(String, &'a Value)>, dotted_keys: &mut Vec<String>) {
+        for (key, value) in map {
+            if key.contains('.') && !dotted_keys.contains(key) {
+                dotted_keys.push(key.clone());
+            }
+            let column = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+            Self::flatten_value(value, column, cells, dotted_keys);
+        }
+    }
+
+    fn flatten_value<'a>(value: &'a Value, column: String, cells: &mut Vec<(String, &'a Value)>, dotted_keys: &mut Vec<String>) {
+        match value {
+            Value::Object(map) if !map.is_empty() => Self::flatten(map, column, cells, dotted_keys),
+            Value::Array(items) if !items.is_empty() => {
+                for (index, item) in items.iter().enumerate() {
+                    Self::flatten_value(item, format!("{}.{}", column, index), cells, dotted_keys);
+                }
+            }
+            leaf => cells.push((column, leaf)),
+        }
+    }
+
+    /// Objects built from a header row, nesting dotted column names
+    fn from_csv(input: &str, options: ConvertOptions, warnings: &mut Vec<String>) -> Result<Value, csv::Error> {
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(options.csv_delimiter.as_byte())
+            .flexible(true)
+            .from_reader(input.as_bytes());
+        let headers = reader.headers()?.clone();
+        let mut rows = Vec::new();
+        for (row_index, record) in reader.records().enumerate() {
+            let record = record?;
+            if record.len() > headers.len() {
+                warnings.push(format!("Row {}: {} extra cells dropped", row_index + 1, record.len() - headers.len()));
+            }
+            let mut row = Value::Object(Map::new());
+            for (column, cell) in headers.iter().zip(record.iter()) {
+                let value = if options.infer_csv_types { Self::infer_cell(cell) } else { Value::String(cell.to_string()) };
+                if !Self::insert_nested(&mut row, column, value.clone()) {
+                    warnings.push(format!(
+                        "Row {}, column {}: conflicts with another column's nesting and was kept as a flat key",
+                        row_index + 1,
+                        column
+                    ));
+                    if let Value::Object(map) = &mut row {
+                        map.insert(column.to_string(), value);
+                    }
+                }
+            }
+            Self::arrays_from_indices(&mut row);
+            rows.push(row);
+        }
+        Ok(Value::Array(rows))
+    }
+
+    /// Place a value at a dotted path, returning false if the path runs into an existing leaf
+    fn insert_nested(root: &mut Value, column: &str, value: Value) -> bool {
+        let mut node = root;
+        let mut parts = column.split('.').peekable();
+        while let Some(part) = parts.next() {
+            let Value::Object(map) = node else {
+                return false;
+            };
+            if parts.peek().is_none() {
+                if map.contains_key(part) {
+                    return false;
+                }
+                map.insert(part.to_string(), value);
+                return true;
+            }
+            node = map.entry(part.to_string()).or_insert_with(|| Value::Object(Map::new()));
+        }
+        false
+    }
+
+    /// Turn objects keyed exactly `0..n` back into arrays
+    fn arrays_from_indices(value: &mut Value) {
+        let Value::Object(map) = value else {
+            return;
+        };
+        map.values_mut().for_each(Self::arrays_from_indices);
+        let is_sequence = !map.is_empty() && map.keys().enumerate().all(|(index, key)| key.parse::<usize>().ok() == Some(index));
+        if is_sequence {
+            let items = std::mem::take(map).into_iter().map(|(_, item)| item).collect();
+            *value = Value::Array(items);
+        }
+    }
+
+    /// Typed value for a CSV cell; anything that is not valid JSON number syntax stays a string
+    fn infer_cell(cell: &str) -> Value {
+        match cell {
+            "" => Value::Null,
+            "true" => Value::Bool(true),
+            "false" => Value::Bool(false),
+            _ => match serde_json::from_str::<Number>(cell) {
+                Ok(number) => Value::Number(number),
+                Err(_) => Value::String(cell.to_string()),
+            },
+        }
+    }
+}
+
+enum NumberPart {
+    Signed(i64),
+    Unsigned(u64),
+    Float(f64),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn convert(input: &str, from: DataFormat, to: DataFormat) -> Conversion {
+        ConverterLogic::convert(input, from, to, ConvertOptions::default()).unwrap()
+    }
+
+    fn to_json(input: &str, from: DataFormat) -> (Value, Vec<String>) {
+        let conversion = convert(input, from, DataFormat::Json);
+        (JsonLogic::parse(&conversion.output).unwrap(), conversion.warnings)
+    }
+
+    const CONFIG: &str = r#"{"name": "api", "port": 8080, "ratio": 0.25, "debug": false, "tags": ["a", "b"], "db": {"host": "localhost", "pool": {"max": 10}}}"#;
+
+    #[test]
+    fn test_round_trip_yaml_and_toml() {
+        let original = JsonLogic::parse(CONFIG).unwrap();
+        for format in [DataFormat::Yaml, DataFormat::Toml] {
+            let converted = convert(CONFIG, DataFormat::Json, format);
+            assert!(converted.warnings.is_empty(), "{}: {:?}", format, converted.warnings);
+            let (back, warnings) = to_json(&converted.output, format);
+            assert!(warnings.is_empty());
+            assert_eq!(back, original, "{}", format);
+        }
+    }
+
+    #[test]
+    fn test_yaml_output_and_input() {
+        let yaml = convert(r#"{"name": "api", "ports": [80, 443], "env": {"DEBUG": null}}"#, DataFormat::Json, DataFormat::Yaml).output;
+        assert_eq!(yaml, "name: api\nports:\n- 80\n- 443\nenv:\n  DEBUG: null\n");
+
+        let input = "base: &base\n  retries: 3\nservice:\n  <<: *base\n  name: web\n1: one\nwhen: !custom 5\nbig: .inf\n";
+        let (value, warnings) = to_json(input, DataFormat::Yaml);
+        assert_eq!(value["service"], json!({ "retries": 3, "name": "web" }));
+        assert_eq!(value["1"], json!("one"));
+        assert_eq!(value["when"], json!(5));
+        assert_eq!(value["big"], Value::Null);
+        assert_eq!(warnings, vec!["$.when: YAML tag !custom was dropped", "$.big: JSON has no inf; written as null"]);
+    }
+
+    #[test]
+    fn test_toml_lossy_conversions() {
+        let input = r#"{"name": "api", "owner": null, "hosts": ["a", null, "b"], "huge": 18446744073709551615}"#;
+        let converted = convert(input, DataFormat::Json, DataFormat::Toml);
+        assert_eq!(
+            converted.warnings,
+            vec![
+                "$.owner: TOML has no null; the value was dropped",
+                "$.hosts[1]: TOML has no null; the value was dropped",
+                "$.huge: integer is larger than TOML allows and was converted to a float",
+            ]
+        );
+        let (value, _) = to_json(&converted.output, DataFormat::Toml);
+        assert_eq!(value["hosts"], json!(["a", "b"]));
+        assert!(value.get("owner").is_none());
+
+        let (value, warnings) = to_json("released = 2024-05-01T12:00:00Z\n", DataFormat::Toml);
+        assert_eq!(value["released"], json!("2024-05-01T12:00:00Z"));
+        assert_eq!(warnings, vec!["$.released: TOML datetime was converted to a string"]);
+    }
+
+    #[test]
+    fn test_toml_needs_table() {
+        let result = ConverterLogic::convert("[1, 2]", DataFormat::Json, DataFormat::Toml, ConvertOptions::default());
+        assert!(matches!(result, Err(ConvertError::Unsupported { format: DataFormat::Toml, .. })));
+    }
+
+    #[test]
+    fn test_number_precision_warnings() {
+        let converted = convert(r#"{"id": 123456789012345678901234, "pi": 3.14159265358979323846, "f": 2.50}"#, DataFormat::Json, DataFormat::Yaml);
+        assert_eq!(
+            converted.warnings,
+            vec![
+                "$.id: integer is too large and was converted to a float",
+                "$.pi: number has more precision than a 64-bit float and was rounded",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_csv_flattening_and_header_inference() {
+        let input = r#"[
+            {"id": 1, "name": "Ann", "address": {"city": "Oslo", "zip": "0150"}, "tags": ["x", "y"]},
+            {"id": 2, "name": "Bob, Jr.", "active": true, "address": {"city": "Bergen"}}
+        ]"#;
+        let converted = convert(input, DataFormat::Json, DataFormat::Csv);
+        assert_eq!(
+            converted.output,
+            "id,name,address.city,address.zip,tags.0,tags.1,active\n1,Ann,Oslo,0150,x,y,\n2,\"Bob, Jr.\",Bergen,,,,true\n"
+        );
+        assert!(converted.warnings.is_empty());
+
+        let (value, _) = to_json(&converted.output, DataFormat::Csv);
+        assert_eq!(value[0], json!({ "id": 1, "name": "Ann", "address": { "city": "Oslo", "zip": "0150" }, "tags": ["x", "y"], "active": null }));
+        assert_eq!(value[1]["name"], json!("Bob, Jr."));
+        assert_eq!(value[1]["active"], json!(true));
+    }
+
+    #[test]
+    fn test_csv_type_inference_options() {
+        let csv = "id;code;flag\n7;007;false\n";
+        let options = ConvertOptions { csv_delimiter: CsvDelimiter::Semicolon, ..ConvertOptions::default() };
+        let typed = ConverterLogic::convert(csv, DataFormat::Csv, DataFormat::Json, options).unwrap();
+        assert_eq!(JsonLogic::parse(&typed.output).unwrap(), json!([{ "id": 7, "code": "007", "flag": false }]));
+
+        let options = ConvertOptions { infer_csv_types: false, ..options };
+        let text = ConverterLogic::convert(csv, DataFormat::Csv, DataFormat::Json, options).unwrap();
+        assert_eq!(JsonLogic::parse(&text.output).unwrap(), json!([{ "id": "7", "code": "007", "flag": "false" }]));
+    }
+
+    #[test]
+    fn test_csv_lossy_warnings() {
+        let input = r#"[{"zip": "1234", "meta": {}, "a.b": 1, "note": "ok"}]"#;
+        let converted = convert(input, DataFormat::Json, DataFormat::Csv);
+        assert_eq!(
+            converted.warnings,
+            vec![
+                "Column zip: some strings look like numbers, booleans or empty cells and will read back as typed values",
+                "Column meta: empty arrays or objects were written as JSON text",
+                "Key a.b: contains '.', so it will read back as a nested key",
+            ]
+        );
+
+        let (value, warnings) = to_json("a,a.b\n1,2\n", DataFormat::Csv);
+        assert_eq!(value, json!([{ "a": 1, "a.b": 2 }]));
+        assert_eq!(warnings, vec!["Row 1, column a.b: conflicts with another column's nesting and was kept as a flat key"]);
+
+        let (value, warnings) = to_json("a,b\n1,2,3,4\n5\n", DataFormat::Csv);
+        assert_eq!(value, json!([{ "a": 1, "b": 2 }, { "a": 5 }]));
+        assert_eq!(warnings, vec!["Row 1: 2 extra cells dropped"]);
+    }
+
+    #[test]
+    fn test_csv_needs_objects() {
+        let result = ConverterLogic::convert("[1, 2]", DataFormat::Json, DataFormat::Csv, ConvertOptions::default());
+        assert_eq!(
+            result,
+            Err(ConvertError::Unsupported { format: DataFormat::Csv, message: "CSV needs an array of objects, but item 0 is number".to_string() })
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for (input, format) in [("{", DataFormat::Json), ("a: [", DataFormat::Yaml), ("a = ", DataFormat::Toml)] {
+            let result = ConverterLogic::convert(input, format, DataFormat::Json, ConvertOptions::default());
+            assert!(matches!(result, Err(ConvertError::Parse { format: failed, .. }) if failed == format), "{}", format);
+        }
+    }
+
+    #[test]
+    fn test_format_from_file_name() {
+        assert_eq!(DataFormat::from_file_name("config.YML"), Some(DataFormat::Yaml));
+        assert_eq!(DataFormat::from_file_name("Cargo.toml"), Some(DataFormat::Toml));
+        assert_eq!(DataFormat::from_file_name("README"), None);
+    }
+}
@@ -0,0 +1,293 @@
+use crate::logic::base64_logic::{Base64Logic, Base64Variant};
+use sha2::{Digest, Sha224, Sha256, Sha384, Sha512, Sha512_256};
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+#[derive(Debug, PartialEq)]
+pub enum HashError {
+    Io(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HashAlgorithm {
+    Md5,
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+    Sha512_256,
+}
+
+impl std::fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HashAlgorithm::Md5 => write!(f, "MD5"),
+            HashAlgorithm::Sha224 => write!(f, "SHA-224"),
+            HashAlgorithm::Sha256 => write!(f, "SHA-256"),
+            HashAlgorithm::Sha384 => write!(f, "SHA-384"),
+            HashAlgorithm::Sha512 => write!(f, "SHA-512"),
+            HashAlgorithm::Sha512_256 => write!(f, "SHA-512/256"),
+        }
+    }
+}
+
+impl HashAlgorithm {
+    pub const ALL: [HashAlgorithm; 6] = [
+        HashAlgorithm::Md5,
+        HashAlgorithm::Sha224,
+        HashAlgorithm::Sha256,
+        HashAlgorithm::Sha384,
+        HashAlgorithm::Sha512,
+        HashAlgorithm::Sha512_256,
+    ];
+
+    /// Internal block size in bytes, as used by HMAC key padding
+    pub fn block_size(self) -> usize {
+        match self {
+            HashAlgorithm::Md5 | HashAlgorithm::Sha224 | HashAlgorithm::Sha256 => 64,
+            HashAlgorithm::Sha384 | HashAlgorithm::Sha512 | HashAlgorithm::Sha512_256 => 128,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DigestEncoding {
+    #[default]
+    Hex,
+    Base64,
+}
+
+impl std::fmt::Display for DigestEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DigestEncoding::Hex => write!(f, "Hex"),
+            DigestEncoding::Base64 => write!(f, "Base64"),
+        }
+    }
+}
+
+impl DigestEncoding {
+    pub const ALL: [DigestEncoding; 2] = [DigestEncoding::Hex, DigestEncoding::Base64];
+}
+
+/// Digest of one algorithm over the same input
+#[derive(Debug, Clone, PartialEq)]
+pub struct HashResult {
+    pub algorithm: HashAlgorithm,
+    pub digest: Vec<u8>,
+}
+
+/// Incremental hasher for any supported algorithm
+enum HashState {
+    Md5(md5::Context),
+    Sha224(Sha224),
+    Sha256(Sha256),
+    Sha384(Sha384),
+    Sha512(Sha512),
+    Sha512_256(Sha512_256),
+}
+
+impl HashState {
+    fn new(algorithm: HashAlgorithm) -> Self {
+        match algorithm {
+            HashAlgorithm::Md5 => HashState::Md5(md5::Context::new()),
+            HashAlgorithm::Sha224 => HashState::Sha224(Sha224::new()),
+            HashAlgorithm::Sha256 => HashState::Sha256(Sha256::new()),
+            HashAlgorithm::Sha384 => HashState::Sha384(Sha384::new()),
+            HashAlgorithm::Sha512 => HashState::Sha512(Sha512::new()),
+            HashAlgorithm::Sha512_256 => HashState::Sha512_256(Sha512_256::new()),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            HashState::Md5(context) => context.consume(data),
+            HashState::Sha224(hasher) => hasher.update(data),
+            HashState::Sha256(hasher) => hasher.update(data),
+            HashState::Sha384(hasher) => hasher.update(data),
+            HashState::Sha512(hasher) => hasher.update(data),
+            HashState::Sha512_256(hasher) => hasher.update(data),
+        }
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        match self {
+            HashState::Md5(context) => context.finalize().0.to_vec(),
+            HashState::Sha224(hasher) => hasher.finalize().to_vec(),
+            HashState::Sha256(hasher) => hasher.finalize().to_vec(),
+            HashState::Sha384(hasher) => hasher.finalize().to_vec(),
+            HashState::Sha512(hasher) => hasher.finalize().to_vec(),
+            HashState::Sha512_256(hasher) => hasher.finalize().to_vec(),
+        }
+    }
+}
+
+pub struct HashLogic;
+
+impl HashLogic {
+    const READ_CHUNK_SIZE: usize = 64 * 1024;
+
+    /// Hash bytes with a single algorithm
+    pub fn digest(algorithm: HashAlgorithm, data: &[u8]) -> Vec<u8> {
+        let mut state = HashState::new(algorithm);
+        state.update(data);
+        state.finalize()
+    }
+
+    /// Hash bytes with every supported algorithm
+    pub fn digest_all(data: &[u8]) -> Vec<HashResult> {
+        HashAlgorithm::ALL
+            .into_iter()
+            .map(|algorithm| HashResult { algorithm, digest: Self::digest(algorithm, data) })
+            .collect()
+    }
+
+    /// Hash a stream with the given algorithms, reading it only once
+    pub fn digest_reader<R: Read>(mut reader: R, algorithms: &[HashAlgorithm]) -> Result<Vec<HashResult>, HashError> {
+        let mut states: Vec<HashState> = algorithms.iter().map(|algorithm| HashState::new(*algorithm)).collect();
+        let mut buffer = vec![0; Self::READ_CHUNK_SIZE];
+        loop {
+            let read = reader.read(&mut buffer).map_err(|err| HashError::Io(err.to_string()))?;
+            if read == 0 {
+                break;
+            }
+            for state in &mut states {
+                state.update(&buffer[..read]);
+            }
+        }
+
+        Ok(algorithms
+            .iter()
+            .zip(states)
+            .map(|(algorithm, state)| HashResult { algorithm: *algorithm, digest: state.finalize() })
+            .collect())
+    }
+
+    /// Hash a file with the given algorithms without loading it into memory
+    pub fn digest_file(path: &Path, algorithms: &[HashAlgorithm]) -> Result<Vec<HashResult>, HashError> {
+        let file = File::open(path).map_err(|err| HashError::Io(err.to_string()))?;
+        Self::digest_reader(file, algorithms)
+    }
+
+    /// Render a digest as hex (optionally uppercase) or standard Base64
+    pub fn format_digest(digest: &[u8], encoding: DigestEncoding, uppercase: bool) -> String {
+        match encoding {
+            DigestEncoding::Hex => {
+                let hex = Self::to_hex(digest);
+                if uppercase { hex.to_uppercase() } else { hex }
+            }
+            DigestEncoding::Base64 => Base64Logic::encode(digest, Base64Variant::Standard),
+        }
+    }
+
+    pub fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    /// Whether an expected checksum, pasted as hex or Base64, equals a digest
+    pub fn matches(expected: &str, digest: &[u8]) -> bool {
+        let expected: String = expected.chars().filter(|c| !c.is_whitespace()).collect();
+        // Tolerate `sha256:abc...` style prefixes used by container registries and SRI
+        let expected = match expected.split_once([':', '-']) {
+            Some((prefix, value)) if Self::is_algorithm_prefix(prefix) => value.to_string(),
+            _ => expected,
+        };
+        if expected.is_empty() {
+            return false;
+        }
+
+        if expected.eq_ignore_ascii_case(&Self::to_hex(digest)) {
+            return true;
+        }
+        Base64Logic::decode_auto(&expected).is_ok_and(|decoded| decoded.bytes == digest)
+    }
+
+    fn is_algorithm_prefix(prefix: &str) -> bool {
+        let prefix = prefix.to_ascii_lowercase();
+        prefix == "md5" || (prefix.starts_with("sha") && prefix[3..].chars().all(|c| c.is_ascii_digit() || c == '_'))
+    }
+
+    /// First algorithm whose digest matches the expected checksum
+    pub fn find_match(expected: &str, results: &[HashResult]) -> Option<HashAlgorithm> {
+        results
+            .iter()
+            .find(|result| Self::matches(expected, &result.digest))
+            .map(|result| result.algorithm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(algorithm: HashAlgorithm, input: &str) -> String {
+        HashLogic::to_hex(&HashLogic::digest(algorithm, input.as_bytes()))
+    }
+
+    #[test]
+    fn test_known_vectors() {
+        assert_eq!(hex(HashAlgorithm::Md5, "abc"), "900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(hex(HashAlgorithm::Sha224, "abc"), "23097d223405d8228642a477bda255b32aadbce4bda0b3f7e36c9da7");
+        assert_eq!(hex(HashAlgorithm::Sha256, "abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+        assert_eq!(
+            hex(HashAlgorithm::Sha384, "abc"),
+            "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7"
+        );
+        assert_eq!(
+            hex(HashAlgorithm::Sha512, "abc"),
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+        );
+        assert_eq!(hex(HashAlgorithm::Sha512_256, "abc"), "53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23");
+    }
+
+    #[test]
+    fn test_empty_input() {
+        assert_eq!(hex(HashAlgorithm::Md5, ""), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(hex(HashAlgorithm::Sha256, ""), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+    }
+
+    #[test]
+    fn test_digest_reader_matches_digest_all() {
+        let data: Vec<u8> = (0..200_000u32).map(|i| (i % 253) as u8).collect();
+        let streamed = HashLogic::digest_reader(data.as_slice(), &HashAlgorithm::ALL).unwrap();
+        assert_eq!(streamed, HashLogic::digest_all(&data));
+    }
+
+    #[test]
+    fn test_digest_file_missing() {
+        let result = HashLogic::digest_file(Path::new("/nonexistent/effless/file"), &[HashAlgorithm::Sha256]);
+        assert!(matches!(result, Err(HashError::Io(_))));
+    }
+
+    #[test]
+    fn test_format_digest() {
+        let digest = HashLogic::digest(HashAlgorithm::Sha256, b"abc");
+        assert_eq!(
+            HashLogic::format_digest(&digest, DigestEncoding::Hex, true),
+            "BA7816BF8F01CFEA414140DE5DAE2223B00361A396177A9CB410FF61F20015AD"
+        );
+        assert_eq!(
+            HashLogic::format_digest(&digest, DigestEncoding::Base64, true),
+            "ungWv48Bz+pBQUDeXa4iI7ADYaOWF3qctBD/YfIAFa0="
+        );
+    }
+
+    #[test]
+    fn test_matches() {
+        let digest = HashLogic::digest(HashAlgorithm::Sha256, b"abc");
+        assert!(HashLogic::matches(" BA7816BF8F01CFEA414140DE5DAE2223B00361A396177A9CB410FF61F20015AD\n", &digest));
+        assert!(HashLogic::matches("sha256:ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad", &digest));
+        assert!(HashLogic::matches("sha256-ungWv48Bz+pBQUDeXa4iI7ADYaOWF3qctBD/YfIAFa0=", &digest));
+        assert!(HashLogic::matches("ungWv48Bz-pBQUDeXa4iI7ADYaOWF3qctBD_YfIAFa0", &digest));
+        assert!(!HashLogic::matches("ba7816bf", &digest));
+        assert!(!HashLogic::matches("", &digest));
+    }
+
+    #[test]
+    fn test_find_match() {
+        let results = HashLogic::digest_all(b"abc");
+        assert_eq!(HashLogic::find_match("900150983cd24fb0d6963f7d28e17f72", &results), Some(HashAlgorithm::Md5));
+        assert_eq!(HashLogic::find_match("00", &results), None);
+    }
+}
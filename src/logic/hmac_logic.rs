@@ -0,0 +1,301 @@
+use crate::logic::base64_logic::Base64Logic;
+use crate::logic::hash_logic::{DigestEncoding, HashAlgorithm, HashLogic};
+
+#[derive(Debug, PartialEq)]
+pub enum HmacError {
+    InvalidHexKey,
+    InvalidBase64Key,
+    MissingTimestamp,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KeyEncoding {
+    #[default]
+    Utf8,
+    Hex,
+    Base64,
+}
+
+impl std::fmt::Display for KeyEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KeyEncoding::Utf8 => write!(f, "UTF-8"),
+            KeyEncoding::Hex => write!(f, "Hex"),
+            KeyEncoding::Base64 => write!(f, "Base64"),
+        }
+    }
+}
+
+impl KeyEncoding {
+    pub const ALL: [KeyEncoding; 3] = [KeyEncoding::Utf8, KeyEncoding::Hex, KeyEncoding::Base64];
+}
+
+/// Signing-string and header conventions of common webhook providers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WebhookPreset {
+    #[default]
+    Raw,
+    GitHub,
+    Stripe,
+    Slack,
+}
+
+impl std::fmt::Display for WebhookPreset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebhookPreset::Raw => write!(f, "Raw HMAC"),
+            WebhookPreset::GitHub => write!(f, "GitHub"),
+            WebhookPreset::Stripe => write!(f, "Stripe"),
+            WebhookPreset::Slack => write!(f, "Slack"),
+        }
+    }
+}
+
+impl WebhookPreset {
+    pub const ALL: [WebhookPreset; 4] = [WebhookPreset::Raw, WebhookPreset::GitHub, WebhookPreset::Stripe, WebhookPreset::Slack];
+
+    /// Header that carries the signature
+    pub fn header_name(self) -> Option<&'static str> {
+        match self {
+            WebhookPreset::Raw => None,
+            WebhookPreset::GitHub => Some("X-Hub-Signature-256"),
+            WebhookPreset::Stripe => Some("Stripe-Signature"),
+            WebhookPreset::Slack => Some("X-Slack-Signature"),
+        }
+    }
+
+    /// Whether the signed string includes a request timestamp
+    pub fn uses_timestamp(self) -> bool {
+        matches!(self, WebhookPreset::Stripe | WebhookPreset::Slack)
+    }
+}
+
+pub struct HmacLogic;
+
+impl HmacLogic {
+    pub const ALGORITHMS: [HashAlgorithm; 4] = [
+        HashAlgorithm::Sha256,
+        HashAlgorithm::Sha384,
+        HashAlgorithm::Sha512,
+        HashAlgorithm::Md5,
+    ];
+
+    /// Turn the key as typed into raw key bytes
+    pub fn decode_key(key: &str, encoding: KeyEncoding) -> Result<Vec<u8>, HmacError> {
+        match encoding {
+            KeyEncoding::Utf8 => Ok(key.as_bytes().to_vec()),
+            KeyEncoding::Hex => Self::parse_hex(key).ok_or(HmacError::InvalidHexKey),
+            KeyEncoding::Base64 => Base64Logic::decode_auto(key)
+                .map(|decoded| decoded.bytes)
+                .map_err(|_| HmacError::InvalidBase64Key),
+        }
+    }
+
+    /// HMAC as defined in RFC 2104
+    pub fn compute(algorithm: HashAlgorithm, key: &[u8], message: &[u8]) -> Vec<u8> {
+        let block_size = algorithm.block_size();
+        let mut block = if key.len() > block_size { HashLogic::digest(algorithm, key) } else { key.to_vec() };
+        block.resize(block_size, 0);
+
+        let mut inner: Vec<u8> = block.iter().map(|byte| byte ^ 0x36).collect();
+        inner.extend_from_slice(message);
+        let mut outer: Vec<u8> = block.iter().map(|byte| byte ^ 0x5c).collect();
+        outer.extend_from_slice(&HashLogic::digest(algorithm, &inner));
+        HashLogic::digest(algorithm, &outer)
+    }
+
+    /// Exact bytes the provider signs for a given body
+    pub fn signing_string(preset: WebhookPreset, body: &[u8], timestamp: &str) -> Result<Vec<u8>, HmacError> {
+        let timestamp = timestamp.trim();
+        if preset.uses_timestamp() && timestamp.is_empty() {
+            return Err(HmacError::MissingTimestamp);
+        }
+
+        let prefix = match preset {
+            WebhookPreset::Raw | WebhookPreset::GitHub => String::new(),
+            WebhookPreset::Stripe => format!("{}.", timestamp),
+            WebhookPreset::Slack => format!("v0:{}:", timestamp),
+        };
+        let mut signed = prefix.into_bytes();
+        signed.extend_from_slice(body);
+        Ok(signed)
+    }
+
+    /// Algorithm the preset mandates, or the chosen one for raw HMAC
+    pub fn algorithm_for(preset: WebhookPreset, chosen: HashAlgorithm) -> HashAlgorithm {
+        match preset {
+            WebhookPreset::Raw => chosen,
+            _ => HashAlgorithm::Sha256,
+        }
+    }
+
+    /// Sign a body the way the preset's provider would
+    pub fn sign(preset: WebhookPreset, algorithm: HashAlgorithm, key: &[u8], body: &[u8], timestamp: &str) -> Result<Vec<u8>, HmacError> {
+        let signed = Self::signing_string(preset, body, timestamp)?;
+        Ok(Self::compute(Self::algorithm_for(preset, algorithm), key, &signed))
+    }
+
+    /// Signature as it appears in the provider's header; raw HMAC uses the chosen encoding
+    pub fn format_signature(preset: WebhookPreset, digest: &[u8], timestamp: &str, encoding: DigestEncoding) -> String {
+        let hex = HashLogic::to_hex(digest);
+        match preset {
+            WebhookPreset::Raw => HashLogic::format_digest(digest, encoding, false),
+            WebhookPreset::GitHub => format!("sha256={}", hex),
+            WebhookPreset::Stripe => format!("t={},v1={}", timestamp.trim(), hex),
+            WebhookPreset::Slack => format!("v0={}", hex),
+        }
+    }
+
+    /// Check a pasted signature header; Stripe headers supply their own timestamp
+    pub fn verify(
+        preset: WebhookPreset,
+        algorithm: HashAlgorithm,
+        key: &[u8],
+        body: &[u8],
+        timestamp: &str,
+        header: &str,
+    ) -> Result<bool, HmacError> {
+        let header = header.trim();
+        let (timestamp, candidates): (String, Vec<&str>) = match preset {
+            WebhookPreset::Raw => (timestamp.to_string(), vec![header]),
+            WebhookPreset::GitHub => (timestamp.to_string(), header.strip_prefix("sha256=").into_iter().collect()),
+            WebhookPreset::Slack => (timestamp.to_string(), header.strip_prefix("v0=").into_iter().collect()),
+            WebhookPreset::Stripe => {
+                let fields: Vec<(&str, &str)> = header
+                    .split(',')
+                    .filter_map(|field| field.trim().split_once('='))
+                    .collect();
+                let header_timestamp = fields.iter().find(|(name, _)| *name == "t").map(|(_, value)| *value);
+                let signatures = fields.iter().filter(|(name, _)| *name == "v1").map(|(_, value)| *value).collect();
+                (header_timestamp.unwrap_or(timestamp).to_string(), signatures)
+            }
+        };
+
+        let digest = Self::sign(preset, algorithm, key, body, &timestamp)?;
+        Ok(candidates.iter().any(|candidate| match preset {
+            WebhookPreset::Raw => HashLogic::matches(candidate, &digest),
+            _ => Self::parse_hex(candidate).is_some_and(|bytes| Self::constant_time_eq(&bytes, &digest)),
+        }))
+    }
+
+    fn parse_hex(input: &str) -> Option<Vec<u8>> {
+        let digits: Vec<u8> = input
+            .trim()
+            .trim_start_matches("0x")
+            .bytes()
+            .filter(|byte| !byte.is_ascii_whitespace())
+            .collect();
+        if !digits.len().is_multiple_of(2) {
+            return None;
+        }
+        digits
+            .chunks(2)
+            .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok())
+            .collect()
+    }
+
+    /// Compare without short-circuiting so timing does not reveal the matching prefix
+    fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+        left.len() == right.len() && left.iter().zip(right).fold(0, |difference, (a, b)| difference | (a ^ b)) == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hmac_hex(algorithm: HashAlgorithm, key: &[u8], message: &str) -> String {
+        HashLogic::to_hex(&HmacLogic::compute(algorithm, key, message.as_bytes()))
+    }
+
+    #[test]
+    fn test_rfc_4231_vectors() {
+        let message = "what do ya want for nothing?";
+        assert_eq!(
+            hmac_hex(HashAlgorithm::Sha256, b"Jefe", message),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        assert_eq!(
+            hmac_hex(HashAlgorithm::Sha384, b"Jefe", message),
+            "af45d2e376484031617f78d2b58a6b1b9c7ef464f5a01b47e42ec3736322445e8e2240ca5e69e2c78b3239ecfab21649"
+        );
+        assert_eq!(
+            hmac_hex(HashAlgorithm::Sha512, b"Jefe", message),
+            "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea2505549758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737"
+        );
+        assert_eq!(hmac_hex(HashAlgorithm::Md5, b"Jefe", message), "750c783e6ab0b503eaa86e310a5db738");
+    }
+
+    #[test]
+    fn test_key_longer_than_block() {
+        let key = [0xaa; 131];
+        assert_eq!(
+            hmac_hex(HashAlgorithm::Sha256, &key, "Test Using Larger Than Block-Size Key - Hash Key First"),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+        );
+    }
+
+    #[test]
+    fn test_decode_key() {
+        assert_eq!(HmacLogic::decode_key("Jefe", KeyEncoding::Utf8).unwrap(), b"Jefe");
+        assert_eq!(HmacLogic::decode_key("4a 65 66 65", KeyEncoding::Hex).unwrap(), b"Jefe");
+        assert_eq!(HmacLogic::decode_key("SmVmZQ==", KeyEncoding::Base64).unwrap(), b"Jefe");
+        assert_eq!(HmacLogic::decode_key("4a6", KeyEncoding::Hex), Err(HmacError::InvalidHexKey));
+        assert_eq!(HmacLogic::decode_key("zz", KeyEncoding::Hex), Err(HmacError::InvalidHexKey));
+        assert_eq!(HmacLogic::decode_key("!!", KeyEncoding::Base64), Err(HmacError::InvalidBase64Key));
+    }
+
+    #[test]
+    fn test_github_signature() {
+        let key = b"It's a Secret to Everybody";
+        let digest = HmacLogic::sign(WebhookPreset::GitHub, HashAlgorithm::Md5, key, b"Hello, World!", "").unwrap();
+        let header = HmacLogic::format_signature(WebhookPreset::GitHub, &digest, "", DigestEncoding::Hex);
+        assert_eq!(header, "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17");
+        assert_eq!(HmacLogic::verify(WebhookPreset::GitHub, HashAlgorithm::Md5, key, b"Hello, World!", "", &header), Ok(true));
+        assert_eq!(HmacLogic::verify(WebhookPreset::GitHub, HashAlgorithm::Md5, key, b"Hello, World?", "", &header), Ok(false));
+    }
+
+    #[test]
+    fn test_slack_signature() {
+        let key = b"8f742231b10e8888abcd99yyyzzz85a5";
+        let body = b"token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J&team_domain=testteamnow&channel_id=G8PSS9T3V&channel_name=foobar&user_id=U2CERLKJA&user_name=roadrunner&command=%2Fwebhook-collect&text=&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2FT1DC2JH3J%2F397700885554%2F96rGlfmibIGlgcZRskXaIFfN&trigger_id=398738663015.47445629121.803a0bc887a14d10d2c447fce8b6703c";
+        let header = "v0=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503";
+        assert_eq!(HmacLogic::verify(WebhookPreset::Slack, HashAlgorithm::Sha256, key, body, "1531420618", header), Ok(true));
+        assert_eq!(
+            HmacLogic::verify(WebhookPreset::Slack, HashAlgorithm::Sha256, key, body, "", header),
+            Err(HmacError::MissingTimestamp)
+        );
+    }
+
+    #[test]
+    fn test_stripe_signature() {
+        let key = b"whsec_test_secret";
+        let body = br#"{"id":"evt_1","object":"event"}"#;
+        let digest = HmacLogic::sign(WebhookPreset::Stripe, HashAlgorithm::Sha256, key, body, "1492774577").unwrap();
+        assert_eq!(digest, HmacLogic::compute(HashAlgorithm::Sha256, key, br#"1492774577.{"id":"evt_1","object":"event"}"#));
+
+        let signature = HashLogic::to_hex(&digest);
+        // The header's own timestamp is used and any matching v1 entry is accepted
+        let header = format!("t=1492774577,v1=00ff,v1={},v0=abcd", signature);
+        assert_eq!(HmacLogic::verify(WebhookPreset::Stripe, HashAlgorithm::Sha256, key, body, "", &header), Ok(true));
+        let wrong_time = format!("t=1492774578,v1={}", signature);
+        assert_eq!(HmacLogic::verify(WebhookPreset::Stripe, HashAlgorithm::Sha256, key, body, "", &wrong_time), Ok(false));
+        assert_eq!(
+            HmacLogic::format_signature(WebhookPreset::Stripe, &digest, "1492774577", DigestEncoding::Hex),
+            format!("t=1492774577,v1={}", signature)
+        );
+    }
+
+    #[test]
+    fn test_raw_verify_accepts_hex_or_base64() {
+        let digest = HmacLogic::compute(HashAlgorithm::Sha256, b"Jefe", b"what do ya want for nothing?");
+        let base64 = HmacLogic::format_signature(WebhookPreset::Raw, &digest, "", DigestEncoding::Base64);
+        let message = b"what do ya want for nothing?";
+        assert_eq!(HmacLogic::verify(WebhookPreset::Raw, HashAlgorithm::Sha256, b"Jefe", message, "", &base64), Ok(true));
+        assert_eq!(
+            HmacLogic::verify(WebhookPreset::Raw, HashAlgorithm::Sha256, b"Jefe", message, "", &HashLogic::to_hex(&digest).to_uppercase()),
+            Ok(true)
+        );
+        assert_eq!(HmacLogic::verify(WebhookPreset::Raw, HashAlgorithm::Sha512, b"Jefe", message, "", &base64), Ok(false));
+    }
+}
@@ -0,0 +1,1254 @@
+use image::RgbaImage;
+use resvg::{tiny_skia, usvg};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
+use unicode_segmentation::UnicodeSegmentation;
+use crate::logic::base64_logic::Base64Logic;
+use crate::logic::qr_logic::QrLogic;
+
+#[derive(Debug, PartialEq)]
+pub enum IconError {
+    InvalidSvg,
+    InvalidSize(u32),
+    ImageEncoding,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IconShape {
+    #[default]
+    Circle,
+    Square,
+    RoundedSquare,
+    Squircle,
+    Hexagon,
+    Shield,
+    Triangle,
+}
+
+impl std::fmt::Display for IconShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IconShape::Circle => write!(f, "Circle"),
+            IconShape::Square => write!(f, "Square"),
+            IconShape::RoundedSquare => write!(f, "Rounded Square"),
+            IconShape::Squircle => write!(f, "Squircle"),
+            IconShape::Hexagon => write!(f, "Hexagon"),
+            IconShape::Shield => write!(f, "Shield"),
+            IconShape::Triangle => write!(f, "Triangle"),
+        }
+    }
+}
+
+impl IconShape {
+    pub const ALL: [IconShape; 7] = [
+        IconShape::Circle,
+        IconShape::Square,
+        IconShape::RoundedSquare,
+        IconShape::Squircle,
+        IconShape::Hexagon,
+        IconShape::Shield,
+        IconShape::Triangle,
+    ];
+
+    /// Vertical position of the text centre as a fraction of the shape's height
+    fn text_center(self) -> f64 {
+        match self {
+            IconShape::Shield => 0.45,
+            IconShape::Triangle => 0.64,
+            _ => 0.5,
+        }
+    }
+
+    /// Width and height of the area text may occupy, as fractions of the shape's extent
+    fn text_box(self) -> (f64, f64) {
+        match self {
+            IconShape::Circle => (0.7, 0.5),
+            IconShape::Square => (0.8, 0.55),
+            IconShape::RoundedSquare | IconShape::Squircle => (0.76, 0.55),
+            IconShape::Hexagon => (0.72, 0.5),
+            IconShape::Shield => (0.64, 0.45),
+            IconShape::Triangle => (0.44, 0.3),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FontFamily {
+    #[default]
+    SansSerif,
+    Serif,
+    Monospace,
+    System,
+}
+
+impl std::fmt::Display for FontFamily {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FontFamily::SansSerif => write!(f, "Sans Serif"),
+            FontFamily::Serif => write!(f, "Serif"),
+            FontFamily::Monospace => write!(f, "Monospace"),
+            FontFamily::System => write!(f, "System UI"),
+        }
+    }
+}
+
+impl FontFamily {
+    pub const ALL: [FontFamily; 4] = [FontFamily::SansSerif, FontFamily::Serif, FontFamily::Monospace, FontFamily::System];
+
+    /// CSS font stack, ending in a generic family so every platform has a fallback
+    fn css_value(self) -> &'static str {
+        match self {
+            FontFamily::SansSerif => "Arial, Helvetica, sans-serif",
+            FontFamily::Serif => "Georgia, 'Times New Roman', serif",
+            FontFamily::Monospace => "Menlo, Consolas, 'DejaVu Sans Mono', monospace",
+            FontFamily::System => "system-ui, -apple-system, 'Segoe UI', Roboto, sans-serif",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FontWeight {
+    Regular,
+    Medium,
+    #[default]
+    Bold,
+    Black,
+}
+
+impl std::fmt::Display for FontWeight {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FontWeight::Regular => write!(f, "Regular"),
+            FontWeight::Medium => write!(f, "Medium"),
+            FontWeight::Bold => write!(f, "Bold"),
+            FontWeight::Black => write!(f, "Black"),
+        }
+    }
+}
+
+impl FontWeight {
+    pub const ALL: [FontWeight; 4] = [FontWeight::Regular, FontWeight::Medium, FontWeight::Bold, FontWeight::Black];
+
+    fn css_value(self) -> u32 {
+        match self {
+            FontWeight::Regular => 400,
+            FontWeight::Medium => 500,
+            FontWeight::Bold => 700,
+            FontWeight::Black => 900,
+        }
+    }
+
+    /// Heavier weights draw wider glyphs
+    fn width_factor(self) -> f64 {
+        match self {
+            FontWeight::Regular => 0.92,
+            FontWeight::Medium => 0.96,
+            FontWeight::Bold => 1.0,
+            FontWeight::Black => 1.08,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FillStyle {
+    #[default]
+    Solid,
+    LinearGradient,
+    RadialGradient,
+}
+
+impl std::fmt::Display for FillStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FillStyle::Solid => write!(f, "Solid"),
+            FillStyle::LinearGradient => write!(f, "Linear Gradient"),
+            FillStyle::RadialGradient => write!(f, "Radial Gradient"),
+        }
+    }
+}
+
+impl FillStyle {
+    pub const ALL: [FillStyle; 3] = [FillStyle::Solid, FillStyle::LinearGradient, FillStyle::RadialGradient];
+}
+
+/// Everything needed to draw an icon
+#[derive(Debug, Clone, PartialEq)]
+pub struct IconSpec {
+    pub text: String,
+    pub shape: IconShape,
+    pub size: u32,
+    pub background_color: String,
+    pub text_color: String,
+    pub font_family: FontFamily,
+    pub font_weight: FontWeight,
+    /// Corner radius of the rounded square, as a percentage of its side
+    pub corner_radius: u32,
+    pub fill: FillStyle,
+    /// End colour of gradient fills; `background_color` is the start
+    pub gradient_color: String,
+    /// Direction of linear gradients in degrees, 0 runs left to right
+    pub gradient_angle: u32,
+    /// Outline width in pixels at `size`; 0 disables the outline
+    pub border_width: u32,
+    pub border_color: String,
+    pub shadow: bool,
+    /// Identicon cells of a 5x5 grid, drawn in the text colour instead of text
+    pub pattern: Option<[bool; 25]>,
+}
+
+impl Default for IconSpec {
+    fn default() -> Self {
+        Self {
+            text: String::new(),
+            shape: IconShape::Circle,
+            size: 128,
+            background_color: "#3498db".to_string(),
+            text_color: "#ffffff".to_string(),
+            font_family: FontFamily::SansSerif,
+            font_weight: FontWeight::Bold,
+            corner_radius: 20,
+            fill: FillStyle::Solid,
+            gradient_color: "#8e44ad".to_string(),
+            gradient_angle: 45,
+            border_width: 0,
+            border_color: "#2c3e50".to_string(),
+            shadow: false,
+            pattern: None,
+        }
+    }
+}
+
+/// Appearance derived from hashing an identicon seed
+#[derive(Debug, Clone, PartialEq)]
+pub struct Identicon {
+    pub background_color: String,
+    pub initials: String,
+    pub pattern: [bool; 25],
+}
+
+/// Square area of the canvas the shape is drawn into
+#[derive(Debug, Clone, Copy)]
+struct Frame {
+    x: f64,
+    y: f64,
+    extent: f64,
+}
+
+impl Frame {
+    /// Map a point given in unit coordinates of the frame to canvas coordinates
+    fn point(&self, u: f64, v: f64) -> String {
+        format!("{},{}", svg_number(self.x + u * self.extent), svg_number(self.y + v * self.extent))
+    }
+}
+
+/// Escape text for use in SVG character data and attribute values
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Approximate advance width of a grapheme in ems
+fn grapheme_width(grapheme: &str, family: FontFamily) -> f64 {
+    let first = grapheme.chars().next().unwrap_or(' ');
+    // CJK, Hangul, emoji and other wide scripts occupy a full em
+    let wide = matches!(first as u32, 0x1100..=0x115F | 0x2E80..=0xA4CF | 0xAC00..=0xD7A3 | 0xF900..=0xFAFF | 0xFE30..=0xFE4F | 0xFF00..=0xFF60 | 0x1F000..=0x1FAFF | 0x20000..=0x3FFFD)
+        || grapheme.contains('\u{200D}')
+        || grapheme.contains('\u{FE0F}');
+    if wide {
+        return 1.0;
+    }
+    if family == FontFamily::Monospace {
+        return 0.6;
+    }
+    if first.is_ascii_uppercase() || first.is_ascii_digit() || first == 'm' || first == 'w' {
+        0.72
+    } else if first.is_ascii_lowercase() {
+        0.58
+    } else if first.is_ascii() {
+        0.5
+    } else {
+        0.68
+    }
+}
+
+/// Format a coordinate with at most two decimals and no trailing zeros
+fn svg_number(value: f64) -> String {
+    let formatted = format!("{:.2}", value);
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    if trimmed == "-0" { "0".to_string() } else { trimmed.to_string() }
+}
+
+/// Multi-file icon sets that can be exported in one go
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BundleTarget {
+    PngSet,
+    Favicon,
+    Android,
+    Ios,
+    Complete,
+}
+
+/// A file inside an exported bundle, addressed by a `/`-separated relative path
+#[derive(Debug, Clone, PartialEq)]
+pub struct BundleFile {
+    pub path: String,
+    pub bytes: Vec<u8>,
+}
+
+/// Caches rasterised sizes so a bundle renders each resolution once
+struct SizeCache<F> {
+    render: F,
+    images: HashMap<u32, RgbaImage>,
+}
+
+impl<F: FnMut(u32) -> Result<RgbaImage, IconError>> SizeCache<F> {
+    fn image(&mut self, size: u32) -> Result<&RgbaImage, IconError> {
+        if !self.images.contains_key(&size) {
+            let image = (self.render)(size)?;
+            self.images.insert(size, image);
+        }
+        Ok(&self.images[&size])
+    }
+
+    fn png(&mut self, size: u32) -> Result<Vec<u8>, IconError> {
+        QrLogic::encode_png(self.image(size)?).map_err(|_| IconError::ImageEncoding)
+    }
+
+    fn ico(&mut self, sizes: &[u32]) -> Result<Vec<u8>, IconError> {
+        let images = sizes
+            .iter()
+            .map(|size| self.image(*size).cloned())
+            .collect::<Result<Vec<_>, _>>()?;
+        IconLogic::encode_ico(&images)
+    }
+
+    fn icns(&mut self) -> Result<Vec<u8>, IconError> {
+        let entries = IconLogic::ICNS_ENTRIES
+            .iter()
+            .map(|(os_type, size)| Ok((*os_type, self.png(*size)?)))
+            .collect::<Result<Vec<_>, IconError>>()?;
+        Ok(IconLogic::encode_icns(&entries))
+    }
+}
+
+pub struct IconLogic;
+
+impl IconLogic {
+    pub const MIN_SIZE: u32 = 16;
+    pub const MAX_SIZE: u32 = 512;
+    pub const MAX_TEXT_LENGTH: usize = 3;
+    pub const MAX_CORNER_RADIUS: u32 = 50;
+    pub const MAX_BORDER_WIDTH: u32 = 32;
+    /// Background colours identicons pick from; all keep white text readable
+    pub const IDENTICON_PALETTE: [&'static str; 16] = [
+        "#e74c3c", "#c0392b", "#e67e22", "#d35400", "#f39c12", "#16a085", "#27ae60", "#2980b9",
+        "#3498db", "#8e44ad", "#9b59b6", "#2c3e50", "#34495e", "#7f8c8d", "#d81b60", "#00897b",
+    ];
+    /// Share of the canvas reserved around the shape for its drop shadow
+    const SHADOW_MARGIN: f64 = 0.08;
+    /// Standard Windows icon resolutions
+    pub const ICO_SIZES: [u32; 7] = [16, 24, 32, 48, 64, 128, 256];
+    pub const MAX_ICO_SIZE: u32 = 256;
+    /// Sizes written by the plain PNG set
+    pub const PNG_SET_SIZES: [u32; 9] = [16, 32, 48, 64, 128, 180, 256, 512, 1024];
+    /// ICNS entry types paired with the pixel size of the PNG they carry
+    const ICNS_ENTRIES: [(&'static str, u32); 10] = [
+        ("icp4", 16),
+        ("icp5", 32),
+        ("ic11", 32),
+        ("ic12", 64),
+        ("ic07", 128),
+        ("ic08", 256),
+        ("ic13", 256),
+        ("ic09", 512),
+        ("ic14", 512),
+        ("ic10", 1024),
+    ];
+    const ANDROID_DENSITIES: [(&'static str, u32); 5] = [
+        ("mdpi", 48),
+        ("hdpi", 72),
+        ("xhdpi", 96),
+        ("xxhdpi", 144),
+        ("xxxhdpi", 192),
+    ];
+    /// (idiom, size in points, scale) for a classic iOS AppIcon set
+    const IOS_ICONS: [(&'static str, &'static str, u32); 18] = [
+        ("iphone", "20", 2),
+        ("iphone", "20", 3),
+        ("iphone", "29", 2),
+        ("iphone", "29", 3),
+        ("iphone", "40", 2),
+        ("iphone", "40", 3),
+        ("iphone", "60", 2),
+        ("iphone", "60", 3),
+        ("ipad", "20", 1),
+        ("ipad", "20", 2),
+        ("ipad", "29", 1),
+        ("ipad", "29", 2),
+        ("ipad", "40", 1),
+        ("ipad", "40", 2),
+        ("ipad", "76", 1),
+        ("ipad", "76", 2),
+        ("ipad", "83.5", 2),
+        ("ios-marketing", "1024", 1),
+    ];
+
+    /// Keep the first `MAX_TEXT_LENGTH` user-perceived characters (grapheme clusters)
+    pub fn truncate_text(text: &str) -> String {
+        text.graphemes(true).take(Self::MAX_TEXT_LENGTH).collect()
+    }
+
+    /// Number of user-perceived characters, counting an emoji sequence or combining mark cluster as one
+    pub fn text_length(text: &str) -> usize {
+        text.graphemes(true).count()
+    }
+
+    /// Hash a seed such as an email address into a stable colour, initials and 5x5 pattern
+    pub fn identicon(seed: &str) -> Identicon {
+        let normalized = seed.trim().to_lowercase();
+        let digest = Sha256::digest(normalized.as_bytes());
+
+        let palette_index = u16::from_be_bytes([digest[0], digest[1]]) as usize % Self::IDENTICON_PALETTE.len();
+
+        // Fill the left three columns from the hash and mirror them, like GitHub avatars
+        let mut pattern = [false; 25];
+        for row in 0..5 {
+            for column in 0..3 {
+                let bit = row * 3 + column;
+                let filled = digest[2 + bit / 8] >> (bit % 8) & 1 == 1;
+                pattern[row * 5 + column] = filled;
+                pattern[row * 5 + 4 - column] = filled;
+            }
+        }
+
+        Identicon {
+            background_color: Self::IDENTICON_PALETTE[palette_index].to_string(),
+            initials: Self::initials(seed),
+            pattern,
+        }
+    }
+
+    /// Up to two initials from a name or the local part of an email address
+    pub fn initials(seed: &str) -> String {
+        let name = seed.trim().split('@').next().unwrap_or_default();
+        name.split(|c: char| c.is_whitespace() || matches!(c, '.' | '_' | '-' | '+'))
+            .filter_map(|part| part.graphemes(true).next())
+            .take(2)
+            .map(|grapheme| grapheme.to_uppercase())
+            .collect()
+    }
+
+    /// Apply a seed's identicon to `base`, keeping its shape, size and styling
+    pub fn identicon_spec(seed: &str, show_pattern: bool, base: &IconSpec) -> IconSpec {
+        let identicon = Self::identicon(seed);
+        IconSpec {
+            text: if show_pattern { String::new() } else { identicon.initials },
+            background_color: identicon.background_color,
+            fill: FillStyle::Solid,
+            pattern: show_pattern.then_some(identicon.pattern),
+            ..base.clone()
+        }
+    }
+
+    /// Largest font size at which `text` fits the shape's text area
+    fn fit_font_size(text: &str, spec: &IconSpec, extent: f64) -> f64 {
+        let (box_width, box_height) = spec.shape.text_box();
+        let em_width: f64 = text
+            .graphemes(true)
+            .map(|grapheme| grapheme_width(grapheme, spec.font_family))
+            .sum::<f64>()
+            * spec.font_weight.width_factor();
+        let by_height = extent * box_height;
+        if em_width <= 0.0 {
+            return by_height;
+        }
+        (extent * box_width / em_width).min(by_height)
+    }
+
+    /// Render an icon as an SVG document of `spec.size` pixels
+    pub fn render_svg(spec: &IconSpec) -> String {
+        let size = spec.size;
+        let display_text = Self::truncate_text(spec.text.trim());
+
+        let frame = Self::shape_frame(spec);
+        let font_size = Self::fit_font_size(&display_text, spec, frame.extent);
+
+        let mut defs = String::new();
+        let fill = match spec.fill {
+            FillStyle::Solid => spec.background_color.clone(),
+            FillStyle::LinearGradient => {
+                let radians = (spec.gradient_angle % 360) as f64 * std::f64::consts::PI / 180.0;
+                let (dx, dy) = (50.0 * radians.cos(), 50.0 * radians.sin());
+                defs.push_str(&format!(
+                    "<linearGradient id=\"background\" x1=\"{}%\" y1=\"{}%\" x2=\"{}%\" y2=\"{}%\"><stop offset=\"0\" stop-color=\"{}\"/><stop offset=\"1\" stop-color=\"{}\"/></linearGradient>",
+                    svg_number(50.0 - dx), svg_number(50.0 - dy), svg_number(50.0 + dx), svg_number(50.0 + dy),
+                    spec.background_color, spec.gradient_color
+                ));
+                "url(#background)".to_string()
+            }
+            FillStyle::RadialGradient => {
+                defs.push_str(&format!(
+                    "<radialGradient id=\"background\" cx=\"50%\" cy=\"50%\" r=\"50%\"><stop offset=\"0\" stop-color=\"{}\"/><stop offset=\"1\" stop-color=\"{}\"/></radialGradient>",
+                    spec.background_color, spec.gradient_color
+                ));
+                "url(#background)".to_string()
+            }
+        };
+
+        let mut paint = format!("fill=\"{}\"", fill);
+        let border_width = spec.border_width.min(Self::MAX_BORDER_WIDTH);
+        if border_width > 0 {
+            paint.push_str(&format!(" stroke=\"{}\" stroke-width=\"{}\" stroke-linejoin=\"round\"", spec.border_color, border_width));
+        }
+        if spec.shadow {
+            let offset = svg_number(frame.extent * 0.03);
+            let blur = svg_number(frame.extent * 0.03);
+            defs.push_str(&format!(
+                "<filter id=\"shadow\" x=\"-20%\" y=\"-20%\" width=\"140%\" height=\"140%\"><feDropShadow dx=\"0\" dy=\"{}\" stdDeviation=\"{}\" flood-color=\"#000000\" flood-opacity=\"0.4\"/></filter>",
+                offset, blur
+            ));
+            paint.push_str(" filter=\"url(#shadow)\"");
+        }
+
+        let shape_element = format!("<{} {}/>", Self::shape_geometry(spec, frame), paint);
+
+        let text_element = if !display_text.is_empty() {
+            format!(
+                "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\" dominant-baseline=\"middle\" fill=\"{}\" font-family=\"{}\" font-size=\"{}\" font-weight=\"{}\">{}</text>",
+                svg_number(frame.x + frame.extent / 2.0),
+                svg_number(frame.y + frame.extent * spec.shape.text_center()),
+                spec.text_color,
+                escape_xml(spec.font_family.css_value()),
+                svg_number(font_size),
+                spec.font_weight.css_value(),
+                escape_xml(&display_text)
+            )
+        } else {
+            String::new()
+        };
+
+        let pattern_element = match &spec.pattern {
+            Some(cells) => {
+                let (box_width, box_height) = spec.shape.text_box();
+                let side = frame.extent * box_width.min(box_height * 1.4);
+                let cell = side / 5.0;
+                let left = frame.x + (frame.extent - side) / 2.0;
+                let top = frame.y + frame.extent * spec.shape.text_center() - side / 2.0;
+                let path: String = cells
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, filled)| **filled)
+                    .map(|(index, _)| {
+                        let x = left + (index % 5) as f64 * cell;
+                        let y = top + (index / 5) as f64 * cell;
+                        format!("M{} {}h{}v{}h-{}z", svg_number(x), svg_number(y), svg_number(cell), svg_number(cell), svg_number(cell))
+                    })
+                    .collect();
+                format!("<path d=\"{}\" fill=\"{}\" shape-rendering=\"crispEdges\"/>", path, spec.text_color)
+            }
+            None => String::new(),
+        };
+
+        let defs = if defs.is_empty() { defs } else { format!("<defs>{}</defs>", defs) };
+
+        format!(
+            "<svg width=\"{}\" height=\"{}\" xmlns=\"http://www.w3.org/2000/svg\">{}{}{}{}</svg>",
+            size, size, defs, shape_element, pattern_element, text_element
+        )
+    }
+
+    /// Area left for the shape once the outline and shadow have room to render
+    fn shape_frame(spec: &IconSpec) -> Frame {
+        let size = spec.size as f64;
+        let mut inset = spec.border_width.min(Self::MAX_BORDER_WIDTH) as f64 / 2.0;
+        if spec.shadow {
+            inset += size * Self::SHADOW_MARGIN;
+        }
+        let inset = inset.min(size / 4.0);
+        Frame { x: inset, y: inset, extent: size - 2.0 * inset }
+    }
+
+    /// Element name and geometry attributes of the background shape
+    fn shape_geometry(spec: &IconSpec, frame: Frame) -> String {
+        let extent = frame.extent;
+        let (cx, cy) = (frame.x + extent / 2.0, frame.y + extent / 2.0);
+        match spec.shape {
+            IconShape::Circle => format!(
+                "circle cx=\"{}\" cy=\"{}\" r=\"{}\"",
+                svg_number(cx), svg_number(cy), svg_number(extent / 2.0)
+            ),
+            IconShape::Square | IconShape::RoundedSquare => {
+                let mut rect = format!(
+                    "rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"",
+                    svg_number(frame.x), svg_number(frame.y), svg_number(extent), svg_number(extent)
+                );
+                if spec.shape == IconShape::RoundedSquare {
+                    let radius = extent * spec.corner_radius.min(Self::MAX_CORNER_RADIUS) as f64 / 100.0;
+                    rect.push_str(&format!(" rx=\"{}\"", svg_number(radius)));
+                }
+                rect
+            }
+            IconShape::Squircle => {
+                // Superellipse |x|^4 + |y|^4 = 1 sampled around its perimeter
+                let points: Vec<String> = (0..64)
+                    .map(|step| {
+                        let angle = step as f64 * std::f64::consts::TAU / 64.0;
+                        let (cos, sin) = (angle.cos(), angle.sin());
+                        let u = 0.5 + 0.5 * cos.signum() * cos.abs().sqrt();
+                        let v = 0.5 + 0.5 * sin.signum() * sin.abs().sqrt();
+                        frame.point(u, v)
+                    })
+                    .collect();
+                format!("polygon points=\"{}\"", points.join(" "))
+            }
+            IconShape::Hexagon => {
+                let points: Vec<String> = (0..6)
+                    .map(|corner| {
+                        let angle = (corner as f64 * 60.0 - 90.0).to_radians();
+                        frame.point(0.5 + 0.5 * angle.cos(), 0.5 + 0.5 * angle.sin())
+                    })
+                    .collect();
+                format!("polygon points=\"{}\"", points.join(" "))
+            }
+            IconShape::Shield => format!(
+                "path d=\"M{} L{} L{} Q{} {} Q{} {} L{} Z\"",
+                frame.point(0.5, 0.0),
+                frame.point(0.92, 0.14),
+                frame.point(0.92, 0.5),
+                frame.point(0.92, 0.84),
+                frame.point(0.5, 1.0),
+                frame.point(0.08, 0.84),
+                frame.point(0.08, 0.5),
+                frame.point(0.08, 0.14),
+            ),
+            IconShape::Triangle => format!(
+                "polygon points=\"{} {} {}\"",
+                frame.point(0.5, 0.067),
+                frame.point(1.0, 0.933),
+                frame.point(0.0, 0.933),
+            ),
+        }
+    }
+
+    /// SVG data URL suitable for an `img` preview or pasting into HTML/CSS
+    pub fn render_data_url(spec: &IconSpec) -> String {
+        Base64Logic::data_uri("image/svg+xml", Self::render_svg(spec).as_bytes())
+    }
+
+    /// Rasterise an icon at an arbitrary pixel size
+    pub fn render_image(spec: &IconSpec, size: u32) -> Result<RgbaImage, IconError> {
+        Self::rasterize(&Self::render_svg(spec), size)
+    }
+
+    /// PNG at the icon's own size
+    pub fn render_png(spec: &IconSpec) -> Result<Vec<u8>, IconError> {
+        QrLogic::encode_png(&Self::render_image(spec, spec.size)?).map_err(|_| IconError::ImageEncoding)
+    }
+
+    /// Multi-resolution ICO up to the icon's own size
+    pub fn render_ico(spec: &IconSpec) -> Result<Vec<u8>, IconError> {
+        let svg = Self::render_svg(spec);
+        let images = Self::ico_sizes(spec.size)
+            .into_iter()
+            .map(|size| Self::rasterize(&svg, size))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::encode_ico(&images)
+    }
+
+    /// ICNS with every standard macOS resolution
+    pub fn render_icns(spec: &IconSpec) -> Result<Vec<u8>, IconError> {
+        let svg = Self::render_svg(spec);
+        Self::icns_file(|size| Self::rasterize(&svg, size))
+    }
+
+    /// Every file of a bundle; the icon text names the web manifest
+    pub fn render_bundle(spec: &IconSpec, target: BundleTarget) -> Result<Vec<BundleFile>, IconError> {
+        let svg = Self::render_svg(spec);
+        let name = if spec.text.trim().is_empty() { "Icon" } else { spec.text.trim() };
+        Self::bundle_files(target, name, &spec.background_color, |size| Self::rasterize(&svg, size))
+    }
+
+    /// Paths of bundle files that already exist below `root` and would be overwritten
+    pub fn existing_files(root: &Path, files: &[BundleFile]) -> Vec<String> {
+        files.iter().filter(|file| root.join(&file.path).exists()).map(|file| file.path.clone()).collect()
+    }
+
+    /// Write bundle files below `root`, creating subdirectories as needed
+    pub fn write_bundle(root: &Path, files: &[BundleFile]) -> std::io::Result<()> {
+        for file in files {
+            let path = root.join(&file.path);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, &file.bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Rasterise an SVG document into a square bitmap, scaling it to `size` pixels
+    pub fn rasterize(svg: &str, size: u32) -> Result<RgbaImage, IconError> {
+        let options = usvg::Options {
+            fontdb: Self::font_database(),
+            ..usvg::Options::default()
+        };
+        let tree = usvg::Tree::from_str(svg, &options).map_err(|_| IconError::InvalidSvg)?;
+        let mut pixmap = tiny_skia::Pixmap::new(size, size).ok_or(IconError::InvalidSize(size))?;
+
+        let svg_size = tree.size();
+        let transform = tiny_skia::Transform::from_scale(
+            size as f32 / svg_size.width(),
+            size as f32 / svg_size.height(),
+        );
+        resvg::render(&tree, transform, &mut pixmap.as_mut());
+
+        // tiny-skia stores premultiplied alpha, image expects straight alpha
+        let rgba = pixmap
+            .pixels()
+            .iter()
+            .flat_map(|pixel| {
+                let color = pixel.demultiply();
+                [color.red(), color.green(), color.blue(), color.alpha()]
+            })
+            .collect();
+        RgbaImage::from_raw(size, size, rgba).ok_or(IconError::InvalidSize(size))
+    }
+
+    /// ICO resolutions to embed for an icon whose largest side is `max_size`
+    pub fn ico_sizes(max_size: u32) -> Vec<u32> {
+        let largest = max_size.min(Self::MAX_ICO_SIZE);
+        let mut sizes: Vec<u32> = Self::ICO_SIZES.into_iter().filter(|s| *s < largest).collect();
+        sizes.push(largest);
+        sizes
+    }
+
+    /// Pack bitmaps into a multi-resolution ICO file
+    pub fn encode_ico(images: &[RgbaImage]) -> Result<Vec<u8>, IconError> {
+        let mut icon_dir = ico::IconDir::new(ico::ResourceType::Icon);
+        for image in images {
+            if image.width() > Self::MAX_ICO_SIZE || image.height() > Self::MAX_ICO_SIZE {
+                return Err(IconError::InvalidSize(image.width().max(image.height())));
+            }
+            let icon_image = ico::IconImage::from_rgba_data(image.width(), image.height(), image.as_raw().clone());
+            let entry = ico::IconDirEntry::encode(&icon_image).map_err(|_| IconError::ImageEncoding)?;
+            icon_dir.add_entry(entry);
+        }
+
+        let mut bytes = Vec::new();
+        icon_dir.write(&mut bytes).map_err(|_| IconError::ImageEncoding)?;
+        Ok(bytes)
+    }
+
+    /// Pack PNG payloads into an Apple ICNS container
+    pub fn encode_icns(entries: &[(&str, Vec<u8>)]) -> Vec<u8> {
+        let total: usize = 8 + entries.iter().map(|(_, data)| 8 + data.len()).sum::<usize>();
+
+        let mut bytes = Vec::with_capacity(total);
+        bytes.extend_from_slice(b"icns");
+        bytes.extend_from_slice(&(total as u32).to_be_bytes());
+        for (os_type, data) in entries {
+            bytes.extend_from_slice(os_type.as_bytes());
+            bytes.extend_from_slice(&((data.len() + 8) as u32).to_be_bytes());
+            bytes.extend_from_slice(data);
+        }
+        bytes
+    }
+
+    /// Build an ICNS file, calling `render` once per pixel size needed
+    pub fn icns_file<F>(render: F) -> Result<Vec<u8>, IconError>
+    where
+        F: FnMut(u32) -> Result<RgbaImage, IconError>,
+    {
+        SizeCache { render, images: HashMap::new() }.icns()
+    }
+
+    /// Build every file of a bundle, calling `render` once per pixel size needed.
+    /// `name` and `theme_color` feed the web manifest.
+    pub fn bundle_files<F>(target: BundleTarget, name: &str, theme_color: &str, render: F) -> Result<Vec<BundleFile>, IconError>
+    where
+        F: FnMut(u32) -> Result<RgbaImage, IconError>,
+    {
+        let mut cache = SizeCache { render, images: HashMap::new() };
+        Self::collect_bundle(target, name, theme_color, "", &mut cache)
+    }
+
+    fn collect_bundle<F>(
+        target: BundleTarget,
+        name: &str,
+        theme_color: &str,
+        prefix: &str,
+        cache: &mut SizeCache<F>,
+    ) -> Result<Vec<BundleFile>, IconError>
+    where
+        F: FnMut(u32) -> Result<RgbaImage, IconError>,
+    {
+        let file = |path: String, bytes: Vec<u8>| BundleFile { path: format!("{}{}", prefix, path), bytes };
+        let mut files = Vec::new();
+
+        match target {
+            BundleTarget::PngSet => {
+                for size in Self::PNG_SET_SIZES {
+                    files.push(file(format!("icon-{}x{}.png", size, size), cache.png(size)?));
+                }
+            }
+            BundleTarget::Favicon => {
+                files.push(file("favicon.ico".to_string(), cache.ico(&[16, 32, 48])?));
+                files.push(file("favicon-16x16.png".to_string(), cache.png(16)?));
+                files.push(file("favicon-32x32.png".to_string(), cache.png(32)?));
+                files.push(file("apple-touch-icon.png".to_string(), cache.png(180)?));
+                files.push(file("android-chrome-192x192.png".to_string(), cache.png(192)?));
+                files.push(file("android-chrome-512x512.png".to_string(), cache.png(512)?));
+                files.push(file("site.webmanifest".to_string(), Self::web_manifest(name, theme_color).into_bytes()));
+            }
+            BundleTarget::Android => {
+                for (density, size) in Self::ANDROID_DENSITIES {
+                    files.push(file(format!("mipmap-{}/ic_launcher.png", density), cache.png(size)?));
+                }
+                files.push(file("playstore-icon.png".to_string(), cache.png(512)?));
+            }
+            BundleTarget::Ios => {
+                let mut images = Vec::new();
+                for (idiom, points, scale) in Self::IOS_ICONS {
+                    let pixels = Self::ios_pixels(points, scale);
+                    let filename = format!("icon-{}.png", pixels);
+                    if !files.iter().any(|f: &BundleFile| f.path.ends_with(&filename)) {
+                        files.push(file(format!("AppIcon.appiconset/{}", filename), cache.png(pixels)?));
+                    }
+                    images.push(json!({
+                        "size": format!("{}x{}", points, points),
+                        "idiom": idiom,
+                        "filename": filename,
+                        "scale": format!("{}x", scale),
+                    }));
+                }
+                let contents = json!({
+                    "images": images,
+                    "info": { "version": 1, "author": "xcode" },
+                });
+                let contents = serde_json::to_string_pretty(&contents).unwrap_or_default();
+                files.push(file("AppIcon.appiconset/Contents.json".to_string(), contents.into_bytes()));
+            }
+            BundleTarget::Complete => {
+                let nested = [
+                    (BundleTarget::PngSet, "png/"),
+                    (BundleTarget::Favicon, "web/"),
+                    (BundleTarget::Android, "android/"),
+                    (BundleTarget::Ios, "ios/"),
+                ];
+                for (nested_target, nested_prefix) in nested {
+                    let nested_prefix = format!("{}{}", prefix, nested_prefix);
+                    files.extend(Self::collect_bundle(nested_target, name, theme_color, &nested_prefix, cache)?);
+                }
+                files.push(file("windows/icon.ico".to_string(), cache.ico(&Self::ICO_SIZES)?));
+                files.push(file("macos/icon.icns".to_string(), cache.icns()?));
+            }
+        }
+
+        Ok(files)
+    }
+
+    fn ios_pixels(points: &str, scale: u32) -> u32 {
+        let points: f64 = points.parse().unwrap_or(0.0);
+        (points * scale as f64).round() as u32
+    }
+
+    fn web_manifest(name: &str, theme_color: &str) -> String {
+        let manifest = json!({
+            "name": name,
+            "short_name": name,
+            "icons": [
+                { "src": "/android-chrome-192x192.png", "sizes": "192x192", "type": "image/png" },
+                { "src": "/android-chrome-512x512.png", "sizes": "512x512", "type": "image/png" },
+            ],
+            "theme_color": theme_color,
+            "background_color": theme_color,
+            "display": "standalone",
+        });
+        serde_json::to_string_pretty(&manifest).unwrap_or_default()
+    }
+
+    /// System fonts are loaded once and shared by every rasterisation
+    fn font_database() -> Arc<usvg::fontdb::Database> {
+        static FONTS: OnceLock<Arc<usvg::fontdb::Database>> = OnceLock::new();
+        FONTS
+            .get_or_init(|| {
+                let mut database = usvg::fontdb::Database::new();
+                database.load_system_fonts();
+                Arc::new(database)
+            })
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(text: &str, shape: IconShape) -> IconSpec {
+        IconSpec {
+            text: text.to_string(),
+            shape,
+            size: 64,
+            background_color: "#ff0000".to_string(),
+            text_color: "#ffffff".to_string(),
+            ..IconSpec::default()
+        }
+    }
+
+    #[test]
+    fn test_render_svg_circle() {
+        let svg = IconLogic::render_svg(&spec("AB", IconShape::Circle));
+        assert!(svg.starts_with("<svg width=\"64\" height=\"64\""));
+        assert!(svg.contains("<circle cx=\"32\" cy=\"32\" r=\"32\" fill=\"#ff0000\"/>"));
+        assert!(svg.contains("font-family=\"Arial, Helvetica, sans-serif\""));
+        assert!(svg.contains("font-weight=\"700\""));
+        assert!(svg.contains(">AB</text>"));
+    }
+
+    #[test]
+    fn test_render_svg_square_without_text() {
+        let svg = IconLogic::render_svg(&spec("", IconShape::Square));
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"64\" height=\"64\" fill=\"#ff0000\"/>"));
+        assert!(!svg.contains("<text"));
+    }
+
+    #[test]
+    fn test_render_svg_truncates_text() {
+        let svg = IconLogic::render_svg(&spec("ABCDE", IconShape::Circle));
+        assert!(svg.contains(">ABC</text>"));
+    }
+
+    #[test]
+    fn test_truncate_text_by_grapheme() {
+        assert_eq!(IconLogic::truncate_text("ABCDE"), "ABC");
+        assert_eq!(IconLogic::truncate_text("日本語です"), "日本語");
+        assert_eq!(IconLogic::truncate_text("สวัสดี"), "สวัส");
+        assert_eq!(IconLogic::truncate_text("👨‍👩‍👧‍👦🇹🇭é!x"), "👨‍👩‍👧‍👦🇹🇭é");
+        assert_eq!(IconLogic::text_length("👍🏽"), 1);
+    }
+
+    #[test]
+    fn test_render_svg_multibyte_text() {
+        let svg = IconLogic::render_svg(&spec("日本語です", IconShape::Circle));
+        assert!(svg.contains(">日本語</text>"));
+        let svg = IconLogic::render_svg(&spec("😀😀😀😀", IconShape::Square));
+        assert!(svg.contains(">😀😀😀</text>"));
+    }
+
+    #[test]
+    fn test_render_svg_escapes_text() {
+        let svg = IconLogic::render_svg(&spec("<&\"", IconShape::Square));
+        assert!(svg.contains(">&lt;&amp;&quot;</text>"));
+        assert!(IconLogic::rasterize(&svg, 32).is_ok());
+    }
+
+    #[test]
+    fn test_render_svg_font_selection() {
+        let mut styled = spec("A", IconShape::Circle);
+        styled.font_family = FontFamily::Serif;
+        styled.font_weight = FontWeight::Regular;
+        let svg = IconLogic::render_svg(&styled);
+        assert!(svg.contains("font-family=\"Georgia, &apos;Times New Roman&apos;, serif\""));
+        assert!(svg.contains("font-weight=\"400\""));
+        assert!(IconLogic::rasterize(&svg, 32).is_ok());
+    }
+
+    #[test]
+    fn test_fit_font_size() {
+        let circle = spec("", IconShape::Circle);
+        let one = IconLogic::fit_font_size("A", &circle, 100.0);
+        let three = IconLogic::fit_font_size("ABC", &circle, 100.0);
+        let wide = IconLogic::fit_font_size("日本語", &circle, 100.0);
+        assert_eq!(one, 50.0);
+        assert!(three < one);
+        assert!(wide < three);
+        // Text never overflows the shape's text box
+        assert!(wide * 3.0 <= 70.0 + f64::EPSILON);
+
+        let triangle = spec("", IconShape::Triangle);
+        assert!(IconLogic::fit_font_size("A", &triangle, 100.0) < one);
+    }
+
+    #[test]
+    fn test_identicon_is_deterministic() {
+        let first = IconLogic::identicon("alice@example.com");
+        assert_eq!(first, IconLogic::identicon("alice@example.com"));
+        assert_eq!(first, IconLogic::identicon("  Alice@Example.com "));
+        assert_ne!(first.pattern, IconLogic::identicon("bob@example.com").pattern);
+        assert!(IconLogic::IDENTICON_PALETTE.contains(&first.background_color.as_str()));
+    }
+
+    #[test]
+    fn test_identicon_pattern_is_symmetric() {
+        for seed in ["alice@example.com", "bob", "日本"] {
+            let pattern = IconLogic::identicon(seed).pattern;
+            for row in 0..5 {
+                for column in 0..5 {
+                    assert_eq!(pattern[row * 5 + column], pattern[row * 5 + 4 - column]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_initials() {
+        assert_eq!(IconLogic::initials("alice@example.com"), "A");
+        assert_eq!(IconLogic::initials("jane.doe@example.com"), "JD");
+        assert_eq!(IconLogic::initials("Émile Zola"), "ÉZ");
+        assert_eq!(IconLogic::initials("mary ann smith"), "MA");
+        assert_eq!(IconLogic::initials(""), "");
+    }
+
+    #[test]
+    fn test_identicon_spec() {
+        let base = spec("XY", IconShape::Square);
+        let initials = IconLogic::identicon_spec("jane.doe@example.com", false, &base);
+        assert_eq!(initials.text, "JD");
+        assert_eq!(initials.shape, IconShape::Square);
+        assert_eq!(initials.pattern, None);
+        assert!(IconLogic::render_svg(&initials).contains(">JD</text>"));
+
+        let pattern = IconLogic::identicon_spec("jane.doe@example.com", true, &base);
+        let svg = IconLogic::render_svg(&pattern);
+        assert!(svg.contains("shape-rendering=\"crispEdges\""));
+        assert!(!svg.contains("<text"));
+        assert_eq!(IconLogic::render_png(&pattern).unwrap(), IconLogic::render_png(&pattern).unwrap());
+    }
+
+    #[test]
+    fn test_render_svg_every_shape_rasterizes() {
+        for shape in IconShape::ALL {
+            let image = IconLogic::render_image(&spec("", shape), 64).unwrap();
+            let center = image.get_pixel(32, 32).0;
+            assert_eq!(center, [255, 0, 0, 255], "{} centre should be filled", shape);
+        }
+    }
+
+    #[test]
+    fn test_render_svg_rounded_square_radius() {
+        let mut rounded = spec("", IconShape::RoundedSquare);
+        rounded.corner_radius = 25;
+        let svg = IconLogic::render_svg(&rounded);
+        assert!(svg.contains("rx=\"16\""));
+        let image = IconLogic::render_image(&rounded, 64).unwrap();
+        assert_eq!(image.get_pixel(0, 0).0[3], 0);
+        assert_eq!(image.get_pixel(32, 0).0, [255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn test_render_svg_linear_gradient() {
+        let mut gradient = spec("", IconShape::Square);
+        gradient.fill = FillStyle::LinearGradient;
+        gradient.gradient_color = "#0000ff".to_string();
+        gradient.gradient_angle = 0;
+        let svg = IconLogic::render_svg(&gradient);
+        assert!(svg.contains("<linearGradient id=\"background\" x1=\"0%\" y1=\"50%\" x2=\"100%\" y2=\"50%\">"));
+        assert!(svg.contains("fill=\"url(#background)\""));
+
+        let image = IconLogic::render_image(&gradient, 64).unwrap();
+        let left = image.get_pixel(0, 32).0;
+        let right = image.get_pixel(63, 32).0;
+        assert!(left[0] > 240 && left[2] < 15);
+        assert!(right[2] > 240 && right[0] < 15);
+    }
+
+    #[test]
+    fn test_render_svg_radial_gradient() {
+        let mut gradient = spec("", IconShape::Circle);
+        gradient.fill = FillStyle::RadialGradient;
+        let svg = IconLogic::render_svg(&gradient);
+        assert!(svg.contains("<radialGradient id=\"background\""));
+    }
+
+    #[test]
+    fn test_render_svg_border() {
+        let mut bordered = spec("", IconShape::Square);
+        bordered.border_width = 8;
+        bordered.border_color = "#00ff00".to_string();
+        let svg = IconLogic::render_svg(&bordered);
+        assert!(svg.contains("<rect x=\"4\" y=\"4\" width=\"56\" height=\"56\""));
+        assert!(svg.contains("stroke=\"#00ff00\" stroke-width=\"8\""));
+
+        let image = IconLogic::render_image(&bordered, 64).unwrap();
+        assert_eq!(image.get_pixel(1, 32).0, [0, 255, 0, 255]);
+        assert_eq!(image.get_pixel(32, 32).0, [255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn test_render_svg_shadow() {
+        let mut shadowed = spec("", IconShape::Square);
+        shadowed.shadow = true;
+        let svg = IconLogic::render_svg(&shadowed);
+        assert!(svg.contains("<feDropShadow"));
+        assert!(svg.contains("filter=\"url(#shadow)\""));
+
+        let image = IconLogic::render_image(&shadowed, 64).unwrap();
+        assert_eq!(image.get_pixel(0, 0).0[3], 0);
+        let below = image.get_pixel(32, 60).0;
+        assert!(below[3] > 0 && below[0] < 100);
+    }
+
+    #[test]
+    fn test_render_data_url() {
+        let url = IconLogic::render_data_url(&spec("A", IconShape::Square));
+        assert!(url.starts_with("data:image/svg+xml;base64,"));
+        let data_uri = Base64Logic::parse_data_uri(&url).unwrap();
+        assert_eq!(data_uri.bytes, IconLogic::render_svg(&spec("A", IconShape::Square)).into_bytes());
+    }
+
+    #[test]
+    fn test_render_png_uses_spec_size() {
+        let png = IconLogic::render_png(&spec("", IconShape::Square)).unwrap();
+        let image = image::load_from_memory(&png).unwrap().to_rgba8();
+        assert_eq!(image.dimensions(), (64, 64));
+        assert_eq!(image.get_pixel(0, 0).0, [255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn test_write_bundle() {
+        let root = std::env::temp_dir().join(format!("effless-icon-bundle-{}", std::process::id()));
+        let files = vec![BundleFile { path: "nested/dir/icon.txt".to_string(), bytes: b"icon".to_vec() }];
+        assert!(IconLogic::existing_files(&root, &files).is_empty());
+        IconLogic::write_bundle(&root, &files).unwrap();
+        assert_eq!(std::fs::read(root.join("nested/dir/icon.txt")).unwrap(), b"icon");
+        assert_eq!(IconLogic::existing_files(&root, &files), vec!["nested/dir/icon.txt"]);
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    const CIRCLE_SVG: &str = "<svg width=\"64\" height=\"64\" xmlns=\"http://www.w3.org/2000/svg\"><circle cx=\"32\" cy=\"32\" r=\"32\" fill=\"#ff0000\"/></svg>";
+
+    #[test]
+    fn test_rasterize_scales_to_size() {
+        let image = IconLogic::rasterize(CIRCLE_SVG, 128).unwrap();
+        assert_eq!(image.dimensions(), (128, 128));
+        assert_eq!(image.get_pixel(64, 64).0, [255, 0, 0, 255]);
+        // Corners are outside the circle and stay transparent
+        assert_eq!(image.get_pixel(0, 0).0[3], 0);
+    }
+
+    #[test]
+    fn test_rasterize_invalid_svg() {
+        assert_eq!(IconLogic::rasterize("not svg", 32), Err(IconError::InvalidSvg));
+        assert_eq!(IconLogic::rasterize(CIRCLE_SVG, 0), Err(IconError::InvalidSize(0)));
+    }
+
+    #[test]
+    fn test_ico_sizes() {
+        assert_eq!(IconLogic::ico_sizes(16), vec![16]);
+        assert_eq!(IconLogic::ico_sizes(100), vec![16, 24, 32, 48, 64, 100]);
+        assert_eq!(IconLogic::ico_sizes(512), vec![16, 24, 32, 48, 64, 128, 256]);
+    }
+
+    #[test]
+    fn test_encode_ico_round_trip() {
+        let images: Vec<RgbaImage> = IconLogic::ico_sizes(48)
+            .into_iter()
+            .map(|size| IconLogic::rasterize(CIRCLE_SVG, size).unwrap())
+            .collect();
+        let bytes = IconLogic::encode_ico(&images).unwrap();
+
+        let icon_dir = ico::IconDir::read(std::io::Cursor::new(bytes)).unwrap();
+        let sizes: Vec<u32> = icon_dir.entries().iter().map(|entry| entry.width()).collect();
+        assert_eq!(sizes, vec![16, 24, 32, 48]);
+    }
+
+    fn solid(size: u32) -> Result<RgbaImage, IconError> {
+        Ok(RgbaImage::from_pixel(size, size, image::Rgba([52, 152, 219, 255])))
+    }
+
+    fn paths(files: &[BundleFile]) -> Vec<&str> {
+        files.iter().map(|file| file.path.as_str()).collect()
+    }
+
+    #[test]
+    fn test_encode_icns_layout() {
+        let bytes = IconLogic::encode_icns(&[("ic07", vec![1, 2, 3]), ("ic08", vec![4])]);
+        assert_eq!(&bytes[..4], b"icns");
+        assert_eq!(u32::from_be_bytes(bytes[4..8].try_into().unwrap()) as usize, bytes.len());
+        assert_eq!(&bytes[8..12], b"ic07");
+        assert_eq!(u32::from_be_bytes(bytes[12..16].try_into().unwrap()), 11);
+        assert_eq!(&bytes[19..23], b"ic08");
+    }
+
+    #[test]
+    fn test_bundle_renders_each_size_once() {
+        let mut rendered = Vec::new();
+        let files = IconLogic::bundle_files(BundleTarget::Complete, "Effless", "#3498db", |size| {
+            rendered.push(size);
+            solid(size)
+        })
+        .unwrap();
+
+        let mut unique = rendered.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(rendered.len(), unique.len());
+        assert!(paths(&files).contains(&"windows/icon.ico"));
+        assert!(paths(&files).contains(&"macos/icon.icns"));
+        assert!(paths(&files).contains(&"web/site.webmanifest"));
+        assert!(paths(&files).contains(&"ios/AppIcon.appiconset/Contents.json"));
+    }
+
+    #[test]
+    fn test_favicon_bundle() {
+        let files = IconLogic::bundle_files(BundleTarget::Favicon, "Effless", "#3498db", solid).unwrap();
+        assert_eq!(
+            paths(&files),
+            vec![
+                "favicon.ico",
+                "favicon-16x16.png",
+                "favicon-32x32.png",
+                "apple-touch-icon.png",
+                "android-chrome-192x192.png",
+                "android-chrome-512x512.png",
+                "site.webmanifest",
+            ]
+        );
+        let manifest: serde_json::Value = serde_json::from_slice(&files[6].bytes).unwrap();
+        assert_eq!(manifest["name"], "Effless");
+        assert_eq!(manifest["theme_color"], "#3498db");
+    }
+
+    #[test]
+    fn test_android_bundle() {
+        let files = IconLogic::bundle_files(BundleTarget::Android, "App", "#000000", solid).unwrap();
+        assert_eq!(files[0].path, "mipmap-mdpi/ic_launcher.png");
+        let image = image::load_from_memory(&files[4].bytes).unwrap();
+        assert_eq!(image.width(), 192);
+    }
+
+    #[test]
+    fn test_ios_bundle_contents() {
+        let files = IconLogic::bundle_files(BundleTarget::Ios, "App", "#000000", solid).unwrap();
+        let contents = files.iter().find(|file| file.path.ends_with("Contents.json")).unwrap();
+        let contents: serde_json::Value = serde_json::from_slice(&contents.bytes).unwrap();
+        let images = contents["images"].as_array().unwrap();
+        assert_eq!(images.len(), 18);
+
+        // Every referenced file exists in the set
+        for entry in images {
+            let filename = entry["filename"].as_str().unwrap();
+            assert!(files.iter().any(|file| file.path == format!("AppIcon.appiconset/{}", filename)));
+        }
+        let ipad_pro = images.iter().find(|entry| entry["size"] == "83.5x83.5").unwrap();
+        assert_eq!(ipad_pro["filename"], "icon-167.png");
+    }
+
+    #[test]
+    fn test_encode_ico_rejects_large_images() {
+        let image = RgbaImage::new(512, 512);
+        assert_eq!(IconLogic::encode_ico(&[image]), Err(IconError::InvalidSize(512)));
+    }
+}
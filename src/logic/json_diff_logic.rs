@@ -0,0 +1,339 @@
+use crate::logic::json_path_logic::{JsonPathLogic, PathSegment};
+use serde_json::{Value, json};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+impl std::fmt::Display for DiffKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiffKind::Added => write!(f, "Added"),
+            DiffKind::Removed => write!(f, "Removed"),
+            DiffKind::Changed => write!(f, "Changed"),
+        }
+    }
+}
+
+/// One difference between the left and right documents
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffEntry {
+    pub kind: DiffKind,
+    /// JSONPath of the node, for display
+    pub path: String,
+    /// JSON Pointer the matching patch operation targets
+    pub pointer: String,
+    pub left: Option<Value>,
+    pub right: Option<Value>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DiffOptions {
+    /// Compare arrays as multisets instead of index by index
+    pub ignore_array_order: bool,
+    /// Numbers closer than this are treated as equal
+    pub numeric_tolerance: f64,
+}
+
+pub struct JsonDiffLogic;
+
+impl JsonDiffLogic {
+    /// Structural differences from `left` to `right`; object key order never matters
+    pub fn diff(left: &Value, right: &Value, options: DiffOptions) -> Vec<DiffEntry> {
+        let mut entries = Vec::new();
+        Self::diff_at(&mut Vec::new(), left, right, options, &mut entries);
+        entries
+    }
+
+    fn diff_at(location: &mut Vec<PathSegment>, left: &Value, right: &Value, options: DiffOptions, entries: &mut Vec<DiffEntry>) {
+        match (left, right) {
+            (Value::Object(left_map), Value::Object(right_map)) => {
+                for (key, left_child) in left_map {
+                    location.push(PathSegment::Key(key.clone()));
+                    match right_map.get(key) {
+                        Some(right_child) => Self::diff_at(location, left_child, right_child, options, entries),
+                        None => entries.push(Self::entry(DiffKind::Removed, location, None, Some(left_child), None)),
+                    }
+                    location.pop();
+                }
+                for (key, right_child) in right_map {
+                    if !left_map.contains_key(key) {
+                        location.push(PathSegment::Key(key.clone()));
+                        entries.push(Self::entry(DiffKind::Added, location, None, None, Some(right_child)));
+                        location.pop();
+                    }
+                }
+            }
+            (Value::Array(left_items), Value::Array(right_items)) if options.ignore_array_order => {
+                Self::diff_unordered(location, left_items, right_items, options, entries);
+            }
+            (Value::Array(left_items), Value::Array(right_items)) => {
+                let common = left_items.len().min(right_items.len());
+                for index in 0..common {
+                    location.push(PathSegment::Index(index));
+                    Self::diff_at(location, &left_items[index], &right_items[index], options, entries);
+                    location.pop();
+                }
+                for (index, right_child) in right_items.iter().enumerate().skip(common) {
+                    location.push(PathSegment::Index(index));
+                    entries.push(Self::entry(DiffKind::Added, location, None, None, Some(right_child)));
+                    location.pop();
+                }
+                // Highest index first so each removal leaves earlier pointers valid
+                for index in (common..left_items.len()).rev() {
+                    location.push(PathSegment::Index(index));
+                    entries.push(Self::entry(DiffKind::Removed, location, None, Some(&left_items[index]), None));
+                    location.pop();
+                }
+            }
+            _ if Self::equal(left, right, options) => {}
+            _ => entries.push(Self::entry(DiffKind::Changed, location, None, Some(left), Some(right))),
+        }
+    }
+
+    fn diff_unordered(
+        location: &mut Vec<PathSegment>,
+        left_items: &[Value],
+        right_items: &[Value],
+        options: DiffOptions,
+        entries: &mut Vec<DiffEntry>,
+    ) {
+        let mut matched = vec![false; right_items.len()];
+        let mut unmatched_left = Vec::new();
+        for (index, left_child) in left_items.iter().enumerate() {
+            let partner = (0..right_items.len()).find(|candidate| {
+                !matched[*candidate] && Self::diff(left_child, &right_items[*candidate], options).is_empty()
+            });
+            match partner {
+                Some(candidate) => matched[candidate] = true,
+                None => unmatched_left.push(index),
+            }
+        }
+
+        for index in unmatched_left.into_iter().rev() {
+            location.push(PathSegment::Index(index));
+            entries.push(Self::entry(DiffKind::Removed, location, None, Some(&left_items[index]), None));
+            location.pop();
+        }
+        // Order is irrelevant here, so additions simply append with the `-` pointer
+        for (index, right_child) in right_items.iter().enumerate().filter(|(index, _)| !matched[*index]) {
+            location.push(PathSegment::Index(index));
+            let append = format!("{}/-", JsonPathLogic::pointer(&location[..location.len() - 1]));
+            entries.push(Self::entry(DiffKind::Added, location, Some(append), None, Some(right_child)));
+            location.pop();
+        }
+    }
+
+    fn equal(left: &Value, right: &Value, options: DiffOptions) -> bool {
+        match (left, right) {
+            (Value::Number(left), Value::Number(right)) => {
+                // Integers compare exactly, since ids above 2^53 collapse together as f64
+                if let (Some(left), Some(right)) = (Self::as_i128(left), Self::as_i128(right)) {
+                    if options.numeric_tolerance <= 0.0 {
+                        return left == right;
+                    }
+                    return (left - right).unsigned_abs() as f64 <= options.numeric_tolerance;
+                }
+                match (left.as_f64(), right.as_f64()) {
+                    (Some(left), Some(right)) if options.numeric_tolerance > 0.0 => (left - right).abs() <= options.numeric_tolerance,
+                    _ => Self::decimal_digits(&left.to_string()) == Self::decimal_digits(&right.to_string()),
+                }
+            }
+            _ => left == right,
+        }
+    }
+
+    /// Sign, significant digits and exponent of a JSON number, so `1.50`, `15e-1` and `1.5` compare equal
+    fn decimal_digits(text: &str) -> (bool, String, i64) {
+        let (mantissa, exponent) = match text.split_once(['e', 'E']) {
+            Some((mantissa, exponent)) => (mantissa, exponent.trim_start_matches('+').parse::<i64>().unwrap_or(0)),
+            None => (text, 0),
+        };
+        let negative = mantissa.starts_with('-');
+        let mantissa = mantissa.trim_start_matches('-');
+        let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let digits = format!("{}{}", whole, fraction);
+        let leading = digits.len() - digits.trim_start_matches('0').len();
+        let significant = digits.trim_start_matches('0').trim_end_matches('0');
+        if significant.is_empty() {
+            return (false, String::new(), 0);
+        }
+        // Exponent of the first significant digit
+        let exponent = exponent + whole.len() as i64 - leading as i64;
+        (negative, significant.to_string(), exponent)
+    }
+
+    fn as_i128(number: &serde_json::Number) -> Option<i128> {
+        number.as_i64().map(i128::from).or_else(|| number.as_u64().map(i128::from))
+    }
+
+    fn entry(kind: DiffKind, location: &[PathSegment], pointer: Option<String>, left: Option<&Value>, right: Option<&Value>) -> DiffEntry {
+        DiffEntry {
+            kind,
+            path: JsonPathLogic::format_path(location),
+            pointer: pointer.unwrap_or_else(|| JsonPathLogic::pointer(location)),
+            left: left.cloned(),
+            right: right.cloned(),
+        }
+    }
+
+    /// Express a diff as an RFC 6902 JSON Patch that turns left into right
+    pub fn to_patch(entries: &[DiffEntry]) -> Value {
+        Value::Array(
+            entries
+                .iter()
+                .map(|entry| match entry.kind {
+                    DiffKind::Added => json!({ "op": "add", "path": entry.pointer, "value": entry.right }),
+                    DiffKind::Removed => json!({ "op": "remove", "path": entry.pointer }),
+                    DiffKind::Changed => json!({ "op": "replace", "path": entry.pointer, "value": entry.right }),
+                })
+                .collect(),
+        )
+    }
+
+    /// Count of added, removed and changed entries
+    pub fn summary(entries: &[DiffEntry]) -> (usize, usize, usize) {
+        let count = |kind: DiffKind| entries.iter().filter(|entry| entry.kind == kind).count();
+        (count(DiffKind::Added), count(DiffKind::Removed), count(DiffKind::Changed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minimal RFC 6902 applier for add/remove/replace, used to check generated patches
+    fn apply(document: &Value, patch: &Value) -> Value {
+        let mut document = document.clone();
+        for operation in patch.as_array().unwrap() {
+            let path = operation["path"].as_str().unwrap();
+            if path.is_empty() {
+                document = operation["value"].clone();
+                continue;
+            }
+            let (parent, last) = path.rsplit_once('/').unwrap();
+            let last = last.replace("~1", "/").replace("~0", "~");
+            let target = document.pointer_mut(parent).unwrap();
+            match (operation["op"].as_str().unwrap(), target) {
+                ("add", Value::Array(items)) if last == "-" => items.push(operation["value"].clone()),
+                ("add", Value::Array(items)) => items.insert(last.parse().unwrap(), operation["value"].clone()),
+                ("remove", Value::Array(items)) => {
+                    items.remove(last.parse().unwrap());
+                }
+                ("replace", Value::Array(items)) => items[last.parse::<usize>().unwrap()] = operation["value"].clone(),
+                ("add" | "replace", Value::Object(map)) => {
+                    map.insert(last, operation["value"].clone());
+                }
+                ("remove", Value::Object(map)) => {
+                    map.shift_remove(&last);
+                }
+                other => panic!("unsupported operation {:?}", other.0),
+            }
+        }
+        document
+    }
+
+    fn kinds_and_paths(entries: &[DiffEntry]) -> Vec<(DiffKind, &str)> {
+        entries.iter().map(|entry| (entry.kind, entry.path.as_str())).collect()
+    }
+
+    #[test]
+    fn test_identical_ignores_key_order() {
+        let left = json!({ "a": 1, "b": { "c": [1, 2], "d": null } });
+        let right = json!({ "b": { "d": null, "c": [1, 2] }, "a": 1 });
+        assert!(JsonDiffLogic::diff(&left, &right, DiffOptions::default()).is_empty());
+    }
+
+    #[test]
+    fn test_added_removed_changed() {
+        let left = json!({ "name": "api", "replicas": 2, "env": { "DEBUG": "1" }, "old": true });
+        let right = json!({ "name": "api", "replicas": 3, "env": { "DEBUG": "1", "LOG": "info" }, "new field": [] });
+        let entries = JsonDiffLogic::diff(&left, &right, DiffOptions::default());
+        assert_eq!(
+            kinds_and_paths(&entries),
+            vec![
+                (DiffKind::Changed, "$.replicas"),
+                (DiffKind::Added, "$.env.LOG"),
+                (DiffKind::Removed, "$.old"),
+                (DiffKind::Added, "$['new field']"),
+            ]
+        );
+        assert_eq!(entries[0].left, Some(json!(2)));
+        assert_eq!(entries[0].right, Some(json!(3)));
+        assert_eq!(JsonDiffLogic::summary(&entries), (2, 1, 1));
+    }
+
+    #[test]
+    fn test_type_change_and_root() {
+        let entries = JsonDiffLogic::diff(&json!({ "a": [1] }), &json!({ "a": { "0": 1 } }), DiffOptions::default());
+        assert_eq!(kinds_and_paths(&entries), vec![(DiffKind::Changed, "$.a")]);
+        let entries = JsonDiffLogic::diff(&json!(1), &json!("1"), DiffOptions::default());
+        assert_eq!(entries[0].pointer, "");
+    }
+
+    #[test]
+    fn test_numeric_tolerance() {
+        let left = json!({ "latency": 120.0, "ratio": 0.3333 });
+        let right = json!({ "latency": 120, "ratio": 0.3334 });
+        let exact = JsonDiffLogic::diff(&left, &right, DiffOptions::default());
+        assert_eq!(kinds_and_paths(&exact), vec![(DiffKind::Changed, "$.ratio")]);
+        let tolerant = DiffOptions { numeric_tolerance: 0.001, ..DiffOptions::default() };
+        assert!(JsonDiffLogic::diff(&left, &right, tolerant).is_empty());
+    }
+
+    #[test]
+    fn test_large_integers_compare_exactly() {
+        let left: Value = serde_json::from_str(r#"{ "id": 9007199254740993 }"#).unwrap();
+        let right: Value = serde_json::from_str(r#"{ "id": 9007199254740992 }"#).unwrap();
+        let entries = JsonDiffLogic::diff(&left, &right, DiffOptions::default());
+        assert_eq!(kinds_and_paths(&entries), vec![(DiffKind::Changed, "$.id")]);
+        let tolerant = DiffOptions { numeric_tolerance: 1.0, ..DiffOptions::default() };
+        assert!(JsonDiffLogic::diff(&left, &right, tolerant).is_empty());
+
+        let left: Value = serde_json::from_str("[0.10000000000000000001, 1.50, -0.0, 120.0]").unwrap();
+        let right: Value = serde_json::from_str("[0.1, 15e-1, 0, 120]").unwrap();
+        assert_eq!(kinds_and_paths(&JsonDiffLogic::diff(&left, &right, DiffOptions::default())), vec![(DiffKind::Changed, "$[0]")]);
+
+        let left: Value = serde_json::from_str("18446744073709551615").unwrap();
+        let right: Value = serde_json::from_str("18446744073709551614").unwrap();
+        assert_eq!(JsonDiffLogic::diff(&left, &right, DiffOptions::default()).len(), 1);
+    }
+
+    #[test]
+    fn test_arrays_ordered() {
+        let left = json!({ "items": [1, 2, 3, 4] });
+        let right = json!({ "items": [1, 5] });
+        let entries = JsonDiffLogic::diff(&left, &right, DiffOptions::default());
+        assert_eq!(
+            kinds_and_paths(&entries),
+            vec![(DiffKind::Changed, "$.items[1]"), (DiffKind::Removed, "$.items[3]"), (DiffKind::Removed, "$.items[2]")]
+        );
+        assert_eq!(apply(&left, &JsonDiffLogic::to_patch(&entries)), right);
+    }
+
+    #[test]
+    fn test_arrays_unordered() {
+        let left = json!({ "tags": ["a", "b", "c", { "id": 1 }] });
+        let right = json!({ "tags": [{ "id": 1 }, "c", "d", "a"] });
+        let options = DiffOptions { ignore_array_order: true, ..DiffOptions::default() };
+        let entries = JsonDiffLogic::diff(&left, &right, options);
+        assert_eq!(kinds_and_paths(&entries), vec![(DiffKind::Removed, "$.tags[1]"), (DiffKind::Added, "$.tags[2]")]);
+        assert_eq!(entries[1].pointer, "/tags/-");
+
+        let patched = apply(&left, &JsonDiffLogic::to_patch(&entries));
+        assert!(JsonDiffLogic::diff(&patched, &right, options).is_empty());
+    }
+
+    #[test]
+    fn test_patch_round_trip() {
+        let left = json!({ "a/b": { "x~y": 1 }, "list": [{ "id": 1, "v": "a" }, { "id": 2 }], "gone": null });
+        let right = json!({ "a/b": { "x~y": 2 }, "list": [{ "id": 1, "v": "b" }, { "id": 2 }, { "id": 3 }], "extra": { "k": [true] } });
+        let entries = JsonDiffLogic::diff(&left, &right, DiffOptions::default());
+        let patch = JsonDiffLogic::to_patch(&entries);
+        assert_eq!(patch[0], json!({ "op": "replace", "path": "/a~1b/x~0y", "value": 2 }));
+        assert_eq!(apply(&left, &patch), right);
+    }
+}
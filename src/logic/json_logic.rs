@@ -0,0 +1,241 @@
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::ser::PrettyFormatter;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonError {
+    /// Parse failure at a 1-based line and column
+    Syntax { line: usize, column: usize, message: String },
+}
+
+impl JsonError {
+    fn from_serde(err: serde_json::Error) -> Self {
+        // The position is reported separately, so drop serde's " at line X column Y" suffix
+        let message = err.to_string();
+        let message = message.rsplit_once(" at line ").map_or(message.as_str(), |(message, _)| message).to_string();
+        JsonError::Syntax { line: err.line(), column: err.column(), message }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JsonIndent {
+    #[default]
+    TwoSpaces,
+    FourSpaces,
+    Tab,
+}
+
+impl std::fmt::Display for JsonIndent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JsonIndent::TwoSpaces => write!(f, "2 spaces"),
+            JsonIndent::FourSpaces => write!(f, "4 spaces"),
+            JsonIndent::Tab => write!(f, "Tab"),
+        }
+    }
+}
+
+impl JsonIndent {
+    pub const ALL: [JsonIndent; 3] = [JsonIndent::TwoSpaces, JsonIndent::FourSpaces, JsonIndent::Tab];
+
+    fn as_bytes(self) -> &'static [u8] {
+        match self {
+            JsonIndent::TwoSpaces => b"  ",
+            JsonIndent::FourSpaces => b"    ",
+            JsonIndent::Tab => b"\t",
+        }
+    }
+}
+
+/// One source line around a syntax error, flagged when it is the offending line
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorLine {
+    pub number: usize,
+    pub text: String,
+    pub is_error: bool,
+}
+
+pub struct JsonLogic;
+
+impl JsonLogic {
+    /// Lines shown above and below the offending line
+    pub const ERROR_CONTEXT_LINES: usize = 2;
+
+    pub fn parse(input: &str) -> Result<Value, JsonError> {
+        serde_json::from_str(input).map_err(JsonError::from_serde)
+    }
+
+    /// Parse a sequence of documents separated by whitespace, such as JSON Lines
+    pub fn parse_many(input: &str) -> Result<Vec<Value>, JsonError> {
+        serde_json::Deserializer::from_str(input)
+            .into_iter::<Value>()
+            .collect::<Result<_, _>>()
+            .map_err(JsonError::from_serde)
+    }
+
+    /// Serialize a value with the given indent
+    pub fn to_pretty(value: &Value, indent: JsonIndent) -> String {
+        let mut output = Vec::new();
+        let mut serializer = serde_json::Serializer::with_formatter(&mut output, PrettyFormatter::with_indent(indent.as_bytes()));
+        // Writing a `Value` into a Vec cannot fail
+        let _ = value.serialize(&mut serializer);
+        String::from_utf8(output).unwrap_or_default()
+    }
+
+    /// Serialize a value without insignificant whitespace
+    pub fn to_minified(value: &Value) -> String {
+        value.to_string()
+    }
+
+    /// Recursively sort object keys; arrays keep their order
+    pub fn sort_keys(value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                map.sort_keys();
+                map.values_mut().for_each(Self::sort_keys);
+            }
+            Value::Array(items) => items.iter_mut().for_each(Self::sort_keys),
+            _ => {}
+        }
+    }
+
+    /// Source lines around a syntax error, for highlighting the offending one
+    pub fn error_excerpt(input: &str, line: usize) -> Vec<ErrorLine> {
+        let first = line.saturating_sub(Self::ERROR_CONTEXT_LINES).max(1);
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, text)| (index + 1, text))
+            .skip(first - 1)
+            .take(line + Self::ERROR_CONTEXT_LINES + 1 - first)
+            .map(|(number, text)| ErrorLine { number, text: text.to_string(), is_error: number == line })
+            .collect()
+    }
+
+    /// JSON type name of a value, as shown in badges and summaries
+    pub fn type_name(value: &Value) -> &'static str {
+        match value {
+            Value::Object(_) => "object",
+            Value::Array(_) => "array",
+            Value::String(_) => "string",
+            Value::Number(_) => "number",
+            Value::Bool(_) => "boolean",
+            Value::Null => "null",
+        }
+    }
+
+    /// Short summary such as `object · 12 keys · 3 levels deep`
+    pub fn describe(value: &Value) -> String {
+        let kind = match value {
+            Value::Object(map) => format!("object · {} keys", map.len()),
+            Value::Array(items) => format!("array · {} items", items.len()),
+            other => Self::type_name(other).to_string(),
+        };
+        format!("{} · {} levels deep", kind, Self::depth(value))
+    }
+
+    fn depth(value: &Value) -> usize {
+        match value {
+            Value::Object(map) => 1 + map.values().map(Self::depth).max().unwrap_or(0),
+            Value::Array(items) => 1 + items.iter().map(Self::depth).max().unwrap_or(0),
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"{"b": [1, 2.50, {"d": null, "c": true}], "a": "x"}"#;
+
+    fn format(input: &str, indent: JsonIndent, sort_keys: bool) -> Result<String, JsonError> {
+        let mut value = JsonLogic::parse(input)?;
+        if sort_keys {
+            JsonLogic::sort_keys(&mut value);
+        }
+        Ok(JsonLogic::to_pretty(&value, indent))
+    }
+
+    fn minify(input: &str, sort_keys: bool) -> Result<String, JsonError> {
+        let mut value = JsonLogic::parse(input)?;
+        if sort_keys {
+            JsonLogic::sort_keys(&mut value);
+        }
+        Ok(JsonLogic::to_minified(&value))
+    }
+
+    #[test]
+    fn test_format_indents() {
+        assert_eq!(
+            format(r#"{"a":[1,{"b":null}]}"#, JsonIndent::TwoSpaces, false).unwrap(),
+            "{\n  \"a\": [\n    1,\n    {\n      \"b\": null\n    }\n  ]\n}"
+        );
+        assert_eq!(format(r#"{"a":1}"#, JsonIndent::FourSpaces, false).unwrap(), "{\n    \"a\": 1\n}");
+        assert_eq!(format(r#"{"a":1}"#, JsonIndent::Tab, false).unwrap(), "{\n\t\"a\": 1\n}");
+    }
+
+    #[test]
+    fn test_minify_keeps_order_and_numbers() {
+        assert_eq!(minify(SAMPLE, false).unwrap(), r#"{"b":[1,2.50,{"d":null,"c":true}],"a":"x"}"#);
+        assert_eq!(
+            minify("[12345678901234567890123, 1e400, -0.0]", false).unwrap(),
+            "[12345678901234567890123,1e400,-0.0]"
+        );
+    }
+
+    #[test]
+    fn test_sort_keys() {
+        assert_eq!(minify(SAMPLE, true).unwrap(), r#"{"a":"x","b":[1,2.50,{"c":true,"d":null}]}"#);
+    }
+
+    #[test]
+    fn test_syntax_error_position() {
+        let input = "{\n  \"a\": 1,\n  \"b\" 2\n}";
+        match format(input, JsonIndent::TwoSpaces, false) {
+            Err(JsonError::Syntax { line, column, message }) => {
+                assert_eq!((line, column), (3, 7));
+                assert_eq!(message, "expected `:`");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert!(matches!(minify("", false), Err(JsonError::Syntax { line: 1, column: 0, .. })));
+    }
+
+    #[test]
+    fn test_parse_many() {
+        let documents = JsonLogic::parse_many("{\"a\": 1}\n{\"a\": 2}[3] \"x\"").unwrap();
+        assert_eq!(documents.len(), 4);
+        assert_eq!(documents[3], Value::String("x".to_string()));
+        assert!(JsonLogic::parse_many("  \n").unwrap().is_empty());
+        assert!(matches!(JsonLogic::parse_many("{}\n{]"), Err(JsonError::Syntax { line: 2, column: 2, .. })));
+    }
+
+    #[test]
+    fn test_error_excerpt() {
+        let input = "1\n2\n3\n4\n5\n6\n7";
+        let excerpt = JsonLogic::error_excerpt(input, 4);
+        let numbers: Vec<usize> = excerpt.iter().map(|line| line.number).collect();
+        assert_eq!(numbers, vec![2, 3, 4, 5, 6]);
+        assert!(excerpt[2].is_error && excerpt[2].text == "4");
+
+        let numbers: Vec<usize> = JsonLogic::error_excerpt(input, 1).iter().map(|line| line.number).collect();
+        assert_eq!(numbers, vec![1, 2, 3]);
+        let numbers: Vec<usize> = JsonLogic::error_excerpt(input, 7).iter().map(|line| line.number).collect();
+        assert_eq!(numbers, vec![5, 6, 7]);
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(JsonLogic::describe(&JsonLogic::parse(SAMPLE).unwrap()), "object · 2 keys · 3 levels deep");
+        assert_eq!(JsonLogic::describe(&JsonLogic::parse("42").unwrap()), "number · 0 levels deep");
+    }
+
+    #[test]
+    fn test_large_document() {
+        let items: Vec<String> = (0..50_000).map(|i| format!(r#"{{"id":{},"name":"item {}"}}"#, i, i)).collect();
+        let input = format!("[{}]", items.join(","));
+        let pretty = format(&input, JsonIndent::TwoSpaces, true).unwrap();
+        assert_eq!(minify(&pretty, false).unwrap(), input);
+    }
+}
@@ -0,0 +1,681 @@
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonPathError {
+    /// Unexpected input at a 0-based character offset
+    Syntax { position: usize, message: String },
+}
+
+/// One step from a node to a child
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// A node matched by a query, with its normalized path and JSON Pointer
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathMatch {
+    pub path: String,
+    /// RFC 6901 pointer for looking the node up in the queried document
+    pub pointer: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Selector {
+    Name(String),
+    Index(i64),
+    Wildcard,
+    Slice(Option<i64>, Option<i64>, i64),
+    Filter(Expr),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Step {
+    Child(Vec<Selector>),
+    Descendant(Vec<Selector>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Operand {
+    /// `@...` or `$...` followed by names and indices
+    Query { relative: bool, segments: Vec<PathSegment> },
+    Literal(Value),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Exists(Operand),
+    Compare(Operand, Comparison, Operand),
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+}
+
+/// Recursive-descent parser for a JSONPath expression
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn error<T>(&self, message: &str) -> Result<T, JsonPathError> {
+        Err(JsonPathError::Syntax { position: self.position, message: message.to_string() })
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn starts_with(&self, text: &str) -> bool {
+        text.chars().enumerate().all(|(offset, c)| self.chars.get(self.position + offset) == Some(&c))
+    }
+
+    fn eat(&mut self, text: &str) -> bool {
+        if self.starts_with(text) {
+            self.position += text.chars().count();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, text: &str) -> Result<(), JsonPathError> {
+        if self.eat(text) { Ok(()) } else { self.error(&format!("expected `{}`", text)) }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    fn is_name_char(c: char) -> bool {
+        c.is_alphanumeric() || c == '_' || c == '-'
+    }
+
+    fn parse_path(&mut self) -> Result<Vec<Step>, JsonPathError> {
+        self.skip_whitespace();
+        self.expect("$")?;
+        let mut steps = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.eat("..") {
+                let selectors = match self.peek() {
+                    Some('[') => self.parse_bracket()?,
+                    Some('*') => {
+                        self.position += 1;
+                        vec![Selector::Wildcard]
+                    }
+                    _ => vec![Selector::Name(self.parse_name()?)],
+                };
+                steps.push(Step::Descendant(selectors));
+            } else if self.eat(".") {
+                if self.eat("*") {
+                    steps.push(Step::Child(vec![Selector::Wildcard]));
+                } else {
+                    steps.push(Step::Child(vec![Selector::Name(self.parse_name()?)]));
+                }
+            } else if self.peek() == Some('[') {
+                steps.push(Step::Child(self.parse_bracket()?));
+            } else if self.peek().is_none() {
+                return Ok(steps);
+            } else {
+                return self.error("expected `.`, `..` or `[`");
+            }
+        }
+    }
+
+    fn parse_name(&mut self) -> Result<String, JsonPathError> {
+        let start = self.position;
+        while self.peek().is_some_and(Self::is_name_char) {
+            self.position += 1;
+        }
+        if start == self.position {
+            return self.error("expected a member name");
+        }
+        Ok(self.chars[start..self.position].iter().collect())
+    }
+
+    fn parse_bracket(&mut self) -> Result<Vec<Selector>, JsonPathError> {
+        self.expect("[")?;
+        let mut selectors = Vec::new();
+        loop {
+            self.skip_whitespace();
+            selectors.push(self.parse_selector()?);
+            self.skip_whitespace();
+            if self.eat("]") {
+                return Ok(selectors);
+            }
+            self.expect(",")?;
+        }
+    }
+
+    fn parse_selector(&mut self) -> Result<Selector, JsonPathError> {
+        match self.peek() {
+            Some('*') => {
+                self.position += 1;
+                Ok(Selector::Wildcard)
+            }
+            Some('\'') | Some('"') => Ok(Selector::Name(self.parse_string()?)),
+            Some('?') => {
+                self.position += 1;
+                self.skip_whitespace();
+                // Both `?(@.a)` and the RFC 9535 form `?@.a` are accepted
+                Ok(Selector::Filter(self.parse_or()?))
+            }
+            Some(c) if c == ':' || c == '-' || c.is_ascii_digit() => self.parse_index_or_slice(),
+            _ => self.error("expected a selector"),
+        }
+    }
+
+    fn parse_integer(&mut self) -> Result<Option<i64>, JsonPathError> {
+        self.skip_whitespace();
+        let start = self.position;
+        self.eat("-");
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.position += 1;
+        }
+        if start == self.position {
+            return Ok(None);
+        }
+        let text: String = self.chars[start..self.position].iter().collect();
+        match text.parse() {
+            Ok(number) => Ok(Some(number)),
+            Err(_) => {
+                self.position = start;
+                self.error("expected an integer")
+            }
+        }
+    }
+
+    fn parse_index_or_slice(&mut self) -> Result<Selector, JsonPathError> {
+        let start = self.parse_integer()?;
+        self.skip_whitespace();
+        if !self.eat(":") {
+            return match start {
+                Some(index) => Ok(Selector::Index(index)),
+                None => self.error("expected an index"),
+            };
+        }
+        let end = self.parse_integer()?;
+        self.skip_whitespace();
+        let step = if self.eat(":") { self.parse_integer()?.unwrap_or(1) } else { 1 };
+        Ok(Selector::Slice(start, end, step))
+    }
+
+    fn parse_string(&mut self) -> Result<String, JsonPathError> {
+        let Some(quote) = self.peek() else {
+            return self.error("expected a string");
+        };
+        self.position += 1;
+        let mut text = String::new();
+        loop {
+            match self.peek() {
+                None => return self.error("unterminated string"),
+                Some(c) if c == quote => {
+                    self.position += 1;
+                    return Ok(text);
+                }
+                Some('\\') => {
+                    self.position += 1;
+                    match self.peek() {
+                        Some('n') => text.push('\n'),
+                        Some('t') => text.push('\t'),
+                        Some(c) => text.push(c),
+                        None => return self.error("unterminated string"),
+                    }
+                    self.position += 1;
+                }
+                Some(c) => {
+                    text.push(c);
+                    self.position += 1;
+                }
+            }
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, JsonPathError> {
+        let mut expr = self.parse_and()?;
+        loop {
+            self.skip_whitespace();
+            if !self.eat("||") {
+                return Ok(expr);
+            }
+            expr = Expr::Or(Box::new(expr), Box::new(self.parse_and()?));
+        }
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, JsonPathError> {
+        let mut expr = self.parse_unary()?;
+        loop {
+            self.skip_whitespace();
+            if !self.eat("&&") {
+                return Ok(expr);
+            }
+            expr = Expr::And(Box::new(expr), Box::new(self.parse_unary()?));
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, JsonPathError> {
+        self.skip_whitespace();
+        if self.starts_with("!=") {
+            return self.error("expected an expression");
+        }
+        if self.eat("!") {
+            return Ok(Expr::Not(Box::new(self.parse_unary()?)));
+        }
+        if self.eat("(") {
+            let expr = self.parse_or()?;
+            self.skip_whitespace();
+            self.expect(")")?;
+            return Ok(expr);
+        }
+
+        let left = self.parse_operand()?;
+        self.skip_whitespace();
+        let comparison = [
+            ("==", Comparison::Eq),
+            ("!=", Comparison::Ne),
+            ("<=", Comparison::Le),
+            (">=", Comparison::Ge),
+            ("<", Comparison::Lt),
+            (">", Comparison::Gt),
+        ]
+        .into_iter()
+        .find(|(operator, _)| self.starts_with(operator));
+
+        match comparison {
+            Some((operator, comparison)) => {
+                self.eat(operator);
+                self.skip_whitespace();
+                let right = self.parse_operand()?;
+                Ok(Expr::Compare(left, comparison, right))
+            }
+            None if matches!(left, Operand::Query { .. }) => Ok(Expr::Exists(left)),
+            None => self.error("expected a comparison operator"),
+        }
+    }
+
+    fn parse_operand(&mut self) -> Result<Operand, JsonPathError> {
+        match self.peek() {
+            Some('@') | Some('$') => {
+                let relative = self.peek() == Some('@');
+                self.position += 1;
+                let mut segments = Vec::new();
+                loop {
+                    if self.starts_with(".") && !self.starts_with("..") {
+                        self.position += 1;
+                        segments.push(PathSegment::Key(self.parse_name()?));
+                    } else if self.eat("[") {
+                        self.skip_whitespace();
+                        match self.peek() {
+                            Some('\'') | Some('"') => segments.push(PathSegment::Key(self.parse_string()?)),
+                            _ => match self.parse_integer()? {
+                                Some(index) if index >= 0 => segments.push(PathSegment::Index(index as usize)),
+                                _ => return self.error("expected a name or non-negative index"),
+                            },
+                        }
+                        self.skip_whitespace();
+                        self.expect("]")?;
+                    } else {
+                        return Ok(Operand::Query { relative, segments });
+                    }
+                }
+            }
+            Some('\'') | Some('"') => Ok(Operand::Literal(Value::String(self.parse_string()?))),
+            _ => {
+                for (keyword, value) in [("true", Value::Bool(true)), ("false", Value::Bool(false)), ("null", Value::Null)] {
+                    if self.eat(keyword) {
+                        return Ok(Operand::Literal(value));
+                    }
+                }
+                let start = self.position;
+                while self.peek().is_some_and(|c| c.is_ascii_digit() || "+-.eE".contains(c)) {
+                    self.position += 1;
+                }
+                let text: String = self.chars[start..self.position].iter().collect();
+                match serde_json::from_str::<serde_json::Number>(&text) {
+                    Ok(number) => Ok(Operand::Literal(Value::Number(number))),
+                    Err(_) => {
+                        self.position = start;
+                        self.error("expected a value")
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub struct JsonPathLogic;
+
+impl JsonPathLogic {
+    /// Evaluate a JSONPath query, returning matches in document order
+    ///
+    /// Supports child and recursive (`..`) member access, wildcards, indices
+    /// (negative from the end), slices, unions, and filters such as
+    /// `[?(@.price < 10 && @.tags)]`.
+    pub fn query(root: &Value, path: &str) -> Result<Vec<PathMatch>, JsonPathError> {
+        let mut parser = Parser { chars: path.chars().collect(), position: 0 };
+        let steps = parser.parse_path()?;
+
+        let mut nodes: Vec<(Vec<PathSegment>, &Value)> = vec![(Vec::new(), root)];
+        for step in &steps {
+            let mut next = Vec::new();
+            for (location, value) in &nodes {
+                match step {
+                    Step::Child(selectors) => Self::select(root, location, value, selectors, &mut next),
+                    Step::Descendant(selectors) => Self::descend(root, location, value, selectors, &mut next),
+                }
+            }
+            nodes = next;
+        }
+
+        Ok(nodes
+            .into_iter()
+            .map(|(location, _)| PathMatch { path: Self::format_path(&location), pointer: Self::pointer(&location) })
+            .collect())
+    }
+
+    fn select<'a>(
+        root: &Value,
+        location: &[PathSegment],
+        value: &'a Value,
+        selectors: &[Selector],
+        output: &mut Vec<(Vec<PathSegment>, &'a Value)>,
+    ) {
+        let child = |segment: PathSegment| {
+            let mut child = location.to_vec();
+            child.push(segment);
+            child
+        };
+
+        for selector in selectors {
+            match (selector, value) {
+                (Selector::Name(name), Value::Object(map)) => {
+                    if let Some(found) = map.get(name) {
+                        output.push((child(PathSegment::Key(name.clone())), found));
+                    }
+                }
+                (Selector::Index(index), Value::Array(items)) => {
+                    let resolved = if *index < 0 { items.len() as i64 + index } else { *index };
+                    if let Some(found) = usize::try_from(resolved).ok().and_then(|resolved| items.get(resolved)) {
+                        output.push((child(PathSegment::Index(resolved as usize)), found));
+                    }
+                }
+                (Selector::Wildcard, Value::Object(map)) => {
+                    for (key, found) in map {
+                        output.push((child(PathSegment::Key(key.clone())), found));
+                    }
+                }
+                (Selector::Wildcard, Value::Array(items)) => {
+                    for (index, found) in items.iter().enumerate() {
+                        output.push((child(PathSegment::Index(index)), found));
+                    }
+                }
+                (Selector::Slice(start, end, step), Value::Array(items)) => {
+                    for index in Self::slice_indices(items.len(), *start, *end, *step) {
+                        output.push((child(PathSegment::Index(index)), &items[index]));
+                    }
+                }
+                (Selector::Filter(expr), Value::Object(map)) => {
+                    for (key, found) in map {
+                        if Self::matches(root, found, expr) {
+                            output.push((child(PathSegment::Key(key.clone())), found));
+                        }
+                    }
+                }
+                (Selector::Filter(expr), Value::Array(items)) => {
+                    for (index, found) in items.iter().enumerate() {
+                        if Self::matches(root, found, expr) {
+                            output.push((child(PathSegment::Index(index)), found));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn descend<'a>(
+        root: &Value,
+        location: &[PathSegment],
+        value: &'a Value,
+        selectors: &[Selector],
+        output: &mut Vec<(Vec<PathSegment>, &'a Value)>,
+    ) {
+        Self::select(root, location, value, selectors, output);
+        let children: Vec<(PathSegment, &Value)> = match value {
+            Value::Object(map) => map.iter().map(|(key, child)| (PathSegment::Key(key.clone()), child)).collect(),
+            Value::Array(items) => items.iter().enumerate().map(|(index, child)| (PathSegment::Index(index), child)).collect(),
+            _ => Vec::new(),
+        };
+        for (segment, child) in children {
+            let mut child_location = location.to_vec();
+            child_location.push(segment);
+            Self::descend(root, &child_location, child, selectors, output);
+        }
+    }
+
+    fn slice_indices(length: usize, start: Option<i64>, end: Option<i64>, step: i64) -> Vec<usize> {
+        let length = length as i64;
+        let normalize = |bound: i64| if bound < 0 { length + bound } else { bound };
+        match step {
+            0 => Vec::new(),
+            step if step > 0 => {
+                let start = normalize(start.unwrap_or(0)).clamp(0, length);
+                let end = normalize(end.unwrap_or(length)).clamp(0, length);
+                (start..end).step_by(step as usize).map(|index| index as usize).collect()
+            }
+            step => {
+                let start = normalize(start.unwrap_or(length - 1)).clamp(-1, length - 1);
+                let end = end.map(normalize).unwrap_or(-1).clamp(-1, length - 1);
+                let mut indices = Vec::new();
+                let mut index = start;
+                while index > end {
+                    indices.push(index as usize);
+                    index += step;
+                }
+                indices
+            }
+        }
+    }
+
+    /// Borrow an operand's value, from the document for queries or the expression for literals
+    fn resolve<'a>(root: &'a Value, current: &'a Value, operand: &'a Operand) -> Option<&'a Value> {
+        match operand {
+            Operand::Literal(value) => Some(value),
+            Operand::Query { relative, segments } => {
+                let mut value = if *relative { current } else { root };
+                for segment in segments {
+                    value = match (segment, value) {
+                        (PathSegment::Key(key), Value::Object(map)) => map.get(key)?,
+                        (PathSegment::Index(index), Value::Array(items)) => items.get(*index)?,
+                        _ => return None,
+                    };
+                }
+                Some(value)
+            }
+        }
+    }
+
+    fn matches(root: &Value, current: &Value, expr: &Expr) -> bool {
+        match expr {
+            Expr::Exists(operand) => Self::resolve(root, current, operand).is_some(),
+            Expr::Not(inner) => !Self::matches(root, current, inner),
+            Expr::And(left, right) => Self::matches(root, current, left) && Self::matches(root, current, right),
+            Expr::Or(left, right) => Self::matches(root, current, left) || Self::matches(root, current, right),
+            Expr::Compare(left, comparison, right) => {
+                Self::compare(Self::resolve(root, current, left), *comparison, Self::resolve(root, current, right))
+            }
+        }
+    }
+
+    fn compare(left: Option<&Value>, comparison: Comparison, right: Option<&Value>) -> bool {
+        let ordering = match (left, right) {
+            (Some(Value::Number(left)), Some(Value::Number(right))) => {
+                left.as_f64().zip(right.as_f64()).and_then(|(left, right)| left.partial_cmp(&right))
+            }
+            (Some(Value::String(left)), Some(Value::String(right))) => Some(left.cmp(right)),
+            (left, right) => {
+                // Other types, and missing members, only support equality
+                let equal = left == right;
+                return match comparison {
+                    Comparison::Eq | Comparison::Le | Comparison::Ge => equal,
+                    Comparison::Ne => !equal,
+                    Comparison::Lt | Comparison::Gt => false,
+                };
+            }
+        };
+        let Some(ordering) = ordering else {
+            return comparison == Comparison::Ne;
+        };
+        match comparison {
+            Comparison::Eq => ordering.is_eq(),
+            Comparison::Ne => ordering.is_ne(),
+            Comparison::Lt => ordering.is_lt(),
+            Comparison::Le => ordering.is_le(),
+            Comparison::Gt => ordering.is_gt(),
+            Comparison::Ge => ordering.is_ge(),
+        }
+    }
+
+    /// Render segments as a JSONPath such as `$.store.book[0]['first name']`
+    pub fn format_path(segments: &[PathSegment]) -> String {
+        let mut path = String::from("$");
+        for segment in segments {
+            Self::push_segment(&mut path, segment);
+        }
+        path
+    }
+
+    /// Path of a child node, given its parent's path
+    pub fn child_path(parent: &str, segment: &PathSegment) -> String {
+        let mut path = parent.to_string();
+        Self::push_segment(&mut path, segment);
+        path
+    }
+
+    fn push_segment(path: &mut String, segment: &PathSegment) {
+        match segment {
+            PathSegment::Index(index) => path.push_str(&format!("[{}]", index)),
+            PathSegment::Key(key) if Self::is_identifier(key) => {
+                path.push('.');
+                path.push_str(key);
+            }
+            PathSegment::Key(key) => {
+                path.push_str(&format!("['{}']", key.replace('\\', "\\\\").replace('\'', "\\'")));
+            }
+        }
+    }
+
+    fn is_identifier(key: &str) -> bool {
+        let mut chars = key.chars();
+        chars.next().is_some_and(|c| c.is_alphabetic() || c == '_') && chars.all(|c| c.is_alphanumeric() || c == '_')
+    }
+
+    /// JSON Pointer (RFC 6901) for a list of segments, used to look nodes up again
+    pub fn pointer(segments: &[PathSegment]) -> String {
+        segments
+            .iter()
+            .map(|segment| match segment {
+                PathSegment::Key(key) => format!("/{}", key.replace('~', "~0").replace('/', "~1")),
+                PathSegment::Index(index) => format!("/{}", index),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn store() -> Value {
+        json!({
+            "store": {
+                "book": [
+                    { "category": "reference", "author": "Nigel Rees", "title": "Sayings of the Century", "price": 8.95 },
+                    { "category": "fiction", "author": "Evelyn Waugh", "title": "Sword of Honour", "price": 12.99 },
+                    { "category": "fiction", "author": "Herman Melville", "title": "Moby Dick", "isbn": "0-553-21311-3", "price": 8.99 },
+                    { "category": "fiction", "author": "J. R. R. Tolkien", "title": "The Lord of the Rings", "isbn": "0-395-19395-8", "price": 22.99 }
+                ],
+                "bicycle": { "color": "red", "price": 399 }
+            },
+            "first name": "Ann"
+        })
+    }
+
+    fn paths(path: &str) -> Vec<String> {
+        JsonPathLogic::query(&store(), path).unwrap().into_iter().map(|found| found.path).collect()
+    }
+
+    fn values(path: &str) -> Vec<Value> {
+        let root = store();
+        JsonPathLogic::query(&root, path).unwrap().into_iter().map(|found| root.pointer(&found.pointer).unwrap().clone()).collect()
+    }
+
+    #[test]
+    fn test_child_and_index() {
+        assert_eq!(values("$.store.bicycle.color"), vec![json!("red")]);
+        assert_eq!(values("$['store']['book'][0].author"), vec![json!("Nigel Rees")]);
+        assert_eq!(values("$.store.book[-1].title"), vec![json!("The Lord of the Rings")]);
+        assert_eq!(paths("$.store.book[9]"), Vec::<String>::new());
+        assert_eq!(paths("$"), vec!["$"]);
+    }
+
+    #[test]
+    fn test_wildcard_and_descendants() {
+        assert_eq!(values("$.store.book[*].author").len(), 4);
+        assert_eq!(values("$..price").len(), 5);
+        assert_eq!(paths("$..bicycle.*"), vec!["$.store.bicycle.color", "$.store.bicycle.price"]);
+        assert_eq!(paths("$..book[2]"), vec!["$.store.book[2]"]);
+    }
+
+    #[test]
+    fn test_slices_and_unions() {
+        assert_eq!(paths("$.store.book[:2]"), vec!["$.store.book[0]", "$.store.book[1]"]);
+        assert_eq!(paths("$.store.book[-2:]"), vec!["$.store.book[2]", "$.store.book[3]"]);
+        assert_eq!(paths("$.store.book[::-2]"), vec!["$.store.book[3]", "$.store.book[1]"]);
+        assert_eq!(paths("$.store.book[0,3].price"), vec!["$.store.book[0].price", "$.store.book[3].price"]);
+        assert_eq!(values("$.store.bicycle['color','price']"), vec![json!("red"), json!(399)]);
+    }
+
+    #[test]
+    fn test_filters() {
+        assert_eq!(values("$.store.book[?(@.price < 10)].title"), vec![json!("Sayings of the Century"), json!("Moby Dick")]);
+        assert_eq!(values("$..book[?(@.isbn)].price"), vec![json!(8.99), json!(22.99)]);
+        assert_eq!(values("$..book[?@.category == 'fiction' && @.price > 20].author"), vec![json!("J. R. R. Tolkien")]);
+        assert_eq!(values("$..book[?(!@.isbn || @.price >= 22.99)]").len(), 3);
+        assert_eq!(values("$..book[?(@.price == $.store.bicycle.price)]").len(), 0);
+        assert_eq!(values("$.store.*[?(@ == 'red')]"), vec![json!("red")]);
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        assert!(matches!(JsonPathLogic::query(&store(), "store"), Err(JsonPathError::Syntax { position: 0, .. })));
+        assert!(matches!(JsonPathLogic::query(&store(), "$.store["), Err(JsonPathError::Syntax { .. })));
+        assert!(matches!(JsonPathLogic::query(&store(), "$[?(@.a <)]"), Err(JsonPathError::Syntax { .. })));
+        assert!(matches!(JsonPathLogic::query(&store(), "$['unterminated]"), Err(JsonPathError::Syntax { .. })));
+    }
+
+    #[test]
+    fn test_format_path() {
+        assert_eq!(paths("$['first name']"), vec!["$['first name']"]);
+        let segments = vec![PathSegment::Key("a/b".to_string()), PathSegment::Index(2), PathSegment::Key("it's".to_string())];
+        assert_eq!(JsonPathLogic::format_path(&segments), "$['a/b'][2]['it\\'s']");
+        assert_eq!(JsonPathLogic::query(&json!({ "it's": 1 }), "$['it\\'s']").unwrap()[0].pointer, "/it's");
+        assert_eq!(JsonPathLogic::child_path("$.a", &PathSegment::Key("b".to_string())), "$.a.b");
+        assert_eq!(JsonPathLogic::pointer(&segments), "/a~1b/2/it's");
+    }
+}
@@ -0,0 +1,255 @@
+use serde_json::{Map, Value, json};
+use crate::logic::shape_logic::{Shape, ShapeLogic};
+
+/// Dialect written into inferred schemas
+pub const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SchemaError {
+    /// The schema itself is not a valid JSON Schema
+    InvalidSchema(String),
+}
+
+impl std::fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaError::InvalidSchema(message) => write!(f, "Invalid schema: {}", message),
+        }
+    }
+}
+
+/// One way an instance fails its schema
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    /// JSON Pointer of the offending value, empty for the root
+    pub instance_path: String,
+    /// JSON Pointer of the keyword that rejected it
+    pub schema_path: String,
+    pub message: String,
+}
+
+/// A schema checked and compiled once, ready to validate any number of instances
+pub struct CompiledSchema(jsonschema::Validator);
+
+pub struct JsonSchemaLogic;
+
+impl JsonSchemaLogic {
+    /// Infer a draft 2020-12 schema that accepts every sample; properties missing from some samples are optional
+    pub fn infer(samples: &[Value]) -> Value {
+        let shape = ShapeLogic::observe(samples);
+
+        let mut schema = Map::new();
+        schema.insert("$schema".to_string(), json!(SCHEMA_DIALECT));
+        schema.extend(Self::shape_schema(&shape));
+        Value::Object(schema)
+    }
+
+    /// Compile a schema for validation; the dialect follows `$schema` and defaults to draft 2020-12
+    pub fn compile(schema: &Value) -> Result<CompiledSchema, SchemaError> {
+        jsonschema::validator_for(schema)
+            .map(CompiledSchema)
+            .map_err(|err| SchemaError::InvalidSchema(err.to_string()))
+    }
+
+    /// Every way an instance fails a compiled schema
+    pub fn validate(schema: &CompiledSchema, instance: &Value) -> Vec<Violation> {
+        schema
+            .0
+            .iter_errors(instance)
+            .map(|err| Violation {
+                instance_path: err.instance_path.to_string(),
+                schema_path: err.schema_path.to_string(),
+                message: err.to_string(),
+            })
+            .collect()
+    }
+
+    /// Schema keywords describing one position; the root gets `$schema` added by `infer`
+    fn shape_schema(shape: &Shape) -> Map<String, Value> {
+        let mut schema = Map::new();
+        let mut types = Vec::new();
+        if shape.object.is_some() {
+            types.push("object");
+        }
+        if shape.array.is_some() {
+            types.push("array");
+        }
+        if shape.string.is_some() {
+            types.push("string");
+        }
+        // Integers are numbers, so a mix of both widens to "number"
+        if shape.float {
+            types.push("number");
+        } else if shape.integer || shape.unsigned {
+            types.push("integer");
+        }
+        if shape.boolean {
+            types.push("boolean");
+        }
+        if shape.null {
+            types.push("null");
+        }
+        match types.as_slice() {
+            [] => {}
+            [single] => {
+                schema.insert("type".to_string(), json!(single));
+            }
+            many => {
+                schema.insert("type".to_string(), json!(many));
+            }
+        }
+
+        if let Some(Some(format)) = shape.string {
+            schema.insert("format".to_string(), json!(format));
+        }
+        if let Some(object) = &shape.object {
+            let properties: Map<String, Value> = object
+                .properties
+                .iter()
+                .map(|(key, shape, _)| (key.clone(), Value::Object(Self::shape_schema(shape))))
+                .collect();
+            let required: Vec<Value> = object
+                .properties
+                .iter()
+                .filter(|(_, _, count)| *count == object.seen)
+                .map(|(key, _, _)| json!(key))
+                .collect();
+            schema.insert("properties".to_string(), Value::Object(properties));
+            if !required.is_empty() {
+                schema.insert("required".to_string(), Value::Array(required));
+            }
+        }
+        if let Some(element) = shape.array.as_ref().filter(|element| !element.is_empty()) {
+            schema.insert("items".to_string(), Value::Object(Self::shape_schema(element)));
+        }
+        schema
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(schema: &Value, instance: &Value) -> Vec<Violation> {
+        JsonSchemaLogic::validate(&JsonSchemaLogic::compile(schema).unwrap(), instance)
+    }
+
+    fn messages_at(violations: &[Violation]) -> Vec<&str> {
+        violations.iter().map(|violation| violation.instance_path.as_str()).collect()
+    }
+
+    #[test]
+    fn test_infer_scalars_and_formats() {
+        let schema = JsonSchemaLogic::infer(&[json!({
+            "id": "3f2b8c1e-4d5a-4b6c-9e7f-0a1b2c3d4e5f",
+            "count": 3,
+            "ratio": 0.5,
+            "active": true,
+            "deleted_at": null,
+            "created": "2024-05-01T12:00:00Z",
+            "day": "2024-05-01",
+            "email": "ops@example.com",
+            "homepage": "https://example.com/a",
+            "name": "api"
+        })]);
+        assert_eq!(schema["$schema"], json!(SCHEMA_DIALECT));
+        assert_eq!(schema["type"], json!("object"));
+        let properties = &schema["properties"];
+        assert_eq!(properties["id"], json!({ "type": "string", "format": "uuid" }));
+        assert_eq!(properties["count"], json!({ "type": "integer" }));
+        assert_eq!(properties["ratio"], json!({ "type": "number" }));
+        assert_eq!(properties["active"], json!({ "type": "boolean" }));
+        assert_eq!(properties["deleted_at"], json!({ "type": "null" }));
+        assert_eq!(properties["created"]["format"], json!("date-time"));
+        assert_eq!(properties["day"]["format"], json!("date"));
+        assert_eq!(properties["email"]["format"], json!("email"));
+        assert_eq!(properties["homepage"]["format"], json!("uri"));
+        assert_eq!(properties["name"], json!({ "type": "string" }));
+        assert_eq!(schema["required"].as_array().unwrap().len(), 10);
+    }
+
+    #[test]
+    fn test_infer_merges_samples() {
+        let samples = [
+            json!({ "id": 1, "tags": ["a"], "owner": { "name": "x" }, "score": 1 }),
+            json!({ "id": 2, "tags": [], "owner": null, "score": 2.5, "note": "hi" }),
+        ];
+        let schema = JsonSchemaLogic::infer(&samples);
+        assert_eq!(schema["required"], json!(["id", "tags", "owner", "score"]));
+        let properties = &schema["properties"];
+        assert_eq!(properties["tags"], json!({ "type": "array", "items": { "type": "string" } }));
+        assert_eq!(
+            properties["owner"],
+            json!({ "type": ["object", "null"], "properties": { "name": { "type": "string" } }, "required": ["name"] })
+        );
+        assert_eq!(properties["score"], json!({ "type": "number" }));
+        assert_eq!(properties["note"], json!({ "type": "string" }));
+    }
+
+    #[test]
+    fn test_infer_arrays() {
+        let schema = JsonSchemaLogic::infer(&[json!([{ "a": 1 }, { "a": 2, "b": "x" }])]);
+        assert_eq!(schema["type"], json!("array"));
+        assert_eq!(schema["items"]["required"], json!(["a"]));
+        assert_eq!(JsonSchemaLogic::infer(&[json!([])]), json!({ "$schema": SCHEMA_DIALECT, "type": "array" }));
+        // Mixed string formats fall back to a plain string
+        let schema = JsonSchemaLogic::infer(&[json!(["2024-05-01", "hello"])]);
+        assert_eq!(schema["items"], json!({ "type": "string" }));
+    }
+
+    #[test]
+    fn test_inferred_schema_accepts_samples() {
+        let samples = [
+            json!({ "users": [{ "id": 1, "email": "a@b.co" }, { "id": 2, "email": "c@d.io", "admin": true }] }),
+            json!({ "users": [], "next": "https://api.example.com/users?page=2" }),
+        ];
+        let schema = JsonSchemaLogic::compile(&JsonSchemaLogic::infer(&samples)).unwrap();
+        for sample in &samples {
+            assert!(JsonSchemaLogic::validate(&schema, sample).is_empty());
+        }
+        let violations = JsonSchemaLogic::validate(&schema, &json!({ "users": [{ "id": "1", "email": "a@b.co" }] }));
+        assert_eq!(messages_at(&violations), vec!["/users/0/id"]);
+    }
+
+    #[test]
+    fn test_validate_lists_every_violation() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string", "minLength": 3 },
+                "region": { "type": "string" },
+                "replicas": { "type": "integer", "minimum": 1 },
+                "ports": { "type": "array", "items": { "type": "integer", "maximum": 65535 } }
+            },
+            "required": ["name", "region"],
+            "additionalProperties": false
+        });
+        let instance = json!({ "name": "ab", "replicas": 0, "ports": [80, 70000], "extra": 1 });
+        let violations = check(&schema, &instance);
+        let mut paths = messages_at(&violations);
+        paths.sort();
+        assert_eq!(paths, vec!["", "", "/name", "/ports/1", "/replicas"]);
+        let region = violations.iter().find(|violation| violation.message.contains("region")).unwrap();
+        assert_eq!(region.schema_path, "/required");
+        assert!(check(&schema, &json!({ "name": "api", "region": "eu" })).is_empty());
+    }
+
+    #[test]
+    fn test_validate_refs_and_large_numbers() {
+        let schema = json!({
+            "$defs": { "port": { "type": "integer", "minimum": 1 } },
+            "type": "array",
+            "items": { "$ref": "#/$defs/port" }
+        });
+        let violations = check(&schema, &json!([443, 0, 1.5]));
+        assert_eq!(messages_at(&violations), vec!["/1", "/2"]);
+        let big: Value = serde_json::from_str("[12345678901234567890123]").unwrap();
+        assert!(check(&json!({ "items": { "type": "number" } }), &big).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_schema() {
+        let result = JsonSchemaLogic::compile(&json!({ "type": "strin" }));
+        assert!(matches!(result, Err(SchemaError::InvalidSchema(_))));
+    }
+}
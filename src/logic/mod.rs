@@ -1,3 +1,18 @@
 // Business logic modules separated from UI components
 pub mod base64_logic;
-pub mod distance_logic;
\ No newline at end of file
+pub mod checksum_logic;
+pub mod codegen_logic;
+pub mod converter_logic;
+pub mod distance_logic;
+pub mod hash_logic;
+pub mod hmac_logic;
+pub mod icon_logic;
+pub mod json_diff_logic;
+pub mod json_logic;
+pub mod json_path_logic;
+pub mod json_schema_logic;
+pub mod qr_logic;
+pub mod qr_payload_logic;
+pub mod query_logic;
+pub mod shape_logic;
+pub mod url_logic;
@@ -0,0 +1,394 @@
+use image::{DynamicImage, ImageFormat, Rgba, RgbaImage};
+use qrcode::{Color, EcLevel, QrCode, Version};
+use std::io::Cursor;
+
+#[derive(Debug, PartialEq)]
+pub enum QrError {
+    EmptyInput,
+    DataTooLong,
+    InvalidColor(String),
+    ImageEncoding,
+    InvalidImage,
+    NoCodeFound,
+    DecodeFailed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCorrection {
+    Low,
+    Medium,
+    Quartile,
+    High,
+}
+
+impl std::fmt::Display for ErrorCorrection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorCorrection::Low => write!(f, "L"),
+            ErrorCorrection::Medium => write!(f, "M"),
+            ErrorCorrection::Quartile => write!(f, "Q"),
+            ErrorCorrection::High => write!(f, "H"),
+        }
+    }
+}
+
+impl ErrorCorrection {
+    pub const ALL: [ErrorCorrection; 4] = [
+        ErrorCorrection::Low,
+        ErrorCorrection::Medium,
+        ErrorCorrection::Quartile,
+        ErrorCorrection::High,
+    ];
+
+    /// Parse the single-letter level name used by the view
+    pub fn from_letter(letter: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|level| level.to_string() == letter)
+    }
+
+    /// Map the raw format-information bits reported by the decoder
+    fn from_format_bits(bits: u16) -> Self {
+        match bits & 0b11 {
+            0 => ErrorCorrection::Medium,
+            1 => ErrorCorrection::Low,
+            2 => ErrorCorrection::High,
+            _ => ErrorCorrection::Quartile,
+        }
+    }
+
+    fn to_ec_level(self) -> EcLevel {
+        match self {
+            ErrorCorrection::Low => EcLevel::L,
+            ErrorCorrection::Medium => EcLevel::M,
+            ErrorCorrection::Quartile => EcLevel::Q,
+            ErrorCorrection::High => EcLevel::H,
+        }
+    }
+}
+
+/// Rendering options for a generated QR code
+#[derive(Debug, Clone, PartialEq)]
+pub struct QrOptions {
+    pub error_correction: ErrorCorrection,
+    pub quiet_zone: u32,
+    pub module_size: u32,
+    pub foreground: String,
+    pub background: String,
+}
+
+impl Default for QrOptions {
+    fn default() -> Self {
+        Self {
+            error_correction: ErrorCorrection::Medium,
+            quiet_zone: 4,
+            module_size: 8,
+            foreground: "#000000".to_string(),
+            background: "#ffffff".to_string(),
+        }
+    }
+}
+
+/// Square grid of QR modules, `true` meaning dark
+#[derive(Debug, Clone, PartialEq)]
+pub struct QrMatrix {
+    pub width: usize,
+    pub version: i16,
+    pub error_correction: ErrorCorrection,
+    modules: Vec<bool>,
+}
+
+impl QrMatrix {
+    pub fn is_dark(&self, x: usize, y: usize) -> bool {
+        self.modules[y * self.width + x]
+    }
+}
+
+/// A QR symbol found in an image
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedQr {
+    pub content: String,
+    pub version: usize,
+    pub error_correction: ErrorCorrection,
+}
+
+pub struct QrLogic;
+
+impl QrLogic {
+    pub const MIN_MODULE_SIZE: u32 = 1;
+    pub const MAX_MODULE_SIZE: u32 = 32;
+    pub const MAX_QUIET_ZONE: u32 = 16;
+
+    /// Encode text into a QR module matrix
+    pub fn generate(input: &str, error_correction: ErrorCorrection) -> Result<QrMatrix, QrError> {
+        if input.is_empty() {
+            return Err(QrError::EmptyInput);
+        }
+
+        let code = QrCode::with_error_correction_level(input.as_bytes(), error_correction.to_ec_level())
+            .map_err(|_| QrError::DataTooLong)?;
+
+        let version = match code.version() {
+            Version::Normal(v) | Version::Micro(v) => v,
+        };
+
+        Ok(QrMatrix {
+            width: code.width(),
+            version,
+            error_correction,
+            modules: code.into_colors().into_iter().map(|c| c == Color::Dark).collect(),
+        })
+    }
+
+    /// Side length in pixels of the rendered image, quiet zone included
+    pub fn image_size(matrix: &QrMatrix, options: &QrOptions) -> u32 {
+        (matrix.width as u32 + options.quiet_zone * 2) * options.module_size
+    }
+
+    /// Render a matrix as an SVG document
+    pub fn render_svg(matrix: &QrMatrix, options: &QrOptions) -> Result<String, QrError> {
+        let foreground = Self::normalize_color(&options.foreground)?;
+        let background = Self::normalize_color(&options.background)?;
+
+        let modules = matrix.width as u32 + options.quiet_zone * 2;
+        let pixels = Self::image_size(matrix, options);
+
+        let mut path = String::new();
+        for y in 0..matrix.width {
+            for x in 0..matrix.width {
+                if matrix.is_dark(x, y) {
+                    let px = x as u32 + options.quiet_zone;
+                    let py = y as u32 + options.quiet_zone;
+                    path.push_str(&format!("M{} {}h1v1h-1z", px, py));
+                }
+            }
+        }
+
+        Ok(format!(
+            "<svg width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\" xmlns=\"http://www.w3.org/2000/svg\"><rect width=\"{}\" height=\"{}\" fill=\"{}\"/><path d=\"{}\" fill=\"{}\"/></svg>",
+            pixels, pixels, modules, modules, modules, modules, background, path, foreground
+        ))
+    }
+
+    /// Render a matrix as an RGBA bitmap
+    pub fn render_image(matrix: &QrMatrix, options: &QrOptions) -> Result<RgbaImage, QrError> {
+        let [fr, fg, fb] = Self::parse_hex_color(&options.foreground)?;
+        let [br, bg, bb] = Self::parse_hex_color(&options.background)?;
+        let dark = Rgba([fr, fg, fb, 255]);
+        let light = Rgba([br, bg, bb, 255]);
+
+        let pixels = Self::image_size(matrix, options);
+        let offset = options.quiet_zone * options.module_size;
+
+        Ok(RgbaImage::from_fn(pixels, pixels, |px, py| {
+            if px < offset || py < offset {
+                return light;
+            }
+            let x = ((px - offset) / options.module_size) as usize;
+            let y = ((py - offset) / options.module_size) as usize;
+            if x < matrix.width && y < matrix.width && matrix.is_dark(x, y) {
+                dark
+            } else {
+                light
+            }
+        }))
+    }
+
+    /// Encode a rendered bitmap as PNG bytes
+    pub fn encode_png(image: &RgbaImage) -> Result<Vec<u8>, QrError> {
+        let mut bytes = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+            .map_err(|_| QrError::ImageEncoding)?;
+        Ok(bytes)
+    }
+
+    /// Decode every QR symbol in an encoded image file (PNG, JPEG, ...)
+    pub fn decode_bytes(bytes: &[u8]) -> Result<Vec<DecodedQr>, QrError> {
+        let image = image::load_from_memory(bytes).map_err(|_| QrError::InvalidImage)?;
+        Self::decode_image(&image)
+    }
+
+    /// Decode every QR symbol in raw RGBA pixels, as handed out by the clipboard
+    pub fn decode_rgba(width: u32, height: u32, rgba: Vec<u8>) -> Result<Vec<DecodedQr>, QrError> {
+        let image = RgbaImage::from_raw(width, height, rgba).ok_or(QrError::InvalidImage)?;
+        Self::decode_image(&DynamicImage::ImageRgba8(image))
+    }
+
+    /// Locate and decode every QR symbol in an image
+    pub fn decode_image(image: &DynamicImage) -> Result<Vec<DecodedQr>, QrError> {
+        let mut prepared = rqrr::PreparedImage::prepare(image.to_luma8());
+        let grids = prepared.detect_grids();
+        if grids.is_empty() {
+            return Err(QrError::NoCodeFound);
+        }
+
+        let decoded: Vec<DecodedQr> = grids
+            .iter()
+            .filter_map(|grid| grid.decode().ok())
+            .map(|(meta, content)| DecodedQr {
+                content,
+                version: meta.version.0,
+                error_correction: ErrorCorrection::from_format_bits(meta.ecc_level),
+            })
+            .collect();
+
+        if decoded.is_empty() {
+            return Err(QrError::DecodeFailed);
+        }
+        Ok(decoded)
+    }
+
+    /// Parse `#rgb` or `#rrggbb` into RGB components
+    pub fn parse_hex_color(color: &str) -> Result<[u8; 3], QrError> {
+        let invalid = || QrError::InvalidColor(color.to_string());
+        let hex = color.trim().strip_prefix('#').ok_or_else(invalid)?;
+
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+
+        let expanded: String = match hex.len() {
+            3 => hex.chars().flat_map(|c| [c, c]).collect(),
+            6 => hex.to_string(),
+            _ => return Err(invalid()),
+        };
+
+        let channel = |i: usize| u8::from_str_radix(&expanded[i..i + 2], 16).map_err(|_| invalid());
+        Ok([channel(0)?, channel(2)?, channel(4)?])
+    }
+
+    fn normalize_color(color: &str) -> Result<String, QrError> {
+        let [r, g, b] = Self::parse_hex_color(color)?;
+        Ok(format!("#{:02x}{:02x}{:02x}", r, g, b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_basic() {
+        let matrix = QrLogic::generate("Hello World", ErrorCorrection::Medium).unwrap();
+        assert_eq!(matrix.version, 1);
+        assert_eq!(matrix.width, 21);
+        assert_eq!(matrix.error_correction, ErrorCorrection::Medium);
+        // Top-left finder pattern corner is always dark
+        assert!(matrix.is_dark(0, 0));
+    }
+
+    #[test]
+    fn test_generate_empty() {
+        let result = QrLogic::generate("", ErrorCorrection::Low);
+        assert_eq!(result, Err(QrError::EmptyInput));
+    }
+
+    #[test]
+    fn test_generate_too_long() {
+        let input = "a".repeat(5000);
+        let result = QrLogic::generate(&input, ErrorCorrection::High);
+        assert_eq!(result, Err(QrError::DataTooLong));
+    }
+
+    #[test]
+    fn test_higher_ecc_needs_larger_version() {
+        let input = "https://example.com/some/longer/path?with=query";
+        let low = QrLogic::generate(input, ErrorCorrection::Low).unwrap();
+        let high = QrLogic::generate(input, ErrorCorrection::High).unwrap();
+        assert!(high.version > low.version);
+    }
+
+    #[test]
+    fn test_render_svg_dimensions() {
+        let matrix = QrLogic::generate("Hello World", ErrorCorrection::Medium).unwrap();
+        let options = QrOptions { quiet_zone: 2, module_size: 10, ..QrOptions::default() };
+        let svg = QrLogic::render_svg(&matrix, &options).unwrap();
+        assert!(svg.contains("width=\"250\""));
+        assert!(svg.contains("viewBox=\"0 0 25 25\""));
+        assert!(svg.contains("M2 2h1v1h-1z"));
+    }
+
+    #[test]
+    fn test_render_svg_invalid_color() {
+        let matrix = QrLogic::generate("Hello World", ErrorCorrection::Medium).unwrap();
+        let options = QrOptions { foreground: "black".to_string(), ..QrOptions::default() };
+        let result = QrLogic::render_svg(&matrix, &options);
+        assert_eq!(result, Err(QrError::InvalidColor("black".to_string())));
+    }
+
+    #[test]
+    fn test_render_image_pixels() {
+        let matrix = QrLogic::generate("Hello World", ErrorCorrection::Medium).unwrap();
+        let options = QrOptions {
+            quiet_zone: 1,
+            module_size: 4,
+            foreground: "#ff0000".to_string(),
+            background: "#00ff00".to_string(),
+            ..QrOptions::default()
+        };
+        let image = QrLogic::render_image(&matrix, &options).unwrap();
+        assert_eq!(image.dimensions(), (92, 92));
+        // Quiet zone uses the background, first module is dark
+        assert_eq!(image.get_pixel(0, 0), &Rgba([0, 255, 0, 255]));
+        assert_eq!(image.get_pixel(4, 4), &Rgba([255, 0, 0, 255]));
+        assert_eq!(image.get_pixel(7, 7), &Rgba([255, 0, 0, 255]));
+    }
+
+    #[test]
+    fn test_encode_png() {
+        let matrix = QrLogic::generate("Hello World", ErrorCorrection::Medium).unwrap();
+        let image = QrLogic::render_image(&matrix, &QrOptions::default()).unwrap();
+        let png = QrLogic::encode_png(&image).unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    }
+
+    #[test]
+    fn test_decode_round_trip() {
+        for level in ErrorCorrection::ALL {
+            let matrix = QrLogic::generate("https://example.com/?q=effless", level).unwrap();
+            let image = QrLogic::render_image(&matrix, &QrOptions::default()).unwrap();
+            let png = QrLogic::encode_png(&image).unwrap();
+
+            let decoded = QrLogic::decode_bytes(&png).unwrap();
+            assert_eq!(decoded.len(), 1);
+            assert_eq!(decoded[0].content, "https://example.com/?q=effless");
+            assert_eq!(decoded[0].version, matrix.version as usize);
+            assert_eq!(decoded[0].error_correction, level);
+        }
+    }
+
+    #[test]
+    fn test_decode_rgba() {
+        let matrix = QrLogic::generate("WIFI:T:nopass;S:Guest;;", ErrorCorrection::Quartile).unwrap();
+        let image = QrLogic::render_image(&matrix, &QrOptions::default()).unwrap();
+        let (width, height) = image.dimensions();
+
+        let decoded = QrLogic::decode_rgba(width, height, image.into_raw()).unwrap();
+        assert_eq!(decoded[0].content, "WIFI:T:nopass;S:Guest;;");
+        assert_eq!(QrLogic::decode_rgba(10, 10, vec![0; 3]), Err(QrError::InvalidImage));
+    }
+
+    #[test]
+    fn test_decode_no_code() {
+        let blank = DynamicImage::ImageRgba8(RgbaImage::from_pixel(64, 64, Rgba([255, 255, 255, 255])));
+        assert_eq!(QrLogic::decode_image(&blank), Err(QrError::NoCodeFound));
+    }
+
+    #[test]
+    fn test_decode_invalid_image() {
+        assert_eq!(QrLogic::decode_bytes(b"not an image"), Err(QrError::InvalidImage));
+    }
+
+    #[test]
+    fn test_parse_hex_color() {
+        assert_eq!(QrLogic::parse_hex_color("#ff8000"), Ok([255, 128, 0]));
+        assert_eq!(QrLogic::parse_hex_color("#fff"), Ok([255, 255, 255]));
+        assert!(QrLogic::parse_hex_color("#ggg").is_err());
+        assert!(QrLogic::parse_hex_color("123456").is_err());
+    }
+
+    #[test]
+    fn test_error_correction_from_letter() {
+        assert_eq!(ErrorCorrection::from_letter("Q"), Some(ErrorCorrection::Quartile));
+        assert_eq!(ErrorCorrection::from_letter("X"), None);
+    }
+}
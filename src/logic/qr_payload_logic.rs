@@ -0,0 +1,370 @@
+use chrono::NaiveDateTime;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use crate::logic::distance_logic::{DistanceError, DistanceLogic};
+
+/// Characters left as-is in mailto query values (RFC 3986 unreserved)
+const MAILTO_VALUE: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~');
+
+#[derive(Debug, PartialEq)]
+pub enum PayloadError {
+    MissingField(&'static str),
+    InvalidCoordinates(DistanceError),
+    InvalidDateTime(String),
+    EndBeforeStart,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WifiSecurity {
+    Wpa,
+    Wep,
+    Open,
+}
+
+impl std::fmt::Display for WifiSecurity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WifiSecurity::Wpa => write!(f, "WPA/WPA2"),
+            WifiSecurity::Wep => write!(f, "WEP"),
+            WifiSecurity::Open => write!(f, "None"),
+        }
+    }
+}
+
+impl WifiSecurity {
+    pub const ALL: [WifiSecurity; 3] = [WifiSecurity::Wpa, WifiSecurity::Wep, WifiSecurity::Open];
+
+    fn code(self) -> &'static str {
+        match self {
+            WifiSecurity::Wpa => "WPA",
+            WifiSecurity::Wep => "WEP",
+            WifiSecurity::Open => "nopass",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WifiPayload {
+    pub ssid: String,
+    pub password: String,
+    pub security: WifiSecurity,
+    pub hidden: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContactPayload {
+    pub first_name: String,
+    pub last_name: String,
+    pub organization: String,
+    pub title: String,
+    pub phone: String,
+    pub email: String,
+    pub url: String,
+    pub address: String,
+    pub note: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EventPayload {
+    pub summary: String,
+    pub location: String,
+    pub description: String,
+    pub start: String,
+    pub end: String,
+}
+
+pub struct QrPayloadLogic;
+
+impl QrPayloadLogic {
+    /// Build a `WIFI:` network payload as understood by Android and iOS cameras
+    pub fn wifi(payload: &WifiPayload) -> Result<String, PayloadError> {
+        if payload.ssid.is_empty() {
+            return Err(PayloadError::MissingField("SSID"));
+        }
+
+        let mut result = format!("WIFI:T:{};S:{};", payload.security.code(), Self::escape_wifi(&payload.ssid));
+        if payload.security != WifiSecurity::Open {
+            if payload.password.is_empty() {
+                return Err(PayloadError::MissingField("Password"));
+            }
+            result.push_str(&format!("P:{};", Self::escape_wifi(&payload.password)));
+        }
+        if payload.hidden {
+            result.push_str("H:true;");
+        }
+        result.push(';');
+        Ok(result)
+    }
+
+    /// Build a vCard 3.0 contact
+    pub fn vcard(payload: &ContactPayload) -> Result<String, PayloadError> {
+        let full_name = format!("{} {}", payload.first_name.trim(), payload.last_name.trim())
+            .trim()
+            .to_string();
+        if full_name.is_empty() {
+            return Err(PayloadError::MissingField("Name"));
+        }
+
+        let mut lines = vec![
+            "BEGIN:VCARD".to_string(),
+            "VERSION:3.0".to_string(),
+            format!(
+                "N:{};{};;;",
+                Self::escape_text(payload.last_name.trim()),
+                Self::escape_text(payload.first_name.trim())
+            ),
+            format!("FN:{}", Self::escape_text(&full_name)),
+        ];
+
+        let optional = [
+            ("ORG", &payload.organization),
+            ("TITLE", &payload.title),
+            ("TEL", &payload.phone),
+            ("EMAIL", &payload.email),
+            ("URL", &payload.url),
+        ];
+        for (property, value) in optional {
+            if !value.trim().is_empty() {
+                lines.push(format!("{}:{}", property, Self::escape_text(value.trim())));
+            }
+        }
+        if !payload.address.trim().is_empty() {
+            lines.push(format!("ADR:;;{};;;;", Self::escape_text(payload.address.trim())));
+        }
+        if !payload.note.trim().is_empty() {
+            lines.push(format!("NOTE:{}", Self::escape_text(payload.note.trim())));
+        }
+        lines.push("END:VCARD".to_string());
+
+        Ok(lines.join("\r\n"))
+    }
+
+    /// Build a `mailto:` link with percent-encoded subject and body
+    pub fn mailto(to: &str, subject: &str, body: &str) -> Result<String, PayloadError> {
+        let to = to.trim();
+        if to.is_empty() {
+            return Err(PayloadError::MissingField("Recipient"));
+        }
+
+        let mut query = Vec::new();
+        if !subject.is_empty() {
+            query.push(format!("subject={}", utf8_percent_encode(subject, MAILTO_VALUE)));
+        }
+        if !body.is_empty() {
+            query.push(format!("body={}", utf8_percent_encode(body, MAILTO_VALUE)));
+        }
+
+        if query.is_empty() {
+            Ok(format!("mailto:{}", to))
+        } else {
+            Ok(format!("mailto:{}?{}", to, query.join("&")))
+        }
+    }
+
+    /// Build an `SMSTO:` payload, the form most QR scanners turn into a prefilled message
+    pub fn sms(number: &str, message: &str) -> Result<String, PayloadError> {
+        let number = Self::normalize_phone(number)?;
+        if message.is_empty() {
+            Ok(format!("SMSTO:{}", number))
+        } else {
+            Ok(format!("SMSTO:{}:{}", number, message))
+        }
+    }
+
+    /// Build a `tel:` link
+    pub fn tel(number: &str) -> Result<String, PayloadError> {
+        Ok(format!("tel:{}", Self::normalize_phone(number)?))
+    }
+
+    /// Build a `geo:` URI after validating the coordinates
+    pub fn geo(lat: f64, lon: f64) -> Result<String, PayloadError> {
+        let coords = DistanceLogic::validate_coordinates(lat, lon)
+            .map_err(PayloadError::InvalidCoordinates)?;
+        Ok(format!("geo:{},{}", coords.lat, coords.lon))
+    }
+
+    /// Build an iCalendar VEVENT; times are `YYYY-MM-DDTHH:MM[:SS]` in floating local time
+    pub fn event(payload: &EventPayload) -> Result<String, PayloadError> {
+        if payload.summary.trim().is_empty() {
+            return Err(PayloadError::MissingField("Title"));
+        }
+        let start = Self::parse_datetime(&payload.start)?;
+        let end = Self::parse_datetime(&payload.end)?;
+        if end < start {
+            return Err(PayloadError::EndBeforeStart);
+        }
+
+        let mut lines = vec![
+            "BEGIN:VEVENT".to_string(),
+            format!("SUMMARY:{}", Self::escape_text(payload.summary.trim())),
+            format!("DTSTART:{}", start.format("%Y%m%dT%H%M%S")),
+            format!("DTEND:{}", end.format("%Y%m%dT%H%M%S")),
+        ];
+        if !payload.location.trim().is_empty() {
+            lines.push(format!("LOCATION:{}", Self::escape_text(payload.location.trim())));
+        }
+        if !payload.description.trim().is_empty() {
+            lines.push(format!("DESCRIPTION:{}", Self::escape_text(payload.description.trim())));
+        }
+        lines.push("END:VEVENT".to_string());
+
+        Ok(lines.join("\r\n"))
+    }
+
+    /// Backslash-escape the characters that delimit fields in a `WIFI:` payload
+    fn escape_wifi(value: &str) -> String {
+        let mut escaped = String::with_capacity(value.len());
+        for c in value.chars() {
+            if matches!(c, '\\' | ';' | ',' | ':' | '"') {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        escaped
+    }
+
+    /// Escape TEXT values shared by vCard 3.0 and iCalendar
+    fn escape_text(value: &str) -> String {
+        value
+            .replace('\\', "\\\\")
+            .replace(';', "\\;")
+            .replace(',', "\\,")
+            .replace("\r\n", "\\n")
+            .replace('\n', "\\n")
+    }
+
+    fn normalize_phone(number: &str) -> Result<String, PayloadError> {
+        let normalized: String = number
+            .chars()
+            .filter(|c| c.is_ascii_digit() || matches!(c, '+' | '*' | '#'))
+            .collect();
+        if normalized.is_empty() {
+            return Err(PayloadError::MissingField("Phone number"));
+        }
+        Ok(normalized)
+    }
+
+    fn parse_datetime(value: &str) -> Result<NaiveDateTime, PayloadError> {
+        NaiveDateTime::parse_from_str(value.trim(), "%Y-%m-%dT%H:%M")
+            .or_else(|_| NaiveDateTime::parse_from_str(value.trim(), "%Y-%m-%dT%H:%M:%S"))
+            .map_err(|_| PayloadError::InvalidDateTime(value.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wifi(ssid: &str, password: &str, security: WifiSecurity, hidden: bool) -> WifiPayload {
+        WifiPayload {
+            ssid: ssid.to_string(),
+            password: password.to_string(),
+            security,
+            hidden,
+        }
+    }
+
+    #[test]
+    fn test_wifi_basic() {
+        let result = QrPayloadLogic::wifi(&wifi("Office", "secret", WifiSecurity::Wpa, false)).unwrap();
+        assert_eq!(result, "WIFI:T:WPA;S:Office;P:secret;;");
+    }
+
+    #[test]
+    fn test_wifi_escaping_and_hidden() {
+        let result = QrPayloadLogic::wifi(&wifi("My;Net", "p:a\\s,s\"", WifiSecurity::Wep, true)).unwrap();
+        assert_eq!(result, "WIFI:T:WEP;S:My\\;Net;P:p\\:a\\\\s\\,s\\\";H:true;;");
+    }
+
+    #[test]
+    fn test_wifi_open_ignores_password() {
+        let result = QrPayloadLogic::wifi(&wifi("Guest", "unused", WifiSecurity::Open, false)).unwrap();
+        assert_eq!(result, "WIFI:T:nopass;S:Guest;;");
+    }
+
+    #[test]
+    fn test_wifi_missing_fields() {
+        let missing_ssid = QrPayloadLogic::wifi(&wifi("", "secret", WifiSecurity::Wpa, false));
+        assert_eq!(missing_ssid, Err(PayloadError::MissingField("SSID")));
+        let missing_password = QrPayloadLogic::wifi(&wifi("Office", "", WifiSecurity::Wpa, false));
+        assert_eq!(missing_password, Err(PayloadError::MissingField("Password")));
+    }
+
+    #[test]
+    fn test_vcard() {
+        let contact = ContactPayload {
+            first_name: "Somchai".to_string(),
+            last_name: "Jaidee".to_string(),
+            organization: "TP Coder, Inc.".to_string(),
+            phone: "+66 81 234 5678".to_string(),
+            note: "line one\nline; two".to_string(),
+            ..ContactPayload::default()
+        };
+        let result = QrPayloadLogic::vcard(&contact).unwrap();
+        assert_eq!(
+            result,
+            "BEGIN:VCARD\r\nVERSION:3.0\r\nN:Jaidee;Somchai;;;\r\nFN:Somchai Jaidee\r\nORG:TP Coder\\, Inc.\r\nTEL:+66 81 234 5678\r\nNOTE:line one\\nline\\; two\r\nEND:VCARD"
+        );
+    }
+
+    #[test]
+    fn test_vcard_requires_name() {
+        let result = QrPayloadLogic::vcard(&ContactPayload::default());
+        assert_eq!(result, Err(PayloadError::MissingField("Name")));
+    }
+
+    #[test]
+    fn test_mailto() {
+        let result = QrPayloadLogic::mailto("dev@example.com", "Hello & welcome", "Line 1\nLine 2").unwrap();
+        assert_eq!(result, "mailto:dev@example.com?subject=Hello%20%26%20welcome&body=Line%201%0ALine%202");
+        assert_eq!(QrPayloadLogic::mailto("dev@example.com", "", "").unwrap(), "mailto:dev@example.com");
+    }
+
+    #[test]
+    fn test_sms_and_tel() {
+        assert_eq!(QrPayloadLogic::sms("+66 (81) 234-5678", "On my way").unwrap(), "SMSTO:+66812345678:On my way");
+        assert_eq!(QrPayloadLogic::tel("081-234-5678").unwrap(), "tel:0812345678");
+        assert_eq!(QrPayloadLogic::tel("abc"), Err(PayloadError::MissingField("Phone number")));
+    }
+
+    #[test]
+    fn test_geo() {
+        assert_eq!(QrPayloadLogic::geo(13.7563, 100.5018).unwrap(), "geo:13.7563,100.5018");
+        assert_eq!(
+            QrPayloadLogic::geo(91.0, 0.0),
+            Err(PayloadError::InvalidCoordinates(DistanceError::InvalidLatitude(91.0)))
+        );
+    }
+
+    #[test]
+    fn test_event() {
+        let event = EventPayload {
+            summary: "Sprint review, Q3".to_string(),
+            location: "Room 4".to_string(),
+            start: "2025-09-01T14:00".to_string(),
+            end: "2025-09-01T15:30".to_string(),
+            ..EventPayload::default()
+        };
+        let result = QrPayloadLogic::event(&event).unwrap();
+        assert_eq!(
+            result,
+            "BEGIN:VEVENT\r\nSUMMARY:Sprint review\\, Q3\r\nDTSTART:20250901T140000\r\nDTEND:20250901T153000\r\nLOCATION:Room 4\r\nEND:VEVENT"
+        );
+    }
+
+    #[test]
+    fn test_event_invalid_times() {
+        let mut event = EventPayload {
+            summary: "Standup".to_string(),
+            start: "2025-09-01T10:00".to_string(),
+            end: "2025-09-01T09:00".to_string(),
+            ..EventPayload::default()
+        };
+        assert_eq!(QrPayloadLogic::event(&event), Err(PayloadError::EndBeforeStart));
+
+        event.end = "tomorrow".to_string();
+        assert_eq!(
+            QrPayloadLogic::event(&event),
+            Err(PayloadError::InvalidDateTime("tomorrow".to_string()))
+        );
+    }
+}
@@ -0,0 +1,336 @@
+use crate::logic::url_logic::{UrlComponent, UrlLogic};
+use serde_json::{Map, Value};
+
+#[derive(Debug, PartialEq)]
+pub enum QueryError {
+    /// A key is used both as a plain value and as a nested object or array
+    Conflict(String),
+    NotAnObject,
+    InvalidJson(String),
+}
+
+/// How arrays are written when converting JSON back into a query string
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ArrayFormat {
+    /// `a[]=1&a[]=2`
+    #[default]
+    Brackets,
+    /// `a[0]=1&a[1]=2`
+    Indices,
+    /// `a=1&a=2`
+    Repeat,
+}
+
+impl std::fmt::Display for ArrayFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArrayFormat::Brackets => write!(f, "a[]=1&a[]=2"),
+            ArrayFormat::Indices => write!(f, "a[0]=1&a[1]=2"),
+            ArrayFormat::Repeat => write!(f, "a=1&a=2"),
+        }
+    }
+}
+
+impl ArrayFormat {
+    pub const ALL: [ArrayFormat; 3] = [ArrayFormat::Brackets, ArrayFormat::Indices, ArrayFormat::Repeat];
+}
+
+pub struct QueryLogic;
+
+impl QueryLogic {
+    /// Decoded key/value pairs in order, keeping repeated keys
+    ///
+    /// Accepts a bare query string, one starting with `?`, or a whole URL.
+    pub fn parse(input: &str) -> Vec<(String, String)> {
+        let input = input.trim();
+        let input = input.split_once('#').map_or(input, |(query, _)| query);
+        let query = input.split_once('?').map_or(input, |(_, query)| query);
+        UrlLogic::parse_query(query)
+    }
+
+    /// Swap in a new query string, keeping any URL before `?` and fragment after `#`
+    pub fn replace_query(input: &str, query: &str) -> String {
+        let input = input.trim();
+        let (rest, fragment) = match input.split_once('#') {
+            Some((rest, fragment)) => (rest, Some(fragment)),
+            None => (input, None),
+        };
+        let base = match rest.split_once('?') {
+            Some((base, _)) => Some(base),
+            None if rest.contains("://") => Some(rest),
+            None => None,
+        };
+
+        let mut output = match base {
+            Some(base) if query.is_empty() => base.to_string(),
+            Some(base) => format!("{}?{}", base, query),
+            None => query.to_string(),
+        };
+        if let Some(fragment) = fragment {
+            output.push('#');
+            output.push_str(fragment);
+        }
+        output
+    }
+
+    /// Serialize decoded pairs, leaving the brackets in keys literal as `from_json` writes them
+    pub fn build(pairs: &[(String, String)]) -> String {
+        pairs
+            .iter()
+            .map(|(key, value)| format!("{}={}", Self::encode_key(key), UrlLogic::encode(value, UrlComponent::Form)))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
+    fn encode_key(key: &str) -> String {
+        UrlLogic::encode(key, UrlComponent::Form).replace("%5B", "[").replace("%5D", "]")
+    }
+
+    /// Split `a[b][]` into `a`, `b` and an empty segment; malformed keys stay whole
+    pub fn key_path(key: &str) -> Vec<String> {
+        let Some(open) = key.find('[').filter(|open| *open > 0) else {
+            return vec![key.to_string()];
+        };
+
+        let mut segments = vec![key[..open].to_string()];
+        let mut rest = &key[open..];
+        while let Some(inner) = rest.strip_prefix('[') {
+            let Some(close) = inner.find(']') else {
+                return vec![key.to_string()];
+            };
+            segments.push(inner[..close].to_string());
+            rest = &inner[close + 1..];
+        }
+        if !rest.is_empty() {
+            return vec![key.to_string()];
+        }
+        segments
+    }
+
+    /// Build a JSON object from pairs, expanding bracket notation and collecting repeated keys into arrays
+    pub fn to_json(pairs: &[(String, String)]) -> Result<Value, QueryError> {
+        let mut root = Value::Object(Map::new());
+        for (key, value) in pairs {
+            let path = Self::key_path(key);
+            Self::insert(&mut root, &path, value.clone(), key)?;
+        }
+        Ok(root)
+    }
+
+    fn insert(slot: &mut Value, path: &[String], value: String, key: &str) -> Result<(), QueryError> {
+        let Some((segment, rest)) = path.split_first() else {
+            // Leaf: a repeated key turns a single value into an array
+            match slot {
+                Value::Null => *slot = Value::String(value),
+                Value::String(existing) => *slot = Value::Array(vec![Value::String(std::mem::take(existing)), Value::String(value)]),
+                Value::Array(items) if items.iter().all(|item| item.is_string()) => items.push(Value::String(value)),
+                _ => return Err(QueryError::Conflict(key.to_string())),
+            }
+            return Ok(());
+        };
+
+        let index = segment.parse::<usize>().ok();
+        if slot.is_null() {
+            *slot = if segment.is_empty() || index.is_some() { Value::Array(Vec::new()) } else { Value::Object(Map::new()) };
+        }
+
+        match slot {
+            Value::Array(items) if segment.is_empty() => {
+                let mut child = Value::Null;
+                Self::insert(&mut child, rest, value, key)?;
+                items.push(child);
+            }
+            Value::Array(items) => {
+                let Some(index) = index else {
+                    return Err(QueryError::Conflict(key.to_string()));
+                };
+                // Like PHP and `qs`, sparse indices are compacted onto the end
+                let index = index.min(items.len());
+                if index == items.len() {
+                    items.push(Value::Null);
+                }
+                Self::insert(&mut items[index], rest, value, key)?;
+            }
+            Value::Object(map) => {
+                let child = map.entry(segment.clone()).or_insert(Value::Null);
+                Self::insert(child, rest, value, key)?;
+            }
+            _ => return Err(QueryError::Conflict(key.to_string())),
+        }
+        Ok(())
+    }
+
+    /// Parse JSON text and convert it into a query string
+    pub fn from_json_text(text: &str, format: ArrayFormat) -> Result<String, QueryError> {
+        let value: Value = serde_json::from_str(text).map_err(|err| QueryError::InvalidJson(err.to_string()))?;
+        Self::from_json(&value, format)
+    }
+
+    /// Flatten a JSON object into a query string using bracket notation
+    ///
+    /// Numbers and booleans are written as text and `null` as an empty value;
+    /// empty arrays and objects have no query representation and are dropped.
+    pub fn from_json(value: &Value, format: ArrayFormat) -> Result<String, QueryError> {
+        let Value::Object(map) = value else {
+            return Err(QueryError::NotAnObject);
+        };
+
+        let mut pairs = Vec::new();
+        for (key, child) in map {
+            Self::flatten(&UrlLogic::encode(key, UrlComponent::Form), child, format, &mut pairs);
+        }
+        Ok(pairs
+            .into_iter()
+            .map(|(key, value)| format!("{}={}", key, UrlLogic::encode(&value, UrlComponent::Form)))
+            .collect::<Vec<_>>()
+            .join("&"))
+    }
+
+    fn flatten(prefix: &str, value: &Value, format: ArrayFormat, pairs: &mut Vec<(String, String)>) {
+        match value {
+            Value::Object(map) => {
+                for (key, child) in map {
+                    let key = format!("{}[{}]", prefix, UrlLogic::encode(key, UrlComponent::Form));
+                    Self::flatten(&key, child, format, pairs);
+                }
+            }
+            Value::Array(items) => {
+                let nested = items.iter().any(|item| item.is_object() || item.is_array());
+                for (index, item) in items.iter().enumerate() {
+                    // Objects inside arrays need an index to keep their fields together
+                    let key = match format {
+                        _ if nested => format!("{}[{}]", prefix, index),
+                        ArrayFormat::Indices => format!("{}[{}]", prefix, index),
+                        ArrayFormat::Brackets => format!("{}[]", prefix),
+                        ArrayFormat::Repeat => prefix.to_string(),
+                    };
+                    Self::flatten(&key, item, format, pairs);
+                }
+            }
+            Value::String(text) => pairs.push((prefix.to_string(), text.clone())),
+            Value::Null => pairs.push((prefix.to_string(), String::new())),
+            other => pairs.push((prefix.to_string(), other.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn pairs(input: &str) -> Vec<(String, String)> {
+        QueryLogic::parse(input)
+    }
+
+    #[test]
+    fn test_parse_keeps_order_and_repeats() {
+        let parsed = pairs("https://app.example.com/callback?code=abc%2F123&scope=openid+email&scope=profile&state=xyz#frag");
+        assert_eq!(
+            parsed,
+            vec![
+                ("code".to_string(), "abc/123".to_string()),
+                ("scope".to_string(), "openid email".to_string()),
+                ("scope".to_string(), "profile".to_string()),
+                ("state".to_string(), "xyz".to_string()),
+            ]
+        );
+        assert_eq!(pairs("?a=1"), pairs("a=1"));
+        assert!(pairs("").is_empty());
+    }
+
+    #[test]
+    fn test_replace_query() {
+        assert_eq!(QueryLogic::replace_query("https://a.com/cb?x=1#top", "y=2"), "https://a.com/cb?y=2#top");
+        assert_eq!(QueryLogic::replace_query("https://a.com/cb", "y=2"), "https://a.com/cb?y=2");
+        assert_eq!(QueryLogic::replace_query("https://a.com/cb?x=1", ""), "https://a.com/cb");
+        assert_eq!(QueryLogic::replace_query("?x=1", "y=2"), "?y=2");
+        assert_eq!(QueryLogic::replace_query("x=1", "y=2"), "y=2");
+    }
+
+    #[test]
+    fn test_build_keeps_brackets() {
+        let query = "user[name]=Ann+Lee&user[roles][]=admin&a%26b=c%3Dd";
+        assert_eq!(QueryLogic::build(&pairs(query)), query);
+        assert_eq!(QueryLogic::build(&pairs("user%5Bname%5D=x")), "user[name]=x");
+        assert_eq!(QueryLogic::build(&[("a b".to_string(), "100%".to_string())]), "a+b=100%25");
+    }
+
+    #[test]
+    fn test_key_path() {
+        assert_eq!(QueryLogic::key_path("a"), vec!["a"]);
+        assert_eq!(QueryLogic::key_path("a[b][c]"), vec!["a", "b", "c"]);
+        assert_eq!(QueryLogic::key_path("a[]"), vec!["a", ""]);
+        assert_eq!(QueryLogic::key_path("a[b"), vec!["a[b"]);
+        assert_eq!(QueryLogic::key_path("a[b]c"), vec!["a[b]c"]);
+        assert_eq!(QueryLogic::key_path("[a]"), vec!["[a]"]);
+    }
+
+    #[test]
+    fn test_to_json_nested() {
+        let value = QueryLogic::to_json(&pairs("user[name]=Ann&user[roles][]=admin&user[roles][]=dev&user[address][city]=Oslo&page=2")).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "user": {
+                    "name": "Ann",
+                    "roles": ["admin", "dev"],
+                    "address": { "city": "Oslo" }
+                },
+                "page": "2"
+            })
+        );
+    }
+
+    #[test]
+    fn test_to_json_repeats_and_indices() {
+        assert_eq!(QueryLogic::to_json(&pairs("a=1&a=2&a=3")).unwrap(), json!({ "a": ["1", "2", "3"] }));
+        assert_eq!(
+            QueryLogic::to_json(&pairs("items[0][id]=1&items[0][qty]=2&items[1][id]=7")).unwrap(),
+            json!({ "items": [{ "id": "1", "qty": "2" }, { "id": "7" }] })
+        );
+        assert_eq!(QueryLogic::to_json(&pairs("a[3]=x&a[9]=y")).unwrap(), json!({ "a": ["x", "y"] }));
+    }
+
+    #[test]
+    fn test_to_json_conflict() {
+        assert_eq!(QueryLogic::to_json(&pairs("a=1&a[b]=2")), Err(QueryError::Conflict("a[b]".to_string())));
+        assert_eq!(QueryLogic::to_json(&pairs("a[b]=2&a=1")), Err(QueryError::Conflict("a".to_string())));
+    }
+
+    #[test]
+    fn test_from_json_formats() {
+        let value = json!({ "q": "rust lang", "tags": ["a", "b"], "filter": { "min": 1, "on": true, "none": null } });
+        assert_eq!(
+            QueryLogic::from_json(&value, ArrayFormat::Brackets).unwrap(),
+            "q=rust+lang&tags[]=a&tags[]=b&filter[min]=1&filter[on]=true&filter[none]="
+        );
+        assert_eq!(
+            QueryLogic::from_json(&value, ArrayFormat::Indices).unwrap(),
+            "q=rust+lang&tags[0]=a&tags[1]=b&filter[min]=1&filter[on]=true&filter[none]="
+        );
+        assert_eq!(
+            QueryLogic::from_json(&value, ArrayFormat::Repeat).unwrap(),
+            "q=rust+lang&tags=a&tags=b&filter[min]=1&filter[on]=true&filter[none]="
+        );
+        assert_eq!(
+            QueryLogic::from_json(&json!({ "items": [{ "id": 1 }] }), ArrayFormat::Repeat).unwrap(),
+            "items[0][id]=1"
+        );
+        assert_eq!(QueryLogic::from_json(&json!({ "a&b": "c=d" }), ArrayFormat::Brackets).unwrap(), "a%26b=c%3Dd");
+    }
+
+    #[test]
+    fn test_from_json_errors() {
+        assert_eq!(QueryLogic::from_json(&json!([1, 2]), ArrayFormat::Brackets), Err(QueryError::NotAnObject));
+        assert!(matches!(QueryLogic::from_json_text("{", ArrayFormat::Brackets), Err(QueryError::InvalidJson(_))));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let query = "user[name]=Ann+Lee&user[roles][]=admin&user[roles][]=dev&items[0][id]=1&items[1][id]=2&page=2";
+        let value = QueryLogic::to_json(&pairs(query)).unwrap();
+        assert_eq!(QueryLogic::from_json(&value, ArrayFormat::Brackets).unwrap(), query);
+        assert_eq!(QueryLogic::to_json(&pairs(&QueryLogic::from_json(&value, ArrayFormat::Repeat).unwrap())).unwrap(), value);
+    }
+}
@@ -0,0 +1,170 @@
+use chrono::{DateTime, NaiveDate};
+use serde_json::Value;
+use std::collections::HashMap;
+use url::Url;
+use uuid::Uuid;
+
+/// Everything observed at one position across all samples
+#[derive(Debug, Default)]
+pub struct Shape {
+    pub null: bool,
+    pub boolean: bool,
+    /// An integer that fits in `i64` was seen
+    pub integer: bool,
+    /// A negative integer was seen
+    pub negative: bool,
+    /// An integer above `i64::MAX` was seen
+    pub unsigned: bool,
+    pub float: bool,
+    /// Format shared by every string seen so far; `Some(None)` once they disagree
+    pub string: Option<Option<&'static str>>,
+    pub object: Option<ObjectShape>,
+    pub array: Option<Box<Shape>>,
+}
+
+#[derive(Debug, Default)]
+pub struct ObjectShape {
+    /// Properties in first-seen order, with how many objects contained each
+    pub properties: Vec<(String, Shape, usize)>,
+    index: HashMap<String, usize>,
+    /// Objects seen at this position
+    pub seen: usize,
+}
+
+impl Shape {
+    pub fn observe(&mut self, value: &Value) {
+        match value {
+            Value::Null => self.null = true,
+            Value::Bool(_) => self.boolean = true,
+            Value::Number(number) if number.is_i64() => {
+                self.integer = true;
+                self.negative |= number.as_i64().is_some_and(|integer| integer < 0);
+            }
+            Value::Number(number) if number.is_u64() => self.unsigned = true,
+            Value::Number(_) => self.float = true,
+            Value::String(text) => {
+                self.string = Some(match self.string {
+                    // Once formats disagree there is nothing left to detect
+                    Some(None) => None,
+                    Some(previous) => previous.filter(|format| ShapeLogic::detect_format(text) == Some(*format)),
+                    None => ShapeLogic::detect_format(text),
+                });
+            }
+            Value::Object(map) => {
+                let object = self.object.get_or_insert_with(ObjectShape::default);
+                object.seen += 1;
+                for (key, child) in map {
+                    let position = *object.index.entry(key.clone()).or_insert_with(|| {
+                        object.properties.push((key.clone(), Shape::default(), 0));
+                        object.properties.len() - 1
+                    });
+                    let (_, shape, count) = &mut object.properties[position];
+                    shape.observe(child);
+                    *count += 1;
+                }
+            }
+            Value::Array(items) => {
+                let element = self.array.get_or_insert_with(Box::default);
+                items.iter().for_each(|item| element.observe(item));
+            }
+        }
+    }
+
+    /// Nothing was ever observed here, e.g. the elements of arrays that were always empty
+    pub fn is_empty(&self) -> bool {
+        !self.null && !self.boolean && !self.is_numeric() && self.string.is_none() && self.object.is_none() && self.array.is_none()
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        self.integer || self.unsigned || self.float
+    }
+
+    /// Number of distinct JSON types seen besides null, counting integers and floats as one
+    pub fn kind_count(&self) -> usize {
+        [self.boolean, self.is_numeric(), self.string.is_some(), self.array.is_some(), self.object.is_some()]
+            .iter()
+            .filter(|seen| **seen)
+            .count()
+    }
+}
+
+pub struct ShapeLogic;
+
+impl ShapeLogic {
+    /// Merge every sample into one shape, so each position records all the types it held
+    pub fn observe(samples: &[Value]) -> Shape {
+        let mut shape = Shape::default();
+        samples.iter().for_each(|sample| shape.observe(sample));
+        shape
+    }
+
+    /// Well-known JSON Schema `format` a string value looks like, if any
+    pub fn detect_format(text: &str) -> Option<&'static str> {
+        if DateTime::parse_from_rfc3339(text).is_ok() {
+            Some("date-time")
+        } else if text.len() == 10 && NaiveDate::parse_from_str(text, "%Y-%m-%d").is_ok() {
+            Some("date")
+        } else if text.len() == 36 && Uuid::try_parse(text).is_ok() {
+            Some("uuid")
+        } else if Self::looks_like_email(text) {
+            Some("email")
+        } else if text.contains("://") && Url::parse(text).is_ok() {
+            Some("uri")
+        } else {
+            None
+        }
+    }
+
+    fn looks_like_email(text: &str) -> bool {
+        match text.split_once('@') {
+            Some((local, domain)) => {
+                !local.is_empty()
+                    && !text.contains(char::is_whitespace)
+                    && !domain.contains('@')
+                    && domain.split('.').count() >= 2
+                    && domain.split('.').all(|label| !label.is_empty())
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_observe_numbers_and_strings() {
+        let shape = ShapeLogic::observe(&[json!(1), json!(18446744073709551615u64), json!(2.5), json!(null)]);
+        assert!(shape.integer && shape.unsigned && shape.float && shape.null);
+        assert!(!shape.negative);
+        assert_eq!(shape.kind_count(), 1);
+        assert!(ShapeLogic::observe(&[json!(3), json!(-3)]).negative);
+
+        let shape = ShapeLogic::observe(&[json!("2024-01-02"), json!("2024-03-04")]);
+        assert_eq!(shape.string, Some(Some("date")));
+        let shape = ShapeLogic::observe(&[json!("2024-01-02"), json!("plain"), json!("2024-03-04")]);
+        assert_eq!(shape.string, Some(None));
+    }
+
+    #[test]
+    fn test_observe_objects_and_arrays() {
+        let shape = ShapeLogic::observe(&[json!({ "a": 1, "b": [] }), json!({ "a": "x" })]);
+        let object = shape.object.as_ref().unwrap();
+        assert_eq!(object.seen, 2);
+        let summary: Vec<(&str, usize)> = object.properties.iter().map(|(key, _, count)| (key.as_str(), *count)).collect();
+        assert_eq!(summary, vec![("a", 2), ("b", 1)]);
+        assert_eq!(object.properties[0].1.kind_count(), 2);
+        assert!(object.properties[1].1.array.as_ref().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_detect_format() {
+        assert_eq!(ShapeLogic::detect_format("2024-01-02T03:04:05Z"), Some("date-time"));
+        assert_eq!(ShapeLogic::detect_format("123e4567-e89b-12d3-a456-426614174000"), Some("uuid"));
+        assert_eq!(ShapeLogic::detect_format("ann@example.com"), Some("email"));
+        assert_eq!(ShapeLogic::detect_format("https://example.com/a"), Some("uri"));
+        assert_eq!(ShapeLogic::detect_format("not@domain"), None);
+    }
+}
@@ -62,6 +62,11 @@ fn render_sidebar(
 ) -> Element {
     let all_tools = vec![
         (ToolType::Base64, "Base64", "Encoders / Decoders"),
+        (ToolType::Url, "URL", "Encoders / Decoders"),
+        (ToolType::Json, "JSON Tools", "Formatters"),
+        (ToolType::Converter, "Data Converter", "Converters"),
+        (ToolType::Hash, "Hash Generator", "Hashing"),
+        (ToolType::Hmac, "HMAC", "Hashing"),
         (ToolType::Uuid, "UUID", "Generators"),
         (ToolType::Ulid, "ULID", "Generators"),
         (ToolType::QrCode, "QR Code", "Generators"),
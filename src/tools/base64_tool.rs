@@ -2,6 +2,10 @@
 
 use dioxus::prelude::*;
 use arboard::Clipboard;
+use rfd::AsyncFileDialog;
+use crate::logic::base64_logic::{Base64Error, Base64Logic, Base64Variant, DecodedContent, StreamControl};
+use std::sync::Arc;
+use std::time::Duration;
 
 pub struct Base64Tool;
 
@@ -18,32 +22,199 @@ impl Base64Tool {
 #[component]
 pub fn Base64ToolView() -> Element {
     let mut input = use_signal(String::new);
+    let mut input_file = use_signal(|| None::<(String, Vec<u8>)>);
     let mut output = use_signal(String::new);
+    let mut output_bytes = use_signal(Vec::<u8>::new);
+    let mut output_mime = use_signal(|| None::<String>);
+    let mut preview = use_signal(|| None::<String>);
+    let mut as_data_uri = use_signal(|| false);
     let mut error = use_signal(|| None::<String>);
+    let mut status = use_signal(|| None::<String>);
+    let mut variant = use_signal(Base64Variant::default);
+    let mut detected = use_signal(|| None::<Base64Variant>);
+    let mut stream_control = use_signal(|| None::<Arc<StreamControl>>);
+    let mut stream_progress = use_signal(|| (0u64, 0u64));
+
+    // Base64 text to decode, read from the loaded file when there is one
+    let source_text = move || match input_file.read().as_ref() {
+        Some((_, bytes)) => String::from_utf8_lossy(bytes).into_owned(),
+        None => input.read().clone(),
+    };
+
+    // `declared` is the media type named by a data URI, which wins over sniffing
+    let mut show_decoded = move |bytes: Vec<u8>, declared: Option<String>| {
+        let mime_type = declared.or_else(|| Base64Logic::sniff_mime(&bytes).map(str::to_string));
+        match Base64Logic::inspect(&bytes) {
+            DecodedContent::Text(text) => {
+                output.set(text);
+                output_mime.set(None);
+            }
+            DecodedContent::Binary { mime_type: sniffed, hex_dump } => {
+                output.set(hex_dump);
+                output_mime.set(Some(mime_type.clone().unwrap_or_else(|| sniffed.to_string())));
+            }
+        }
+        preview.set(
+            mime_type
+                .filter(|mime_type| mime_type.starts_with("image/"))
+                .map(|mime_type| Base64Logic::data_uri(&mime_type, &bytes)),
+        );
+        output_bytes.set(bytes);
+        error.set(None);
+    };
 
     let encode = move |_| {
-        use crate::logic::base64_logic::Base64Logic;
-        let encoded = Base64Logic::encode(&input.read());
+        let encoded = match (input_file.read().as_ref(), as_data_uri()) {
+            (Some((name, bytes)), true) => Base64Logic::data_uri(Base64Logic::mime_for_file(name, bytes), bytes),
+            (Some((_, bytes)), false) => Base64Logic::encode(bytes, variant()),
+            (None, true) => Base64Logic::data_uri("text/plain;charset=utf-8", input.read().as_bytes()),
+            (None, false) => Base64Logic::encode(input.read().as_bytes(), variant()),
+        };
+        output_bytes.set(encoded.clone().into_bytes());
         output.set(encoded);
+        output_mime.set(None);
+        preview.set(None);
+        detected.set(None);
+        status.set(None);
         error.set(None);
     };
 
     let decode = move |_| {
-        use crate::logic::base64_logic::Base64Logic;
-        match Base64Logic::decode(&input.read()) {
-            Ok(decoded) => {
-                output.set(decoded);
-                error.set(None);
+        detected.set(None);
+        status.set(None);
+        let source = source_text();
+        if Base64Logic::is_data_uri(&source) {
+            match Base64Logic::parse_data_uri(&source) {
+                Ok(data_uri) => show_decoded(data_uri.bytes, Some(data_uri.mime_type)),
+                Err(err) => error.set(Some(format!("{:?}", err))),
             }
+            return;
+        }
+        match Base64Logic::decode(&source, variant()) {
+            Ok(bytes) => show_decoded(bytes, None),
             Err(err) => {
                 error.set(Some(format!("{:?}", err)));
             }
         }
     };
 
+    let auto_decode = move |_| {
+        status.set(None);
+        let source = source_text();
+        if Base64Logic::is_data_uri(&source) {
+            detected.set(None);
+            match Base64Logic::parse_data_uri(&source) {
+                Ok(data_uri) => show_decoded(data_uri.bytes, Some(data_uri.mime_type)),
+                Err(err) => error.set(Some(format!("{:?}", err))),
+            }
+            return;
+        }
+        match Base64Logic::decode_auto(&source) {
+            Ok(result) => {
+                variant.set(result.variant);
+                detected.set(Some(result.variant));
+                show_decoded(result.bytes, None);
+            }
+            Err(err) => {
+                detected.set(None);
+                error.set(Some(format!("{:?}", err)));
+            }
+        }
+    };
+
+    let open_file = move |_| async move {
+        let Some(handle) = AsyncFileDialog::new().pick_file().await else {
+            return;
+        };
+
+        let bytes = handle.read().await;
+        status.set(Some(format!("Loaded {} ({} bytes)", handle.file_name(), bytes.len())));
+        input_file.set(Some((handle.file_name(), bytes)));
+        as_data_uri.set(true);
+        error.set(None);
+    };
+
+    let save_output = move |_| async move {
+        let extension = output_mime.read().as_deref().map(Base64Logic::extension_for).unwrap_or("txt");
+        let Some(handle) = AsyncFileDialog::new()
+            .set_file_name(format!("output.{}", extension))
+            .save_file()
+            .await
+        else {
+            return;
+        };
+
+        match std::fs::write(handle.path(), &*output_bytes.read()) {
+            Ok(()) => status.set(Some(format!("Saved to {}", handle.path().display()))),
+            Err(err) => error.set(Some(format!("Failed to save file: {}", err))),
+        }
+    };
+
+    // Large files go straight from disk to disk on a blocking task so the UI stays responsive
+    let stream_file = move |decode: bool| async move {
+        if stream_control.read().is_some() {
+            return;
+        }
+        let Some(source) = AsyncFileDialog::new().pick_file().await else {
+            return;
+        };
+        let suggested_name = if decode { "decoded.bin".to_string() } else { format!("{}.b64", source.file_name()) };
+        let Some(target) = AsyncFileDialog::new().set_file_name(suggested_name).save_file().await else {
+            return;
+        };
+
+        let input_path = source.path().to_path_buf();
+        let output_path = target.path().to_path_buf();
+        let total = std::fs::metadata(&input_path).map(|metadata| metadata.len()).unwrap_or(0);
+        let selected_variant = variant();
+        let control = Arc::new(StreamControl::new());
+        stream_control.set(Some(control.clone()));
+        stream_progress.set((0, total));
+        status.set(None);
+        error.set(None);
+
+        let worker = control.clone();
+        let job = tokio::task::spawn_blocking(move || {
+            if decode {
+                Base64Logic::decode_file(&input_path, &output_path, selected_variant, &worker)
+            } else {
+                Base64Logic::encode_file(&input_path, &output_path, selected_variant, &worker)
+            }
+        });
+        while !job.is_finished() {
+            stream_progress.set((control.processed(), total));
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        stream_control.set(None);
+
+        match job.await {
+            Ok(Ok(())) => status.set(Some(format!(
+                "{} {} to {}",
+                if decode { "Decoded" } else { "Encoded" },
+                Base64Logic::format_size(total),
+                target.path().display()
+            ))),
+            Ok(Err(Base64Error::Cancelled)) => status.set(Some("Cancelled".to_string())),
+            Ok(Err(err)) => error.set(Some(format!("{:?}", err))),
+            Err(err) => error.set(Some(format!("Streaming task failed: {}", err))),
+        }
+    };
+
+    let cancel_stream = move |_| {
+        if let Some(control) = stream_control.read().as_ref() {
+            control.cancel();
+        }
+    };
+
     let clear = move |_| {
         input.set(String::new());
+        input_file.set(None);
         output.set(String::new());
+        output_bytes.set(Vec::new());
+        output_mime.set(None);
+        preview.set(None);
+        detected.set(None);
+        status.set(None);
         error.set(None);
     };
 
@@ -73,13 +244,28 @@ pub fn Base64ToolView() -> Element {
                     "Input"
                 }
                 
-                textarea {
-                    style: "width: calc(100% - 20px); height: 60px; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; font-family: monospace; resize: none; box-sizing: border-box;",
-                    placeholder: "Enter text to encode/decode...",
-                    value: "{input.read()}",
-                    oninput: move |event| {
-                        input.set(event.value());
-                        error.set(None);
+                if let Some((name, bytes)) = input_file.read().as_ref() {
+                    div {
+                        style: "display: flex; align-items: center; gap: 10px; height: 60px; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; background-color: #f8f9fa; box-sizing: border-box;",
+                        span {
+                            style: "flex: 1; font-size: 14px; font-family: monospace; color: #2c3e50;",
+                            "📄 {name} ({bytes.len()} bytes)"
+                        }
+                        button {
+                            style: "padding: 4px 8px; background-color: #95a5a6; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                            onclick: move |_| input_file.set(None),
+                            "Use Text Input"
+                        }
+                    }
+                } else {
+                    textarea {
+                        style: "width: calc(100% - 20px); height: 60px; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; font-family: monospace; resize: none; box-sizing: border-box;",
+                        placeholder: "Enter text to encode/decode...",
+                        value: "{input.read()}",
+                        oninput: move |event| {
+                            input.set(event.value());
+                            error.set(None);
+                        }
                     }
                 }
             }
@@ -88,6 +274,39 @@ pub fn Base64ToolView() -> Element {
             div {
                 style: "margin-bottom: 15px; display: flex; gap: 10px; flex-shrink: 0;",
                 
+                select {
+                    style: "padding: 8px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; background-color: white;",
+                    disabled: as_data_uri(),
+                    onchange: move |event| {
+                        let selected = Base64Variant::ALL
+                            .into_iter()
+                            .find(|option| option.to_string() == event.value())
+                            .unwrap_or(Base64Variant::Standard);
+                        variant.set(selected);
+                        detected.set(None);
+                    },
+                    
+                    for option_variant in Base64Variant::ALL {
+                        option {
+                            value: "{option_variant}",
+                            selected: variant() == option_variant,
+                            "{option_variant}"
+                        }
+                    }
+                }
+                
+                label {
+                    style: "display: flex; gap: 6px; align-items: center; font-size: 14px; color: #2c3e50; cursor: pointer;",
+                    title: "Encode as a complete data: URI with the detected MIME type",
+                    
+                    input {
+                        r#type: "checkbox",
+                        checked: as_data_uri(),
+                        onchange: move |event| as_data_uri.set(event.checked())
+                    }
+                    "Data URI"
+                }
+                
                 button {
                     style: "padding: 8px 16px; background-color: #3498db; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
                     onclick: encode,
@@ -100,6 +319,18 @@ pub fn Base64ToolView() -> Element {
                     "Decode"
                 }
                 
+                button {
+                    style: "padding: 8px 16px; background-color: #16a085; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                    onclick: auto_decode,
+                    "Auto-detect Decode"
+                }
+                
+                button {
+                    style: "padding: 8px 16px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                    onclick: open_file,
+                    "Open File"
+                }
+                
                 button {
                     style: "padding: 8px 16px; background-color: #95a5a6; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
                     onclick: clear,
@@ -107,6 +338,48 @@ pub fn Base64ToolView() -> Element {
                 }
             }
             
+            // Large file streaming
+            div {
+                style: "margin-bottom: 15px; display: flex; gap: 10px; align-items: center; flex-shrink: 0;",
+                
+                span {
+                    style: "font-size: 14px; color: #2c3e50;",
+                    "Large files:"
+                }
+                
+                if stream_control.read().is_some() {
+                    div {
+                        style: "flex: 1; height: 10px; background-color: #ecf0f1; border-radius: 5px; overflow: hidden;",
+                        div {
+                            style: "height: 100%; background-color: #3498db; width: {stream_percent(stream_progress())}%;",
+                        }
+                    }
+                    
+                    span {
+                        style: "font-size: 12px; color: #7f8c8d; font-family: monospace;",
+                        "{Base64Logic::format_size(stream_progress().0)} / {Base64Logic::format_size(stream_progress().1)}"
+                    }
+                    
+                    button {
+                        style: "padding: 4px 8px; background-color: #e74c3c; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                        onclick: cancel_stream,
+                        "Cancel"
+                    }
+                } else {
+                    button {
+                        style: "padding: 4px 8px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                        onclick: move |_| stream_file(false),
+                        "Stream Encode File..."
+                    }
+                    
+                    button {
+                        style: "padding: 4px 8px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                        onclick: move |_| stream_file(true),
+                        "Stream Decode File..."
+                    }
+                }
+            }
+            
             // Output section
             div {
                 style: "flex: 1; display: flex; flex-direction: column; min-height: 0; overflow: hidden;",
@@ -119,12 +392,42 @@ pub fn Base64ToolView() -> Element {
                         "Output"
                     }
                     
+                    if let Some(matched) = detected() {
+                        span {
+                            style: "font-size: 12px; color: #16a085;",
+                            "Detected: {matched}"
+                        }
+                    }
+                    
+                    if let Some(mime_type) = output_mime.read().as_ref() {
+                        span {
+                            style: "font-size: 12px; color: #e67e22;",
+                            "Binary data · {mime_type} · {output_bytes.read().len()} bytes"
+                        }
+                    }
+                    
                     if !output.read().is_empty() {
                         button {
                             style: "padding: 4px 8px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
                             onclick: copy_to_clipboard,
                             "📋 Copy"
                         }
+                        
+                        button {
+                            style: "padding: 4px 8px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                            onclick: save_output,
+                            "💾 Save"
+                        }
+                    }
+                }
+                
+                if let Some(image) = preview.read().as_ref() {
+                    div {
+                        style: "flex-shrink: 0; margin-bottom: 10px; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; background-color: #f8f9fa; display: flex; justify-content: center;",
+                        img {
+                            style: "max-width: 100%; max-height: 200px; object-fit: contain;",
+                            src: "{image}"
+                        }
                     }
                 }
                 
@@ -145,6 +448,13 @@ pub fn Base64ToolView() -> Element {
                 }
             }
             
+            if let Some(message) = status.read().as_ref() {
+                div {
+                    style: "margin-top: 10px; padding: 10px; background-color: #e8f5e8; border: 1px solid #4caf50; border-radius: 4px; color: #2e7d32; font-size: 14px; flex-shrink: 0;",
+                    "{message}"
+                }
+            }
+            
             // Error message
             if let Some(err) = error.read().as_ref() {
                 div {
@@ -154,4 +464,13 @@ pub fn Base64ToolView() -> Element {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// Completed share of a streaming job as a CSS width percentage
+fn stream_percent((processed, total): (u64, u64)) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        (processed as f64 / total as f64 * 100.0).min(100.0)
+    }
+}
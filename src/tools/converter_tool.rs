@@ -0,0 +1,334 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use arboard::Clipboard;
+use rfd::AsyncFileDialog;
+use crate::logic::base64_logic::Base64Logic;
+use crate::logic::converter_logic::{ConvertOptions, ConverterLogic, CsvDelimiter, DataFormat};
+
+pub struct ConverterTool;
+
+impl ConverterTool {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn view(&self) -> Element {
+        rsx! { ConverterToolView {} }
+    }
+}
+
+#[component]
+pub fn ConverterToolView() -> Element {
+    let mut from = use_signal(|| DataFormat::Json);
+    let mut to = use_signal(|| DataFormat::Yaml);
+    let mut input = use_signal(String::new);
+    let mut output = use_signal(String::new);
+    // Format the current output was converted to, which the target picker may no longer show
+    let mut output_format = use_signal(|| DataFormat::Yaml);
+    let mut csv_delimiter = use_signal(CsvDelimiter::default);
+    let mut infer_csv_types = use_signal(|| true);
+    let mut warnings = use_signal(Vec::<String>::new);
+    let mut busy = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let mut status = use_signal(|| None::<String>);
+
+    let convert = move |_| async move {
+        let text = input.read().clone();
+        let (source, target) = (from(), to());
+        let options = ConvertOptions { csv_delimiter: csv_delimiter(), infer_csv_types: infer_csv_types(), ..ConvertOptions::default() };
+        busy.set(true);
+        status.set(None);
+        let job = tokio::task::spawn_blocking(move || ConverterLogic::convert(&text, source, target, options)).await;
+        busy.set(false);
+
+        match job {
+            Ok(Ok(conversion)) => {
+                error.set(None);
+                output.set(conversion.output);
+                output_format.set(target);
+                status.set(Some(format!("Converted {} to {}", source, target)));
+                warnings.set(conversion.warnings);
+            }
+            Ok(Err(err)) => {
+                error.set(Some(err.to_string()));
+                warnings.set(Vec::new());
+            }
+            Err(err) => error.set(Some(format!("Conversion task failed: {}", err))),
+        }
+    };
+
+    // Feed the output back in as the new input, so a conversion can be checked by reversing it
+    let swap = move |_| {
+        let (source, target) = (from(), to());
+        from.set(target);
+        to.set(source);
+        let converted = output.read().clone();
+        if !converted.is_empty() {
+            from.set(output_format());
+            input.set(converted);
+            output.set(String::new());
+        }
+        warnings.set(Vec::new());
+        error.set(None);
+    };
+
+    let open_file = move |_| async move {
+        let Some(handle) = AsyncFileDialog::new()
+            .add_filter("Data files", &["json", "yaml", "yml", "toml", "csv", "tsv"])
+            .pick_file()
+            .await
+        else {
+            return;
+        };
+        let bytes = handle.read().await;
+        let name = handle.file_name();
+        if let Some(format) = DataFormat::from_file_name(&name) {
+            from.set(format);
+            if format == to() {
+                to.set(if format == DataFormat::Json { DataFormat::Yaml } else { DataFormat::Json });
+            }
+        }
+        if name.to_ascii_lowercase().ends_with(".tsv") {
+            csv_delimiter.set(CsvDelimiter::Tab);
+        }
+        input.set(String::from_utf8_lossy(&bytes).into_owned());
+        output.set(String::new());
+        warnings.set(Vec::new());
+        error.set(None);
+        status.set(Some(format!("Loaded {} ({})", name, Base64Logic::format_size(bytes.len() as u64))));
+    };
+
+    let save_output = move |_| async move {
+        let text = output.read().clone();
+        let format = output_format();
+        let file_name = format!("converted.{}", format.extension());
+        let Some(handle) = AsyncFileDialog::new().add_filter(format.to_string(), &[format.extension()]).set_file_name(file_name).save_file().await else {
+            return;
+        };
+        match std::fs::write(handle.path(), text) {
+            Ok(()) => status.set(Some(format!("Saved {}", handle.path().display()))),
+            Err(err) => error.set(Some(format!("Failed to save file: {}", err))),
+        }
+    };
+
+    let copy_output = move |_| {
+        if let Ok(mut clipboard) = Clipboard::new() {
+            let _ = clipboard.set_text(output.read().clone());
+            status.set(Some("Copied to clipboard".to_string()));
+        }
+    };
+
+    let clear = move |_| {
+        input.set(String::new());
+        output.set(String::new());
+        warnings.set(Vec::new());
+        error.set(None);
+        status.set(None);
+    };
+
+    let uses_csv = from() == DataFormat::Csv || to() == DataFormat::Csv;
+
+    rsx! {
+        div {
+            style: "padding: 20px; height: 100%; display: flex; flex-direction: column; box-sizing: border-box; overflow: hidden;",
+
+            h1 {
+                style: "font-size: 24px; margin-bottom: 15px; color: #2c3e50; margin-top: 0; flex-shrink: 0;",
+                "Data Converter"
+            }
+
+            // Controls
+            div {
+                style: "margin-bottom: 15px; display: flex; gap: 10px; align-items: center; flex-wrap: wrap; flex-shrink: 0;",
+
+                button {
+                    style: "padding: 8px 16px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                    onclick: open_file,
+                    "Open File"
+                }
+
+                select {
+                    style: "padding: 8px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; background-color: white;",
+                    onchange: move |event| {
+                        let selected = DataFormat::ALL.into_iter().find(|format| format.to_string() == event.value()).unwrap_or_default();
+                        from.set(selected);
+                    },
+
+                    for format in DataFormat::ALL {
+                        option {
+                            value: "{format}",
+                            selected: from() == format,
+                            "{format}"
+                        }
+                    }
+                }
+
+                button {
+                    style: "padding: 8px 12px; background-color: #ecf0f1; color: #2c3e50; border: 1px solid #bdc3c7; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                    title: "Swap formats and convert the output back",
+                    onclick: swap,
+                    "⇄"
+                }
+
+                select {
+                    style: "padding: 8px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; background-color: white;",
+                    onchange: move |event| {
+                        let selected = DataFormat::ALL.into_iter().find(|format| format.to_string() == event.value()).unwrap_or_default();
+                        to.set(selected);
+                    },
+
+                    for format in DataFormat::ALL {
+                        option {
+                            value: "{format}",
+                            selected: to() == format,
+                            "{format}"
+                        }
+                    }
+                }
+
+                if uses_csv {
+                    select {
+                        style: "padding: 8px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; background-color: white;",
+                        title: "CSV delimiter",
+                        onchange: move |event| {
+                            let selected = CsvDelimiter::ALL.into_iter().find(|option| option.to_string() == event.value()).unwrap_or_default();
+                            csv_delimiter.set(selected);
+                        },
+
+                        for option_delimiter in CsvDelimiter::ALL {
+                            option {
+                                value: "{option_delimiter}",
+                                selected: csv_delimiter() == option_delimiter,
+                                "{option_delimiter}"
+                            }
+                        }
+                    }
+
+                    if from() == DataFormat::Csv {
+                        label {
+                            style: "display: flex; gap: 6px; align-items: center; font-size: 14px; color: #2c3e50; cursor: pointer;",
+
+                            input {
+                                r#type: "checkbox",
+                                checked: infer_csv_types(),
+                                onchange: move |event| infer_csv_types.set(event.checked())
+                            }
+                            "Infer types"
+                        }
+                    }
+                }
+
+                button {
+                    style: "padding: 8px 16px; background-color: #3498db; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                    disabled: busy(),
+                    onclick: convert,
+                    "Convert"
+                }
+
+                button {
+                    style: "padding: 8px 16px; background-color: #95a5a6; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                    onclick: clear,
+                    "Clear"
+                }
+
+                if busy() {
+                    span {
+                        style: "font-size: 14px; color: #7f8c8d;",
+                        "Working..."
+                    }
+                }
+            }
+
+            div {
+                style: "flex: 1; display: flex; gap: 20px; min-height: 0;",
+
+                // Input section
+                div {
+                    style: "flex: 1; display: flex; flex-direction: column; min-height: 0;",
+
+                    h3 {
+                        style: "font-size: 16px; margin-bottom: 5px; color: #2c3e50; margin-top: 0;",
+                        "Input ({from()})"
+                    }
+
+                    textarea {
+                        style: "flex: 1; width: 100%; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 13px; font-family: monospace; resize: none; box-sizing: border-box; white-space: pre; overflow-wrap: normal;",
+                        placeholder: "Paste {from()} here...",
+                        spellcheck: false,
+                        value: "{input.read()}",
+                        oninput: move |event| input.set(event.value())
+                    }
+                }
+
+                // Output section
+                div {
+                    style: "flex: 1; display: flex; flex-direction: column; min-height: 0;",
+
+                    div {
+                        style: "display: flex; align-items: center; gap: 10px; margin-bottom: 5px;",
+
+                        h3 {
+                            style: "font-size: 16px; color: #2c3e50; margin: 0;",
+                            if output.read().is_empty() { "Output ({to()})" } else { "Output ({output_format()})" }
+                        }
+
+                        if !output.read().is_empty() {
+                            button {
+                                style: "padding: 4px 8px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                                onclick: copy_output,
+                                "📋 Copy"
+                            }
+
+                            button {
+                                style: "padding: 4px 8px; background-color: #27ae60; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                                onclick: save_output,
+                                "💾 Save"
+                            }
+                        }
+                    }
+
+                    textarea {
+                        style: "flex: 1; width: 100%; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 13px; font-family: monospace; resize: none; box-sizing: border-box; background-color: #f8f9fa; white-space: pre; overflow-wrap: normal;",
+                        readonly: true,
+                        value: "{output.read()}"
+                    }
+                }
+            }
+
+            if let Some(message) = status.read().as_ref() {
+                div {
+                    style: "margin-top: 10px; padding: 10px; background-color: #e8f5e8; border: 1px solid #4caf50; border-radius: 4px; color: #2e7d32; font-size: 14px; flex-shrink: 0;",
+                    "{message}"
+                }
+            }
+
+            // Information lost or reinterpreted by the conversion
+            if !warnings.read().is_empty() {
+                div {
+                    style: "margin-top: 10px; padding: 10px; background-color: #fff8e1; border: 1px solid #ffb300; border-radius: 4px; color: #8d6e00; font-size: 14px; flex-shrink: 0; max-height: 150px; overflow-y: auto;",
+
+                    div {
+                        style: "margin-bottom: 6px; font-weight: 500;",
+                        "⚠ {warnings.read().len()} lossy conversions"
+                    }
+
+                    for (index, warning) in warnings.read().iter().enumerate() {
+                        div {
+                            key: "{index}",
+                            style: "font-family: monospace; font-size: 13px;",
+                            "{warning}"
+                        }
+                    }
+                }
+            }
+
+            if let Some(message) = error.read().as_ref() {
+                div {
+                    style: "margin-top: 10px; padding: 10px; background-color: #ffebee; border: 1px solid #f44336; border-radius: 4px; color: #c62828; font-size: 14px; flex-shrink: 0; white-space: pre-wrap; font-family: monospace;",
+                    "{message}"
+                }
+            }
+        }
+    }
+}
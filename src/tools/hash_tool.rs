@@ -0,0 +1,631 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use arboard::Clipboard;
+use rfd::AsyncFileDialog;
+use std::path::{Path, PathBuf};
+use crate::logic::checksum_logic::{ChecksumLogic, EntryStatus, ManifestEntry, ManifestFormat};
+use crate::logic::hash_logic::{DigestEncoding, HashAlgorithm, HashLogic, HashResult};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HashMode {
+    #[default]
+    Digest,
+    Manifest,
+}
+
+impl std::fmt::Display for HashMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HashMode::Digest => write!(f, "Digest"),
+            HashMode::Manifest => write!(f, "Checksum File"),
+        }
+    }
+}
+
+impl HashMode {
+    pub const ALL: [HashMode; 2] = [HashMode::Digest, HashMode::Manifest];
+}
+
+pub struct HashTool;
+
+impl HashTool {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn view(&self) -> Element {
+        rsx! { HashToolView {} }
+    }
+}
+
+#[component]
+pub fn HashToolView() -> Element {
+    let mut mode = use_signal(HashMode::default);
+    let mut input = use_signal(String::new);
+    let mut input_file = use_signal(|| None::<String>);
+    let mut results = use_signal(|| HashLogic::digest_all(b""));
+    let mut encoding = use_signal(DigestEncoding::default);
+    let mut uppercase = use_signal(|| false);
+    let mut expected = use_signal(String::new);
+    let mut hashing = use_signal(|| false);
+    let mut status = use_signal(|| None::<String>);
+    let mut error = use_signal(|| None::<String>);
+
+    let open_file = move |_| async move {
+        let Some(handle) = AsyncFileDialog::new().pick_file().await else {
+            return;
+        };
+
+        let path = handle.path().to_path_buf();
+        hashing.set(true);
+        error.set(None);
+        status.set(None);
+        let job = tokio::task::spawn_blocking(move || HashLogic::digest_file(&path, &HashAlgorithm::ALL)).await;
+        hashing.set(false);
+
+        match job {
+            Ok(Ok(file_results)) => {
+                results.set(file_results);
+                input_file.set(Some(handle.file_name()));
+            }
+            Ok(Err(err)) => error.set(Some(format!("{:?}", err))),
+            Err(err) => error.set(Some(format!("Hashing task failed: {}", err))),
+        }
+    };
+
+    let use_text_input = move |_| {
+        input_file.set(None);
+        results.set(HashLogic::digest_all(input.read().as_bytes()));
+    };
+
+    let clear = move |_| {
+        input.set(String::new());
+        input_file.set(None);
+        expected.set(String::new());
+        results.set(HashLogic::digest_all(b""));
+        status.set(None);
+        error.set(None);
+    };
+
+    let copy_digest = move |text: String| {
+        if let Ok(mut clipboard) = Clipboard::new() {
+            let _ = clipboard.set_text(text);
+            status.set(Some("Copied to clipboard".to_string()));
+        }
+    };
+
+    let matched = {
+        let expected = expected.read();
+        if expected.trim().is_empty() { None } else { Some(HashLogic::find_match(&expected, &results.read())) }
+    };
+
+    rsx! {
+        div {
+            style: "padding: 20px; height: 100%; display: flex; flex-direction: column; box-sizing: border-box; overflow: hidden;",
+
+            h1 {
+                style: "font-size: 24px; margin-bottom: 15px; color: #2c3e50; margin-top: 0; flex-shrink: 0;",
+                "Hash Generator"
+            }
+
+            // Mode tabs
+            div {
+                style: "display: flex; gap: 10px; margin-bottom: 15px; flex-shrink: 0;",
+
+                for option_mode in HashMode::ALL {
+                    button {
+                        style: if mode() == option_mode {
+                            "padding: 8px 16px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;"
+                        } else {
+                            "padding: 8px 16px; background-color: #ecf0f1; color: #2c3e50; border: 1px solid #bdc3c7; border-radius: 4px; cursor: pointer; font-size: 14px;"
+                        },
+                        onclick: move |_| mode.set(option_mode),
+                        "{option_mode}"
+                    }
+                }
+            }
+
+            if mode() == HashMode::Manifest {
+                ManifestPanel {}
+            } else {
+                // Input section
+                div {
+                    style: "margin-bottom: 15px; flex-shrink: 0;",
+
+                    h3 {
+                        style: "font-size: 16px; margin-bottom: 5px; color: #2c3e50; margin-top: 0;",
+                        "Input"
+                    }
+
+                    if let Some(name) = input_file.read().as_ref() {
+                        div {
+                            style: "display: flex; align-items: center; gap: 10px; height: 60px; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; background-color: #f8f9fa; box-sizing: border-box;",
+                            span {
+                                style: "flex: 1; font-size: 14px; font-family: monospace; color: #2c3e50;",
+                                "📄 {name}"
+                            }
+                            button {
+                                style: "padding: 4px 8px; background-color: #95a5a6; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                                onclick: use_text_input,
+                                "Use Text Input"
+                            }
+                        }
+                    } else {
+                        textarea {
+                            style: "width: calc(100% - 20px); height: 60px; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; font-family: monospace; resize: none; box-sizing: border-box;",
+                            placeholder: "Enter text to hash...",
+                            value: "{input.read()}",
+                            oninput: move |event| {
+                                let value = event.value();
+                                results.set(HashLogic::digest_all(value.as_bytes()));
+                                input.set(value);
+                                status.set(None);
+                            }
+                        }
+                    }
+                }
+
+                // Options
+                div {
+                    style: "margin-bottom: 15px; display: flex; gap: 10px; align-items: center; flex-shrink: 0;",
+
+                    button {
+                        style: "padding: 8px 16px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                        disabled: hashing(),
+                        onclick: open_file,
+                        if hashing() { "Hashing..." } else { "Open File" }
+                    }
+
+                    select {
+                        style: "padding: 8px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; background-color: white;",
+                        onchange: move |event| {
+                            let selected = DigestEncoding::ALL
+                                .into_iter()
+                                .find(|option| option.to_string() == event.value())
+                                .unwrap_or(DigestEncoding::Hex);
+                            encoding.set(selected);
+                        },
+
+                        for option_encoding in DigestEncoding::ALL {
+                            option {
+                                value: "{option_encoding}",
+                                selected: encoding() == option_encoding,
+                                "{option_encoding}"
+                            }
+                        }
+                    }
+
+                    label {
+                        style: "display: flex; gap: 6px; align-items: center; font-size: 14px; color: #2c3e50; cursor: pointer;",
+
+                        input {
+                            r#type: "checkbox",
+                            checked: uppercase(),
+                            disabled: encoding() != DigestEncoding::Hex,
+                            onchange: move |event| uppercase.set(event.checked())
+                        }
+                        "Uppercase"
+                    }
+
+                    button {
+                        style: "padding: 8px 16px; background-color: #95a5a6; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                        onclick: clear,
+                        "Clear"
+                    }
+                }
+
+                // Expected checksum
+                div {
+                    style: "margin-bottom: 15px; flex-shrink: 0;",
+
+                    h3 {
+                        style: "font-size: 16px; margin-bottom: 5px; color: #2c3e50; margin-top: 0;",
+                        "Compare with expected checksum"
+                    }
+
+                    input {
+                        style: "width: 100%; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; font-family: monospace; box-sizing: border-box;",
+                        placeholder: "Paste a hex or Base64 checksum...",
+                        value: "{expected.read()}",
+                        oninput: move |event| expected.set(event.value())
+                    }
+
+                    match matched {
+                        Some(Some(algorithm)) => rsx! {
+                            div {
+                                style: "margin-top: 8px; font-size: 14px; color: #2e7d32;",
+                                "✓ Matches {algorithm}"
+                            }
+                        },
+                        Some(None) => rsx! {
+                            div {
+                                style: "margin-top: 8px; font-size: 14px; color: #c62828;",
+                                "✗ Does not match any algorithm"
+                            }
+                        },
+                        None => rsx! {},
+                    }
+                }
+
+                // Results
+                div {
+                    style: "flex: 1; overflow-y: auto; min-height: 0;",
+
+                    for result in results.read().iter().cloned() {
+                        DigestRow {
+                            key: "{result.algorithm}",
+                            formatted: HashLogic::format_digest(&result.digest, encoding(), uppercase()),
+                            highlighted: matched == Some(Some(result.algorithm)),
+                            result,
+                            on_copy: copy_digest,
+                        }
+                    }
+                }
+
+                if let Some(message) = status.read().as_ref() {
+                    div {
+                        style: "margin-top: 10px; padding: 10px; background-color: #e8f5e8; border: 1px solid #4caf50; border-radius: 4px; color: #2e7d32; font-size: 14px; flex-shrink: 0;",
+                        "{message}"
+                    }
+                }
+
+                // Error message
+                if let Some(err) = error.read().as_ref() {
+                    div {
+                        style: "margin-top: 10px; padding: 10px; background-color: #ffebee; border: 1px solid #f44336; border-radius: 4px; color: #c62828; font-size: 14px; flex-shrink: 0;",
+                        "{err}"
+                    }
+            }
+            }
+        }
+    }
+}
+
+#[component]
+fn DigestRow(result: HashResult, formatted: String, highlighted: bool, on_copy: EventHandler<String>) -> Element {
+    let border = if highlighted { "#4caf50" } else { "#bdc3c7" };
+    let background = if highlighted { "#e8f5e8" } else { "#f8f9fa" };
+    let copied = formatted.clone();
+
+    rsx! {
+        div {
+            style: "display: flex; align-items: center; gap: 10px; margin-bottom: 8px; padding: 8px 10px; border: 1px solid {border}; border-radius: 4px; background-color: {background};",
+
+            span {
+                style: "width: 100px; flex-shrink: 0; font-size: 14px; font-weight: 500; color: #2c3e50;",
+                "{result.algorithm}"
+            }
+
+            span {
+                style: "flex: 1; font-size: 13px; font-family: monospace; color: #2c3e50; word-break: break-all; user-select: text;",
+                "{formatted}"
+            }
+
+            button {
+                style: "padding: 4px 8px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px; flex-shrink: 0;",
+                onclick: move |_| on_copy.call(copied.clone()),
+                "📋 Copy"
+            }
+        }
+    }
+}
+
+#[component]
+fn ManifestPanel() -> Element {
+    let mut manifest_name = use_signal(|| None::<String>);
+    let mut rows = use_signal(Vec::<(ManifestEntry, Option<EntryStatus>)>::new);
+    let mut algorithm = use_signal(|| HashAlgorithm::Sha256);
+    let mut format = use_signal(ManifestFormat::default);
+    let mut generated = use_signal(|| None::<(PathBuf, Vec<ManifestEntry>)>);
+    let mut progress = use_signal(|| None::<(usize, usize)>);
+    let mut status = use_signal(|| None::<String>);
+    let mut error = use_signal(|| None::<String>);
+
+    let open_manifest = move |_| async move {
+        let Some(handle) = AsyncFileDialog::new().pick_file().await else {
+            return;
+        };
+
+        let name = handle.file_name();
+        let text = String::from_utf8_lossy(&handle.read().await).into_owned();
+        let base_dir = handle.path().parent().map(Path::to_path_buf).unwrap_or_default();
+        generated.set(None);
+        status.set(None);
+        error.set(None);
+        let entries = match ChecksumLogic::parse_manifest(&text, ChecksumLogic::algorithm_hint(&name)) {
+            Ok(entries) => entries,
+            Err(err) => {
+                rows.set(Vec::new());
+                error.set(Some(format!("{:?}", err)));
+                return;
+            }
+        };
+        manifest_name.set(Some(name));
+        rows.set(entries.iter().cloned().map(|entry| (entry, None)).collect());
+
+        // One blocking task per file; tokio's pool runs them in parallel
+        let total = entries.len();
+        progress.set(Some((0, total)));
+        let jobs: Vec<_> = entries
+            .into_iter()
+            .map(|entry| {
+                let base_dir = base_dir.clone();
+                tokio::task::spawn_blocking(move || ChecksumLogic::verify_entry(&base_dir, &entry))
+            })
+            .collect();
+        for (index, job) in jobs.into_iter().enumerate() {
+            let result = job.await.unwrap_or(EntryStatus::Failed);
+            rows.write()[index].1 = Some(result);
+            progress.set(Some((index + 1, total)));
+        }
+        progress.set(None);
+    };
+
+    let generate_manifest = move |_| async move {
+        let Some(folder) = AsyncFileDialog::new().pick_folder().await else {
+            return;
+        };
+
+        let dir = folder.path().to_path_buf();
+        let selected_algorithm = algorithm();
+        manifest_name.set(None);
+        rows.set(Vec::new());
+        status.set(None);
+        error.set(None);
+        progress.set(Some((0, 0)));
+
+        let listing = {
+            let dir = dir.clone();
+            tokio::task::spawn_blocking(move || ChecksumLogic::list_files(&dir)).await
+        };
+        let mut files = match listing {
+            Ok(Ok(files)) => files,
+            Ok(Err(err)) => {
+                progress.set(None);
+                error.set(Some(format!("{:?}", err)));
+                return;
+            }
+            Err(err) => {
+                progress.set(None);
+                error.set(Some(format!("Listing task failed: {}", err)));
+                return;
+            }
+        };
+        // A previous manifest in the folder would be stale as soon as the new one is saved
+        let default_name = ChecksumLogic::default_file_name(selected_algorithm);
+        files.retain(|path| *path != default_name);
+
+        let total = files.len();
+        progress.set(Some((0, total)));
+        let jobs: Vec<_> = files
+            .into_iter()
+            .map(|path| {
+                let dir = dir.clone();
+                tokio::task::spawn_blocking(move || ChecksumLogic::hash_entry(&dir, &path, selected_algorithm))
+            })
+            .collect();
+        let mut entries = Vec::with_capacity(total);
+        for (index, job) in jobs.into_iter().enumerate() {
+            match job.await {
+                Ok(Ok(entry)) => entries.push(entry),
+                Ok(Err(err)) => error.set(Some(format!("{:?}", err))),
+                Err(err) => error.set(Some(format!("Hashing task failed: {}", err))),
+            }
+            progress.set(Some((index + 1, total)));
+        }
+        progress.set(None);
+        status.set(Some(format!("Hashed {} files in {}", entries.len(), dir.display())));
+        generated.set(Some((dir, entries)));
+    };
+
+    let generated_text = generated
+        .read()
+        .as_ref()
+        .map(|(_, entries)| ChecksumLogic::format_manifest(entries, format()));
+
+    let save_manifest = {
+        let generated_text = generated_text.clone();
+        move |_| {
+            let generated_text = generated_text.clone();
+            async move {
+                let (Some(text), Some((dir, entries))) = (generated_text, generated.read().clone()) else {
+                    return;
+                };
+                let file_name = entries
+                    .first()
+                    .map(|entry| ChecksumLogic::default_file_name(entry.algorithm))
+                    .unwrap_or_else(|| ChecksumLogic::default_file_name(algorithm()));
+                let Some(handle) = AsyncFileDialog::new().set_directory(&dir).set_file_name(file_name).save_file().await else {
+                    return;
+                };
+
+                match std::fs::write(handle.path(), text) {
+                    Ok(()) => status.set(Some(format!("Saved {}", handle.path().display()))),
+                    Err(err) => error.set(Some(format!("Failed to save manifest: {}", err))),
+                }
+            }
+        }
+    };
+
+    let count = |wanted: EntryStatus| rows.read().iter().filter(|(_, result)| result.as_ref() == Some(&wanted)).count();
+    let summary = format!(
+        "{} OK, {} FAILED, {} MISSING",
+        count(EntryStatus::Ok),
+        count(EntryStatus::Failed),
+        count(EntryStatus::Missing)
+    );
+    let busy = progress().is_some();
+
+    rsx! {
+        // Options
+        div {
+            style: "margin-bottom: 15px; display: flex; gap: 10px; align-items: center; flex-shrink: 0;",
+
+            button {
+                style: "padding: 8px 16px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                disabled: busy,
+                onclick: open_manifest,
+                "Verify Checksum File"
+            }
+
+            span {
+                style: "width: 1px; height: 24px; background-color: #bdc3c7;",
+            }
+
+            select {
+                style: "padding: 8px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; background-color: white;",
+                onchange: move |event| {
+                    let selected = ChecksumLogic::ALGORITHMS
+                        .into_iter()
+                        .find(|option| option.to_string() == event.value())
+                        .unwrap_or(HashAlgorithm::Sha256);
+                    algorithm.set(selected);
+                },
+
+                for option_algorithm in ChecksumLogic::ALGORITHMS {
+                    option {
+                        value: "{option_algorithm}",
+                        selected: algorithm() == option_algorithm,
+                        "{option_algorithm}"
+                    }
+                }
+            }
+
+            select {
+                style: "padding: 8px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; background-color: white;",
+                onchange: move |event| {
+                    let selected = ManifestFormat::ALL
+                        .into_iter()
+                        .find(|option| option.to_string() == event.value())
+                        .unwrap_or(ManifestFormat::Gnu);
+                    format.set(selected);
+                },
+
+                for option_format in ManifestFormat::ALL {
+                    option {
+                        value: "{option_format}",
+                        selected: format() == option_format,
+                        "{option_format}"
+                    }
+                }
+            }
+
+            button {
+                style: "padding: 8px 16px; background-color: #27ae60; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                disabled: busy,
+                onclick: generate_manifest,
+                "Generate for Folder"
+            }
+
+            if let Some((done, total)) = progress() {
+                span {
+                    style: "font-size: 14px; color: #7f8c8d;",
+                    "Hashing {done} / {total} files..."
+                }
+            }
+        }
+
+        if let Some(text) = generated_text {
+            div {
+                style: "flex: 1; display: flex; flex-direction: column; min-height: 0;",
+
+                div {
+                    style: "display: flex; align-items: center; gap: 10px; margin-bottom: 5px;",
+
+                    h3 {
+                        style: "font-size: 16px; color: #2c3e50; margin: 0;",
+                        "Generated Manifest"
+                    }
+
+                    button {
+                        style: "padding: 4px 8px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                        onclick: save_manifest,
+                        "💾 Save"
+                    }
+                }
+
+                textarea {
+                    style: "flex: 1; width: 100%; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 13px; font-family: monospace; resize: none; box-sizing: border-box; background-color: #f8f9fa; white-space: pre; overflow-wrap: normal;",
+                    readonly: true,
+                    value: "{text}"
+                }
+            }
+        } else if !rows.read().is_empty() {
+            div {
+                style: "margin-bottom: 8px; display: flex; gap: 10px; font-size: 14px; color: #2c3e50; flex-shrink: 0;",
+
+                if let Some(name) = manifest_name.read().as_ref() {
+                    span { style: "font-family: monospace;", "📄 {name}" }
+                }
+                span { style: "font-weight: 500;", "{summary}" }
+            }
+
+            div {
+                style: "flex: 1; overflow-y: auto; min-height: 0; border: 1px solid #bdc3c7; border-radius: 4px;",
+
+                table {
+                    style: "width: 100%; border-collapse: collapse; font-size: 13px;",
+
+                    thead {
+                        tr {
+                            style: "background-color: #ecf0f1; text-align: left; color: #2c3e50;",
+                            th { style: "padding: 6px 10px; width: 90px;", "Status" }
+                            th { style: "padding: 6px 10px;", "File" }
+                            th { style: "padding: 6px 10px; width: 110px;", "Algorithm" }
+                        }
+                    }
+
+                    tbody {
+                        for (index, (entry, result)) in rows.read().iter().cloned().enumerate() {
+                            tr {
+                                key: "{index}",
+                                style: "border-top: 1px solid #ecf0f1;",
+
+                                td {
+                                    style: match result {
+                                        Some(EntryStatus::Ok) => "padding: 6px 10px; font-weight: 600; color: #2e7d32;",
+                                        Some(EntryStatus::Failed) => "padding: 6px 10px; font-weight: 600; color: #c62828;",
+                                        Some(EntryStatus::Missing) => "padding: 6px 10px; font-weight: 600; color: #ef6c00;",
+                                        None => "padding: 6px 10px; color: #95a5a6;",
+                                    },
+                                    match &result {
+                                        Some(result) => result.to_string(),
+                                        None => "…".to_string(),
+                                    }
+                                }
+                                td {
+                                    style: "padding: 6px 10px; font-family: monospace; color: #2c3e50; word-break: break-all; user-select: text;",
+                                    "{entry.path}"
+                                }
+                                td {
+                                    style: "padding: 6px 10px; color: #7f8c8d;",
+                                    "{entry.algorithm}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        } else {
+            div {
+                style: "flex: 1; font-size: 14px; color: #7f8c8d;",
+                "Open a SHA256SUMS-style file to verify the files it lists, or pick a folder to generate one."
+            }
+        }
+
+        if let Some(message) = status.read().as_ref() {
+            div {
+                style: "margin-top: 10px; padding: 10px; background-color: #e8f5e8; border: 1px solid #4caf50; border-radius: 4px; color: #2e7d32; font-size: 14px; flex-shrink: 0;",
+                "{message}"
+            }
+        }
+
+        if let Some(err) = error.read().as_ref() {
+            div {
+                style: "margin-top: 10px; padding: 10px; background-color: #ffebee; border: 1px solid #f44336; border-radius: 4px; color: #c62828; font-size: 14px; flex-shrink: 0;",
+                "{err}"
+            }
+        }
+    }
+}
@@ -0,0 +1,405 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use arboard::Clipboard;
+use rfd::AsyncFileDialog;
+use std::sync::Arc;
+use crate::logic::hash_logic::{DigestEncoding, HashAlgorithm};
+use crate::logic::hmac_logic::{HmacLogic, KeyEncoding, WebhookPreset};
+
+pub struct HmacTool;
+
+impl HmacTool {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn view(&self) -> Element {
+        rsx! { HmacToolView {} }
+    }
+}
+
+#[component]
+pub fn HmacToolView() -> Element {
+    let mut preset = use_signal(WebhookPreset::default);
+    let mut algorithm = use_signal(|| HashAlgorithm::Sha256);
+    let mut key = use_signal(String::new);
+    let mut key_encoding = use_signal(KeyEncoding::default);
+    let mut message = use_signal(String::new);
+    let mut message_file = use_signal(|| None::<(String, Arc<[u8]>)>);
+    let mut timestamp = use_signal(String::new);
+    let mut encoding = use_signal(DigestEncoding::default);
+    let mut header = use_signal(String::new);
+    let mut status = use_signal(|| None::<String>);
+
+    let open_file = move |_| async move {
+        let Some(handle) = AsyncFileDialog::new().pick_file().await else {
+            return;
+        };
+        let bytes = handle.read().await;
+        message_file.set(Some((handle.file_name(), Arc::from(bytes))));
+        status.set(None);
+    };
+
+    let clear = move |_| {
+        key.set(String::new());
+        message.set(String::new());
+        message_file.set(None);
+        timestamp.set(String::new());
+        header.set(String::new());
+        status.set(None);
+    };
+
+    let inputs = move || SigningInputs {
+        preset: preset(),
+        algorithm: algorithm(),
+        key: key(),
+        key_encoding: key_encoding(),
+        timestamp: timestamp(),
+        encoding: encoding(),
+        header: header(),
+    };
+
+    // Files can be large, so they are signed on a blocking task that restarts whenever an input changes
+    let file_outcome = use_resource(move || async move {
+        let (_, bytes) = message_file()?;
+        let inputs = inputs();
+        tokio::task::spawn_blocking(move || inputs.apply(&bytes)).await.ok()
+    });
+
+    // Pasted payloads are small enough to sign on every render; `None` while a file is still being signed
+    let (signature, verification) = if message_file.read().is_some() {
+        match file_outcome.read().clone().flatten() {
+            Some((signature, verification)) => (Some(signature), verification),
+            None => (None, None),
+        }
+    } else {
+        let (signature, verification) = inputs().apply(message.read().as_bytes());
+        (Some(signature), verification)
+    };
+
+    let copy_signature = {
+        let signature = signature.clone();
+        move |_| {
+            if let Some(Ok(text)) = &signature
+                && let Ok(mut clipboard) = Clipboard::new()
+            {
+                let _ = clipboard.set_text(text.clone());
+                status.set(Some("Signature copied to clipboard".to_string()));
+            }
+        }
+    };
+
+    let header_label = match preset().header_name() {
+        Some(name) => format!("Verify {} header", name),
+        None => "Verify signature".to_string(),
+    };
+
+    rsx! {
+        div {
+            style: "padding: 20px; height: 100%; display: flex; flex-direction: column; box-sizing: border-box; overflow-y: auto;",
+
+            h1 {
+                style: "font-size: 24px; margin-bottom: 15px; color: #2c3e50; margin-top: 0; flex-shrink: 0;",
+                "HMAC Generator"
+            }
+
+            // Preset and algorithm
+            div {
+                style: "margin-bottom: 15px; display: flex; gap: 10px; align-items: center; flex-shrink: 0;",
+
+                select {
+                    style: "padding: 8px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; background-color: white;",
+                    onchange: move |event| {
+                        let selected = WebhookPreset::ALL
+                            .into_iter()
+                            .find(|option| option.to_string() == event.value())
+                            .unwrap_or(WebhookPreset::Raw);
+                        preset.set(selected);
+                        if selected != WebhookPreset::Raw {
+                            // Providers sign with SHA-256 and hand out secrets as plain strings
+                            algorithm.set(HashAlgorithm::Sha256);
+                            key_encoding.set(KeyEncoding::Utf8);
+                        }
+                    },
+
+                    for option_preset in WebhookPreset::ALL {
+                        option {
+                            value: "{option_preset}",
+                            selected: preset() == option_preset,
+                            "{option_preset}"
+                        }
+                    }
+                }
+
+                select {
+                    style: "padding: 8px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; background-color: white;",
+                    disabled: preset() != WebhookPreset::Raw,
+                    onchange: move |event| {
+                        let selected = HmacLogic::ALGORITHMS
+                            .into_iter()
+                            .find(|option| format!("HMAC-{}", option) == event.value())
+                            .unwrap_or(HashAlgorithm::Sha256);
+                        algorithm.set(selected);
+                    },
+
+                    for option_algorithm in HmacLogic::ALGORITHMS {
+                        option {
+                            value: "HMAC-{option_algorithm}",
+                            selected: algorithm() == option_algorithm,
+                            "HMAC-{option_algorithm}"
+                        }
+                    }
+                }
+
+                if preset() == WebhookPreset::Raw {
+                    select {
+                        style: "padding: 8px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; background-color: white;",
+                        onchange: move |event| {
+                            let selected = DigestEncoding::ALL
+                                .into_iter()
+                                .find(|option| option.to_string() == event.value())
+                                .unwrap_or(DigestEncoding::Hex);
+                            encoding.set(selected);
+                        },
+
+                        for option_encoding in DigestEncoding::ALL {
+                            option {
+                                value: "{option_encoding}",
+                                selected: encoding() == option_encoding,
+                                "{option_encoding}"
+                            }
+                        }
+                    }
+                }
+
+                button {
+                    style: "padding: 8px 16px; background-color: #95a5a6; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                    onclick: clear,
+                    "Clear"
+                }
+            }
+
+            // Key
+            div {
+                style: "margin-bottom: 15px; flex-shrink: 0;",
+
+                h3 {
+                    style: "font-size: 16px; margin-bottom: 5px; color: #2c3e50; margin-top: 0;",
+                    "Secret Key"
+                }
+
+                div {
+                    style: "display: flex; gap: 10px;",
+
+                    input {
+                        style: "flex: 1; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; font-family: monospace;",
+                        placeholder: "Signing secret...",
+                        value: "{key.read()}",
+                        oninput: move |event| key.set(event.value())
+                    }
+
+                    select {
+                        style: "padding: 8px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; background-color: white;",
+                        onchange: move |event| {
+                            let selected = KeyEncoding::ALL
+                                .into_iter()
+                                .find(|option| option.to_string() == event.value())
+                                .unwrap_or(KeyEncoding::Utf8);
+                            key_encoding.set(selected);
+                        },
+
+                        for option_encoding in KeyEncoding::ALL {
+                            option {
+                                value: "{option_encoding}",
+                                selected: key_encoding() == option_encoding,
+                                "{option_encoding}"
+                            }
+                        }
+                    }
+                }
+            }
+
+            if preset().uses_timestamp() {
+                div {
+                    style: "margin-bottom: 15px; flex-shrink: 0;",
+
+                    h3 {
+                        style: "font-size: 16px; margin-bottom: 5px; color: #2c3e50; margin-top: 0;",
+                        if preset() == WebhookPreset::Slack { "X-Slack-Request-Timestamp" } else { "Timestamp (t=)" }
+                    }
+
+                    input {
+                        style: "width: 100%; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; font-family: monospace; box-sizing: border-box;",
+                        placeholder: "1531420618",
+                        value: "{timestamp.read()}",
+                        oninput: move |event| timestamp.set(event.value())
+                    }
+                }
+            }
+
+            // Message
+            div {
+                style: "margin-bottom: 15px; flex-shrink: 0;",
+
+                div {
+                    style: "display: flex; align-items: center; gap: 10px; margin-bottom: 5px;",
+
+                    h3 {
+                        style: "font-size: 16px; color: #2c3e50; margin: 0;",
+                        "Message / Raw Body"
+                    }
+
+                    button {
+                        style: "padding: 4px 8px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                        onclick: open_file,
+                        "Open File"
+                    }
+                }
+
+                if let Some((name, bytes)) = message_file.read().as_ref() {
+                    div {
+                        style: "display: flex; align-items: center; gap: 10px; height: 100px; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; background-color: #f8f9fa; box-sizing: border-box;",
+                        span {
+                            style: "flex: 1; font-size: 14px; font-family: monospace; color: #2c3e50;",
+                            "📄 {name} ({bytes.len()} bytes)"
+                        }
+                        button {
+                            style: "padding: 4px 8px; background-color: #95a5a6; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                            onclick: move |_| message_file.set(None),
+                            "Use Text Input"
+                        }
+                    }
+                } else {
+                    textarea {
+                        style: "width: 100%; height: 100px; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; font-family: monospace; resize: vertical; box-sizing: border-box;",
+                        placeholder: "Paste the exact request body...",
+                        value: "{message.read()}",
+                        oninput: move |event| message.set(event.value())
+                    }
+                }
+            }
+
+            // Signature
+            div {
+                style: "margin-bottom: 15px; flex-shrink: 0;",
+
+                div {
+                    style: "display: flex; align-items: center; gap: 10px; margin-bottom: 5px;",
+
+                    h3 {
+                        style: "font-size: 16px; color: #2c3e50; margin: 0;",
+                        "Signature"
+                    }
+
+                    if matches!(signature, Some(Ok(_))) {
+                        button {
+                            style: "padding: 4px 8px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                            onclick: copy_signature,
+                            "📋 Copy"
+                        }
+                    }
+                }
+
+                match &signature {
+                    Some(Ok(text)) => rsx! {
+                        div {
+                            style: "padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; background-color: #f8f9fa; font-size: 13px; font-family: monospace; word-break: break-all; user-select: text;",
+                            "{text}"
+                        }
+                    },
+                    Some(Err(err)) => rsx! {
+                        div {
+                            style: "padding: 10px; background-color: #ffebee; border: 1px solid #f44336; border-radius: 4px; color: #c62828; font-size: 14px;",
+                            "{err}"
+                        }
+                    },
+                    None => rsx! {
+                        div {
+                            style: "padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; background-color: #f8f9fa; font-size: 14px; color: #7f8c8d;",
+                            "Signing file..."
+                        }
+                    },
+                }
+            }
+
+            // Verification
+            div {
+                style: "margin-bottom: 15px; flex-shrink: 0;",
+
+                h3 {
+                    style: "font-size: 16px; margin-bottom: 5px; color: #2c3e50; margin-top: 0;",
+                    "{header_label}"
+                }
+
+                input {
+                    style: "width: 100%; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; font-family: monospace; box-sizing: border-box;",
+                    placeholder: "Paste the received signature...",
+                    value: "{header.read()}",
+                    oninput: move |event| header.set(event.value())
+                }
+
+                match verification {
+                    Some(Ok(true)) => rsx! {
+                        div {
+                            style: "margin-top: 8px; font-size: 14px; color: #2e7d32;",
+                            "✓ Signature is valid"
+                        }
+                    },
+                    Some(Ok(false)) => rsx! {
+                        div {
+                            style: "margin-top: 8px; font-size: 14px; color: #c62828;",
+                            "✗ Signature does not match"
+                        }
+                    },
+                    Some(Err(err)) => rsx! {
+                        div {
+                            style: "margin-top: 8px; font-size: 14px; color: #c62828;",
+                            "{err}"
+                        }
+                    },
+                    None => rsx! {},
+                }
+            }
+
+            if let Some(message) = status.read().as_ref() {
+                div {
+                    style: "padding: 10px; background-color: #e8f5e8; border: 1px solid #4caf50; border-radius: 4px; color: #2e7d32; font-size: 14px; flex-shrink: 0;",
+                    "{message}"
+                }
+            }
+        }
+    }
+}
+
+/// Everything a signature depends on besides the message body
+#[derive(Debug, Clone, PartialEq)]
+struct SigningInputs {
+    preset: WebhookPreset,
+    algorithm: HashAlgorithm,
+    key: String,
+    key_encoding: KeyEncoding,
+    timestamp: String,
+    encoding: DigestEncoding,
+    header: String,
+}
+
+impl SigningInputs {
+    /// The formatted signature, plus whether the pasted header matches when one was given
+    fn apply(&self, body: &[u8]) -> (Result<String, String>, Option<Result<bool, String>>) {
+        let key_bytes = HmacLogic::decode_key(&self.key, self.key_encoding);
+        let signature = key_bytes.as_ref().map_err(|err| format!("{:?}", err)).and_then(|key_bytes| {
+            HmacLogic::sign(self.preset, self.algorithm, key_bytes, body, &self.timestamp)
+                .map(|digest| HmacLogic::format_signature(self.preset, &digest, &self.timestamp, self.encoding))
+                .map_err(|err| format!("{:?}", err))
+        });
+        let verification = match (&key_bytes, self.header.trim().is_empty()) {
+            (Ok(key_bytes), false) => Some(
+                HmacLogic::verify(self.preset, self.algorithm, key_bytes, body, &self.timestamp, &self.header)
+                    .map_err(|err| format!("{:?}", err)),
+            ),
+            _ => None,
+        };
+        (signature, verification)
+    }
+}
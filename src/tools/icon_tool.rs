@@ -2,26 +2,20 @@
 
 use dioxus::prelude::*;
 use arboard::Clipboard;
+use rfd::{AsyncFileDialog, AsyncMessageDialog, MessageButtons, MessageDialogResult, MessageLevel};
+use crate::logic::icon_logic::{BundleTarget, FillStyle, FontFamily, FontWeight, IconLogic, IconShape, IconSpec};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum IconShape {
-    Circle,
-    Square,
-}
-
-impl std::fmt::Display for IconShape {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            IconShape::Circle => write!(f, "Circle"),
-            IconShape::Square => write!(f, "Square"),
-        }
-    }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum ExportFormat {
+    #[default]
     Png,
     Ico,
+    Icns,
+    PngSet,
+    Favicon,
+    Android,
+    Ios,
+    Complete,
 }
 
 impl std::fmt::Display for ExportFormat {
@@ -29,24 +23,63 @@ impl std::fmt::Display for ExportFormat {
         match self {
             ExportFormat::Png => write!(f, "PNG"),
             ExportFormat::Ico => write!(f, "ICO"),
+            ExportFormat::Icns => write!(f, "ICNS"),
+            ExportFormat::PngSet => write!(f, "PNG Set (16-1024px)"),
+            ExportFormat::Favicon => write!(f, "Favicon Set"),
+            ExportFormat::Android => write!(f, "Android Mipmaps"),
+            ExportFormat::Ios => write!(f, "iOS AppIcon Set"),
+            ExportFormat::Complete => write!(f, "Complete Bundle"),
         }
     }
 }
 
-pub struct IconTool;
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 8] = [
+        ExportFormat::Png,
+        ExportFormat::Ico,
+        ExportFormat::Icns,
+        ExportFormat::PngSet,
+        ExportFormat::Favicon,
+        ExportFormat::Android,
+        ExportFormat::Ios,
+        ExportFormat::Complete,
+    ];
 
-impl Default for IconShape {
-    fn default() -> Self {
-        IconShape::Circle
+    /// Bundle formats are written into a folder instead of a single file
+    fn bundle_target(self) -> Option<BundleTarget> {
+        match self {
+            ExportFormat::Png | ExportFormat::Ico | ExportFormat::Icns => None,
+            ExportFormat::PngSet => Some(BundleTarget::PngSet),
+            ExportFormat::Favicon => Some(BundleTarget::Favicon),
+            ExportFormat::Android => Some(BundleTarget::Android),
+            ExportFormat::Ios => Some(BundleTarget::Ios),
+            ExportFormat::Complete => Some(BundleTarget::Complete),
+        }
     }
 }
 
-impl Default for ExportFormat {
-    fn default() -> Self {
-        ExportFormat::Png
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IconMode {
+    #[default]
+    Text,
+    Identicon,
+}
+
+impl std::fmt::Display for IconMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IconMode::Text => write!(f, "Text"),
+            IconMode::Identicon => write!(f, "Identicon"),
+        }
     }
 }
 
+impl IconMode {
+    pub const ALL: [IconMode; 2] = [IconMode::Text, IconMode::Identicon];
+}
+
+pub struct IconTool;
+
 impl IconTool {
     pub fn new() -> Self {
         Self
@@ -57,92 +90,61 @@ impl IconTool {
     }
 }
 
-fn generate_preview_svg(text: &str, shape: IconShape, size: u32, bg_color: &str, text_color: &str) -> String {
-        let display_text = if text.len() > 3 {
-            &text[..3]
-        } else {
-            text
-        };
-
-        let font_size = match display_text.len() {
-            0 => size / 4,
-            1 => size / 2,
-            2 => size / 3,
-            3 => size / 4,
-            _ => size / 4,
-        };
-
-        let shape_element = match shape {
-            IconShape::Circle => {
-                let radius = size / 2;
-                format!("<circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"{}\"/>", 
-                    radius, radius, radius, bg_color)
-            },
-            IconShape::Square => {
-                format!("<rect width=\"{}\" height=\"{}\" fill=\"{}\"/>", size, size, bg_color)
-            }
-        };
-
-        let text_element = if !display_text.is_empty() {
-            format!(
-                "<text x=\"50%\" y=\"50%\" text-anchor=\"middle\" dominant-baseline=\"middle\" fill=\"{}\" font-family=\"Arial, sans-serif\" font-size=\"{}\" font-weight=\"bold\">{}</text>",
-                text_color, font_size, display_text
-            )
-        } else {
-            String::new()
-        };
-
-        format!(
-            "<svg width=\"{}\" height=\"{}\" xmlns=\"http://www.w3.org/2000/svg\">{}{}</svg>",
-            size, size, shape_element, text_element
-        )
-    }
-
-fn simple_base64_encode(input: &str) -> String {
-        // Simple base64 encoding without external dependencies
-        
-        let chars = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
-        let mut result = String::new();
-        let bytes = input.as_bytes();
-        
-        for chunk in bytes.chunks(3) {
-            let mut buffer = [0u8; 3];
-            for (i, &byte) in chunk.iter().enumerate() {
-                buffer[i] = byte;
-            }
-            
-            let combined = ((buffer[0] as u32) << 16) | ((buffer[1] as u32) << 8) | (buffer[2] as u32);
-            
-            result.push(chars.chars().nth(((combined >> 18) & 0x3F) as usize).unwrap());
-            result.push(chars.chars().nth(((combined >> 12) & 0x3F) as usize).unwrap());
-            result.push(if chunk.len() > 1 { chars.chars().nth(((combined >> 6) & 0x3F) as usize).unwrap() } else { '=' });
-            result.push(if chunk.len() > 2 { chars.chars().nth((combined & 0x3F) as usize).unwrap() } else { '=' });
-        }
-        
-        result
-    }
-
 #[component]
 pub fn IconToolView() -> Element {
+    let defaults = IconSpec::default();
+    let mut mode = use_signal(IconMode::default);
+    let mut seed = use_signal(String::new);
+    let mut show_pattern = use_signal(|| false);
     let mut text = use_signal(String::new);
-    let mut shape = use_signal(|| IconShape::Circle);
-    let mut size = use_signal(|| 128u32);
-    let mut background_color = use_signal(|| "#3498db".to_string());
-    let mut text_color = use_signal(|| "#ffffff".to_string());
-    let mut export_format = use_signal(|| ExportFormat::Png);
+    let mut shape = use_signal(|| defaults.shape);
+    let mut size = use_signal(|| defaults.size);
+    let mut background_color = use_signal(|| defaults.background_color.clone());
+    let mut text_color = use_signal(|| defaults.text_color.clone());
+    let mut font_family = use_signal(|| defaults.font_family);
+    let mut font_weight = use_signal(|| defaults.font_weight);
+    let mut corner_radius = use_signal(|| defaults.corner_radius);
+    let mut fill = use_signal(|| defaults.fill);
+    let mut gradient_color = use_signal(|| defaults.gradient_color.clone());
+    let mut gradient_angle = use_signal(|| defaults.gradient_angle);
+    let mut border_width = use_signal(|| defaults.border_width);
+    let mut border_color = use_signal(|| defaults.border_color.clone());
+    let mut shadow = use_signal(|| defaults.shadow);
+    let mut export_format = use_signal(ExportFormat::default);
     let mut preview_data = use_signal(String::new);
     let mut status = use_signal(|| None::<String>);
 
+    let spec = move || {
+        let spec = IconSpec {
+            text: text.read().clone(),
+            shape: shape(),
+            size: size(),
+            background_color: background_color.read().clone(),
+            text_color: text_color.read().clone(),
+            font_family: font_family(),
+            font_weight: font_weight(),
+            corner_radius: corner_radius(),
+            fill: fill(),
+            gradient_color: gradient_color.read().clone(),
+            gradient_angle: gradient_angle(),
+            border_width: border_width(),
+            border_color: border_color.read().clone(),
+            shadow: shadow(),
+            pattern: None,
+        };
+        match mode() {
+            IconMode::Text => spec,
+            IconMode::Identicon => IconLogic::identicon_spec(&seed.read(), show_pattern(), &spec),
+        }
+    };
+
     // Generate preview whenever inputs change
     let mut generate_preview = move || {
-        let svg = generate_preview_svg(&text.read(), shape(), size(), &background_color.read(), &text_color.read());
-        let data_url = format!("data:image/svg+xml;base64,{}", simple_base64_encode(&svg));
-        preview_data.set(data_url);
+        preview_data.set(IconLogic::render_data_url(&spec()));
     };
 
     let copy_base64 = move |_| {
-        let svg = generate_preview_svg(&text.read(), shape(), size(), &background_color.read(), &text_color.read());
-        let data_url = format!("data:image/svg+xml;base64,{}", simple_base64_encode(&svg));
+        let data_url = IconLogic::render_data_url(&spec());
         
         if let Ok(mut clipboard) = Clipboard::new() {
             let _ = clipboard.set_text(data_url);
@@ -152,17 +154,120 @@ pub fn IconToolView() -> Element {
         }
     };
 
-    let download_icon = move |_| {
-        status.set(Some("Download functionality not implemented yet - this would save the icon as a file".to_string()));
+    let download_icon = move |_| async move {
+        let spec = spec();
+        let format = export_format();
+
+        // Bundles rasterise up to 1024 px several times, so rendering runs on a blocking task
+        if let Some(target) = format.bundle_target() {
+            let Some(folder) = AsyncFileDialog::new().pick_folder().await else {
+                return;
+            };
+
+            status.set(Some("Rendering icons...".to_string()));
+            let files = match tokio::task::spawn_blocking(move || IconLogic::render_bundle(&spec, target)).await {
+                Ok(Ok(files)) => files,
+                Ok(Err(err)) => {
+                    status.set(Some(format!("Failed to render icon: {:?}", err)));
+                    return;
+                }
+                Err(err) => {
+                    status.set(Some(format!("Rendering task failed: {}", err)));
+                    return;
+                }
+            };
+
+            let existing = IconLogic::existing_files(folder.path(), &files);
+            if !existing.is_empty() {
+                let confirmed = AsyncMessageDialog::new()
+                    .set_level(MessageLevel::Warning)
+                    .set_title("Replace existing files?")
+                    .set_description(format!(
+                        "{} already contains {} of these files:\n\n{}\n\nReplace them?",
+                        folder.path().display(),
+                        existing.len(),
+                        existing.join("\n")
+                    ))
+                    .set_buttons(MessageButtons::YesNo)
+                    .show()
+                    .await;
+                if confirmed != MessageDialogResult::Yes {
+                    status.set(Some("Export cancelled; no files were written".to_string()));
+                    return;
+                }
+            }
+
+            match IconLogic::write_bundle(folder.path(), &files) {
+                Ok(()) if existing.is_empty() => status.set(Some(format!("Wrote {} files to {}", files.len(), folder.path().display()))),
+                Ok(()) => status.set(Some(format!(
+                    "Wrote {} files to {}, replacing {}",
+                    files.len(),
+                    folder.path().display(),
+                    existing.join(", ")
+                ))),
+                Err(err) => status.set(Some(format!("Failed to save bundle: {}", err))),
+            }
+            return;
+        }
+
+        let extension = match format {
+            ExportFormat::Ico => "ico",
+            ExportFormat::Icns => "icns",
+            _ => "png",
+        };
+        let encoded = tokio::task::spawn_blocking(move || match format {
+            ExportFormat::Ico => IconLogic::render_ico(&spec),
+            ExportFormat::Icns => IconLogic::render_icns(&spec),
+            _ => IconLogic::render_png(&spec),
+        })
+        .await;
+
+        let bytes = match encoded {
+            Ok(Ok(bytes)) => bytes,
+            Ok(Err(err)) => {
+                status.set(Some(format!("Failed to render icon: {:?}", err)));
+                return;
+            }
+            Err(err) => {
+                status.set(Some(format!("Rendering task failed: {}", err)));
+                return;
+            }
+        };
+
+        let Some(handle) = AsyncFileDialog::new()
+            .add_filter(format.to_string(), &[extension])
+            .set_file_name(format!("icon.{}", extension))
+            .save_file()
+            .await
+        else {
+            return;
+        };
+
+        match std::fs::write(handle.path(), bytes) {
+            Ok(()) => status.set(Some(format!("Saved to {}", handle.path().display()))),
+            Err(err) => status.set(Some(format!("Failed to save file: {}", err))),
+        }
     };
 
     let clear = move |_| {
-        text.set(String::new());
-        shape.set(IconShape::Circle);
-        size.set(128);
-        background_color.set("#3498db".to_string());
-        text_color.set("#ffffff".to_string());
-        export_format.set(ExportFormat::Png);
+        let defaults = IconSpec::default();
+        seed.set(String::new());
+        show_pattern.set(false);
+        text.set(defaults.text);
+        shape.set(defaults.shape);
+        size.set(defaults.size);
+        background_color.set(defaults.background_color);
+        text_color.set(defaults.text_color);
+        font_family.set(defaults.font_family);
+        font_weight.set(defaults.font_weight);
+        corner_radius.set(defaults.corner_radius);
+        fill.set(defaults.fill);
+        gradient_color.set(defaults.gradient_color);
+        gradient_angle.set(defaults.gradient_angle);
+        border_width.set(defaults.border_width);
+        border_color.set(defaults.border_color);
+        shadow.set(defaults.shadow);
+        export_format.set(ExportFormat::default());
         preview_data.set(String::new());
         status.set(None);
     };
@@ -174,7 +279,7 @@ pub fn IconToolView() -> Element {
 
     rsx! {
         div {
-            style: "padding: 20px; height: 100%; display: flex; flex-direction: column; box-sizing: border-box; overflow: hidden;",
+            style: "padding: 20px; height: 100%; display: flex; flex-direction: column; box-sizing: border-box; overflow-y: auto;",
                 
                 h1 {
                     style: "font-size: 24px; margin-bottom: 20px; color: #2c3e50;",
@@ -188,26 +293,135 @@ pub fn IconToolView() -> Element {
                     div {
                         style: "flex: 1;",
                         
-                        // Text input
+                        // Mode
+                        div {
+                            style: "display: flex; gap: 10px; margin-bottom: 15px;",
+                            
+                            for option_mode in IconMode::ALL {
+                                button {
+                                    style: if mode() == option_mode {
+                                        "flex: 1; padding: 8px; background-color: #76ABAE; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;"
+                                    } else {
+                                        "flex: 1; padding: 8px; background-color: #31363F; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;"
+                                    },
+                                    onclick: move |_| {
+                                        mode.set(option_mode);
+                                        generate_preview();
+                                        status.set(None);
+                                    },
+                                    "{option_mode}"
+                                }
+                            }
+                        }
+                        
+                        if mode() == IconMode::Text {
+                            // Text input
+                            div {
+                                style: "margin-bottom: 15px;",
+                            
+                                h3 {
+                                    style: "font-size: 16px; margin-bottom: 5px; color: #2c3e50;",
+                                    "Text ({IconLogic::text_length(&text.read())}/{IconLogic::MAX_TEXT_LENGTH} characters)"
+                                }
+                            
+                                input {
+                                    style: "width: 100%; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px;",
+                                    placeholder: "Initials or emoji...",
+                                    value: "{text.read()}",
+                                    oninput: move |event| {
+                                        text.set(IconLogic::truncate_text(&event.value()));
+                                        generate_preview();
+                                        status.set(None);
+                                    }
+                                }
+                            }
+                        
+                        } else {
+                            // Identicon seed
+                            div {
+                                style: "margin-bottom: 15px;",
+                                
+                                h3 {
+                                    style: "font-size: 16px; margin-bottom: 5px; color: #2c3e50;",
+                                    "Seed"
+                                }
+                                
+                                input {
+                                    style: "width: 100%; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px;",
+                                    placeholder: "alice@example.com",
+                                    value: "{seed.read()}",
+                                    oninput: move |event| {
+                                        seed.set(event.value());
+                                        generate_preview();
+                                        status.set(None);
+                                    }
+                                }
+                                
+                                label {
+                                    style: "display: flex; gap: 8px; align-items: center; margin-top: 10px; font-size: 14px; color: #2c3e50; cursor: pointer;",
+                                    
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: show_pattern(),
+                                        onchange: move |event| {
+                                            show_pattern.set(event.checked());
+                                            generate_preview();
+                                        }
+                                    }
+                                    "Show 5x5 pattern instead of initials"
+                                }
+                            }
+                        }
+                        
+                        // Font
                         div {
                             style: "margin-bottom: 15px;",
                             
                             h3 {
                                 style: "font-size: 16px; margin-bottom: 5px; color: #2c3e50;",
-                                "Text (max 3 characters)"
+                                "Font"
                             }
                             
-                            input {
-                                style: "width: 100%; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px;",
-                                placeholder: "Enter text (max 3 chars)...",
-                                value: "{text.read()}",
-                                maxlength: "3",
-                                oninput: move |event| {
-                                    let new_text = event.value();
-                                    if new_text.len() <= 3 {
-                                        text.set(new_text);
+                            div {
+                                style: "display: flex; gap: 10px;",
+                                
+                                select {
+                                    style: "flex: 1; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; background-color: white;",
+                                    onchange: move |event| {
+                                        let selected_family = FontFamily::ALL
+                                            .into_iter()
+                                            .find(|family| family.to_string() == event.value())
+                                            .unwrap_or(FontFamily::SansSerif);
+                                        font_family.set(selected_family);
                                         generate_preview();
-                                        status.set(None);
+                                    },
+                                    
+                                    for family in FontFamily::ALL {
+                                        option {
+                                            value: "{family}",
+                                            selected: font_family() == family,
+                                            "{family}"
+                                        }
+                                    }
+                                }
+                                
+                                select {
+                                    style: "flex: 1; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; background-color: white;",
+                                    onchange: move |event| {
+                                        let selected_weight = FontWeight::ALL
+                                            .into_iter()
+                                            .find(|weight| weight.to_string() == event.value())
+                                            .unwrap_or(FontWeight::Bold);
+                                        font_weight.set(selected_weight);
+                                        generate_preview();
+                                    },
+                                    
+                                    for weight in FontWeight::ALL {
+                                        option {
+                                            value: "{weight}",
+                                            selected: font_weight() == weight,
+                                            "{weight}"
+                                        }
                                     }
                                 }
                             }
@@ -225,24 +439,45 @@ pub fn IconToolView() -> Element {
                             select {
                                 style: "width: 100%; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; background-color: white;",
                                 onchange: move |event| {
-                                    let selected_shape = match event.value().as_str() {
-                                        "Circle" => IconShape::Circle,
-                                        "Square" => IconShape::Square,
-                                        _ => IconShape::Circle,
-                                    };
+                                    let selected_shape = IconShape::ALL
+                                        .into_iter()
+                                        .find(|shape| shape.to_string() == event.value())
+                                        .unwrap_or(IconShape::Circle);
                                     shape.set(selected_shape);
                                     generate_preview();
                                 },
                                 
-                                option {
-                                    value: "Circle",
-                                    selected: matches!(shape(), IconShape::Circle),
-                                    "Circle"
+                                for option_shape in IconShape::ALL {
+                                    option {
+                                        value: "{option_shape}",
+                                        selected: shape() == option_shape,
+                                        "{option_shape}"
+                                    }
                                 }
-                                option {
-                                    value: "Square",
-                                    selected: matches!(shape(), IconShape::Square),
-                                    "Square"
+                            }
+                        }
+                        
+                        if shape() == IconShape::RoundedSquare {
+                            div {
+                                style: "margin-bottom: 15px;",
+                                
+                                h3 {
+                                    style: "font-size: 16px; margin-bottom: 5px; color: #2c3e50;",
+                                    "Corner Radius ({corner_radius()}%)"
+                                }
+                                
+                                input {
+                                    r#type: "range",
+                                    style: "width: 100%;",
+                                    min: "0",
+                                    max: "{IconLogic::MAX_CORNER_RADIUS}",
+                                    value: "{corner_radius()}",
+                                    oninput: move |event| {
+                                        if let Ok(radius) = event.value().parse::<u32>() {
+                                            corner_radius.set(radius);
+                                            generate_preview();
+                                        }
+                                    }
                                 }
                             }
                         }
@@ -264,7 +499,7 @@ pub fn IconToolView() -> Element {
                                 max: "512",
                                 oninput: move |event| {
                                     if let Ok(new_size) = event.value().parse::<u32>() {
-                                        if new_size >= 16 && new_size <= 512 {
+                                        if (IconLogic::MIN_SIZE..=IconLogic::MAX_SIZE).contains(&new_size) {
                                             size.set(new_size);
                                             generate_preview();
                                         }
@@ -273,13 +508,123 @@ pub fn IconToolView() -> Element {
                             }
                         }
                         
-                        // Background color
+                        // The identicon palette picks the background
+                        if mode() == IconMode::Text {
+                            // Background color
+                            div {
+                                style: "margin-bottom: 15px;",
+                            
+                                h3 {
+                                    style: "font-size: 16px; margin-bottom: 5px; color: #2c3e50;",
+                                    "Background Color"
+                                }
+                            
+                                div {
+                                    style: "display: flex; gap: 10px; align-items: center;",
+                                
+                                    input {
+                                        r#type: "color",
+                                        style: "width: 50px; height: 40px; border: 1px solid #bdc3c7; border-radius: 4px; cursor: pointer;",
+                                        value: "{background_color.read()}",
+                                        oninput: move |event| {
+                                            background_color.set(event.value());
+                                            generate_preview();
+                                        }
+                                    }
+                                
+                                    input {
+                                        r#type: "text",
+                                        style: "flex: 1; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px;",
+                                        value: "{background_color.read()}",
+                                        placeholder: "#3498db",
+                                        oninput: move |event| {
+                                            let value = event.value();
+                                            if value.starts_with('#') && (value.len() == 7 || value.len() == 4) {
+                                                background_color.set(value);
+                                                generate_preview();
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        
+                            // Background fill
+                            div {
+                                style: "margin-bottom: 15px;",
+                            
+                                h3 {
+                                    style: "font-size: 16px; margin-bottom: 5px; color: #2c3e50;",
+                                    "Background Fill"
+                                }
+                            
+                                select {
+                                    style: "width: 100%; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; background-color: white;",
+                                    onchange: move |event| {
+                                        let selected_fill = FillStyle::ALL
+                                            .into_iter()
+                                            .find(|style| style.to_string() == event.value())
+                                            .unwrap_or(FillStyle::Solid);
+                                        fill.set(selected_fill);
+                                        generate_preview();
+                                    },
+                                
+                                    for style in FillStyle::ALL {
+                                        option {
+                                            value: "{style}",
+                                            selected: fill() == style,
+                                            "{style}"
+                                        }
+                                    }
+                                }
+                            
+                                if fill() != FillStyle::Solid {
+                                    div {
+                                        style: "display: flex; gap: 10px; align-items: center; margin-top: 10px;",
+                                    
+                                        input {
+                                            r#type: "color",
+                                            style: "width: 50px; height: 40px; border: 1px solid #bdc3c7; border-radius: 4px; cursor: pointer;",
+                                            title: "Gradient end color",
+                                            value: "{gradient_color.read()}",
+                                            oninput: move |event| {
+                                                gradient_color.set(event.value());
+                                                generate_preview();
+                                            }
+                                        }
+                                    
+                                        if fill() == FillStyle::LinearGradient {
+                                            label {
+                                                style: "font-size: 14px; color: #2c3e50;",
+                                                "Angle {gradient_angle()}°"
+                                            }
+                                        
+                                            input {
+                                                r#type: "range",
+                                                style: "flex: 1;",
+                                                min: "0",
+                                                max: "359",
+                                                value: "{gradient_angle()}",
+                                                oninput: move |event| {
+                                                    if let Ok(angle) = event.value().parse::<u32>() {
+                                                        gradient_angle.set(angle);
+                                                        generate_preview();
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        
+                        }
+                        
+                        // Text color
                         div {
                             style: "margin-bottom: 15px;",
                             
                             h3 {
                                 style: "font-size: 16px; margin-bottom: 5px; color: #2c3e50;",
-                                "Background Color"
+                                "Text Color"
                             }
                             
                             div {
@@ -288,9 +633,9 @@ pub fn IconToolView() -> Element {
                                 input {
                                     r#type: "color",
                                     style: "width: 50px; height: 40px; border: 1px solid #bdc3c7; border-radius: 4px; cursor: pointer;",
-                                    value: "{background_color.read()}",
+                                    value: "{text_color.read()}",
                                     oninput: move |event| {
-                                        background_color.set(event.value());
+                                        text_color.set(event.value());
                                         generate_preview();
                                     }
                                 }
@@ -298,12 +643,12 @@ pub fn IconToolView() -> Element {
                                 input {
                                     r#type: "text",
                                     style: "flex: 1; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px;",
-                                    value: "{background_color.read()}",
-                                    placeholder: "#3498db",
+                                    value: "{text_color.read()}",
+                                    placeholder: "#ffffff",
                                     oninput: move |event| {
                                         let value = event.value();
                                         if value.starts_with('#') && (value.len() == 7 || value.len() == 4) {
-                                            background_color.set(value);
+                                            text_color.set(value);
                                             generate_preview();
                                         }
                                     }
@@ -311,13 +656,13 @@ pub fn IconToolView() -> Element {
                             }
                         }
                         
-                        // Text color
+                        // Outline and shadow
                         div {
                             style: "margin-bottom: 15px;",
                             
                             h3 {
                                 style: "font-size: 16px; margin-bottom: 5px; color: #2c3e50;",
-                                "Text Color"
+                                "Outline"
                             }
                             
                             div {
@@ -326,27 +671,44 @@ pub fn IconToolView() -> Element {
                                 input {
                                     r#type: "color",
                                     style: "width: 50px; height: 40px; border: 1px solid #bdc3c7; border-radius: 4px; cursor: pointer;",
-                                    value: "{text_color.read()}",
+                                    value: "{border_color.read()}",
                                     oninput: move |event| {
-                                        text_color.set(event.value());
+                                        border_color.set(event.value());
                                         generate_preview();
                                     }
                                 }
                                 
                                 input {
-                                    r#type: "text",
+                                    r#type: "number",
                                     style: "flex: 1; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px;",
-                                    value: "{text_color.read()}",
-                                    placeholder: "#ffffff",
+                                    title: "Outline width in pixels (0 for none)",
+                                    min: "0",
+                                    max: "{IconLogic::MAX_BORDER_WIDTH}",
+                                    value: "{border_width()}",
                                     oninput: move |event| {
-                                        let value = event.value();
-                                        if value.starts_with('#') && (value.len() == 7 || value.len() == 4) {
-                                            text_color.set(value);
+                                        if let Ok(width) = event.value().parse::<u32>()
+                                            && width <= IconLogic::MAX_BORDER_WIDTH
+                                        {
+                                            border_width.set(width);
                                             generate_preview();
                                         }
                                     }
                                 }
                             }
+                            
+                            label {
+                                style: "display: flex; gap: 8px; align-items: center; margin-top: 10px; font-size: 14px; color: #2c3e50; cursor: pointer;",
+                                
+                                input {
+                                    r#type: "checkbox",
+                                    checked: shadow(),
+                                    onchange: move |event| {
+                                        shadow.set(event.checked());
+                                        generate_preview();
+                                    }
+                                }
+                                "Drop shadow"
+                            }
                         }
                         
                         // Export format
@@ -361,23 +723,19 @@ pub fn IconToolView() -> Element {
                             select {
                                 style: "width: 100%; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; background-color: white;",
                                 onchange: move |event| {
-                                    let selected_format = match event.value().as_str() {
-                                        "PNG" => ExportFormat::Png,
-                                        "ICO" => ExportFormat::Ico,
-                                        _ => ExportFormat::Png,
-                                    };
+                                    let selected_format = ExportFormat::ALL
+                                        .into_iter()
+                                        .find(|format| format.to_string() == event.value())
+                                        .unwrap_or(ExportFormat::Png);
                                     export_format.set(selected_format);
                                 },
                                 
-                                option {
-                                    value: "PNG",
-                                    selected: matches!(export_format(), ExportFormat::Png),
-                                    "PNG"
-                                }
-                                option {
-                                    value: "ICO",
-                                    selected: matches!(export_format(), ExportFormat::Ico),
-                                    "ICO"
+                                for format in ExportFormat::ALL {
+                                    option {
+                                        value: "{format}",
+                                        selected: export_format() == format,
+                                        "{format}"
+                                    }
                                 }
                             }
                         }
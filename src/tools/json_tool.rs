@@ -0,0 +1,1235 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use arboard::Clipboard;
+use rfd::AsyncFileDialog;
+use crate::logic::base64_logic::Base64Logic;
+use std::sync::Arc;
+use serde_json::Value;
+use crate::logic::codegen_logic::{CodeLanguage, CodegenLogic};
+use crate::logic::json_diff_logic::{DiffEntry, DiffKind, DiffOptions, JsonDiffLogic};
+use crate::logic::json_logic::{ErrorLine, JsonError, JsonIndent, JsonLogic};
+use crate::logic::json_path_logic::{JsonPathError, JsonPathLogic, PathSegment};
+use crate::logic::json_schema_logic::{JsonSchemaLogic, SchemaError, Violation};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JsonMode {
+    #[default]
+    Format,
+    Tree,
+    Diff,
+    Schema,
+    Code,
+}
+
+impl std::fmt::Display for JsonMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JsonMode::Format => write!(f, "Format"),
+            JsonMode::Tree => write!(f, "Tree & JSONPath"),
+            JsonMode::Diff => write!(f, "Diff"),
+            JsonMode::Schema => write!(f, "Schema"),
+            JsonMode::Code => write!(f, "Code"),
+        }
+    }
+}
+
+impl JsonMode {
+    pub const ALL: [JsonMode; 5] = [JsonMode::Format, JsonMode::Tree, JsonMode::Diff, JsonMode::Schema, JsonMode::Code];
+}
+
+/// Operations that run on a blocking task, since multi-megabyte documents take a while
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JsonAction {
+    Format,
+    Minify,
+    Validate,
+}
+
+/// A syntax error along with the source lines to highlight
+#[derive(Debug, Clone, PartialEq)]
+struct SyntaxError {
+    line: usize,
+    column: usize,
+    message: String,
+    excerpt: Vec<ErrorLine>,
+}
+
+/// Parsed document shared by tree nodes, compared by pointer so re-renders stay cheap
+#[derive(Clone)]
+struct SharedDocument(Arc<Value>);
+
+impl PartialEq for SharedDocument {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Children rendered per expanded node before a "show more" row
+const CHILD_PAGE_SIZE: usize = 100;
+/// Query matches listed at once
+const MATCH_LIMIT: usize = 500;
+/// Diff entries listed at once
+const DIFF_LIMIT: usize = 1000;
+/// Schema violations listed at once
+const VIOLATION_LIMIT: usize = 1000;
+
+pub struct JsonTool;
+
+impl JsonTool {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn view(&self) -> Element {
+        rsx! { JsonToolView {} }
+    }
+}
+
+#[component]
+pub fn JsonToolView() -> Element {
+    let mut mode = use_signal(JsonMode::default);
+    let mut input = use_signal(String::new);
+    let mut output = use_signal(String::new);
+    let mut indent = use_signal(JsonIndent::default);
+    let mut sort_keys = use_signal(|| false);
+    let mut busy = use_signal(|| false);
+    let mut syntax_error = use_signal(|| None::<SyntaxError>);
+    let mut status = use_signal(|| None::<String>);
+
+    let run = move |action: JsonAction| async move {
+        let text = input.read().clone();
+        let (selected_indent, sorted) = (indent(), sort_keys());
+        busy.set(true);
+        status.set(None);
+        let job = tokio::task::spawn_blocking(move || {
+            let mut value = match JsonLogic::parse(&text) {
+                Ok(value) => value,
+                Err(JsonError::Syntax { line, column, message }) => {
+                    let excerpt = JsonLogic::error_excerpt(&text, line);
+                    return Err(SyntaxError { line, column, message, excerpt });
+                }
+            };
+            let summary = format!("{} · {}", JsonLogic::describe(&value), Base64Logic::format_size(text.len() as u64));
+            if sorted {
+                JsonLogic::sort_keys(&mut value);
+            }
+            let formatted = match action {
+                JsonAction::Format => Some(JsonLogic::to_pretty(&value, selected_indent)),
+                JsonAction::Minify => Some(JsonLogic::to_minified(&value)),
+                JsonAction::Validate => None,
+            };
+            Ok((formatted, summary))
+        })
+        .await;
+        busy.set(false);
+
+        match job {
+            Ok(Ok((formatted, summary))) => {
+                syntax_error.set(None);
+                if let Some(formatted) = formatted {
+                    output.set(formatted);
+                }
+                let verb = match action {
+                    JsonAction::Format => "Formatted",
+                    JsonAction::Minify => "Minified",
+                    JsonAction::Validate => "Valid JSON",
+                };
+                status.set(Some(format!("{} · {}", verb, summary)));
+            }
+            Ok(Err(err)) => syntax_error.set(Some(err)),
+            Err(err) => status.set(Some(format!("JSON task failed: {}", err))),
+        }
+    };
+
+    let open_file = move |_| async move {
+        let Some(handle) = AsyncFileDialog::new().add_filter("JSON", &["json"]).pick_file().await else {
+            return;
+        };
+        let bytes = handle.read().await;
+        input.set(String::from_utf8_lossy(&bytes).into_owned());
+        output.set(String::new());
+        syntax_error.set(None);
+        status.set(Some(format!("Loaded {} ({})", handle.file_name(), Base64Logic::format_size(bytes.len() as u64))));
+    };
+
+    let copy_output = move |_| {
+        if let Ok(mut clipboard) = Clipboard::new() {
+            let _ = clipboard.set_text(output.read().clone());
+            status.set(Some("Copied to clipboard".to_string()));
+        }
+    };
+
+    let clear = move |_| {
+        input.set(String::new());
+        output.set(String::new());
+        syntax_error.set(None);
+        status.set(None);
+    };
+
+    rsx! {
+        div {
+            style: "padding: 20px; height: 100%; display: flex; flex-direction: column; box-sizing: border-box; overflow: hidden;",
+
+            h1 {
+                style: "font-size: 24px; margin-bottom: 15px; color: #2c3e50; margin-top: 0; flex-shrink: 0;",
+                "JSON Tools"
+            }
+
+            // Mode tabs
+            div {
+                style: "display: flex; gap: 10px; margin-bottom: 15px; flex-shrink: 0;",
+
+                for option_mode in JsonMode::ALL {
+                    button {
+                        style: if mode() == option_mode {
+                            "padding: 8px 16px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;"
+                        } else {
+                            "padding: 8px 16px; background-color: #ecf0f1; color: #2c3e50; border: 1px solid #bdc3c7; border-radius: 4px; cursor: pointer; font-size: 14px;"
+                        },
+                        onclick: move |_| {
+                            mode.set(option_mode);
+                            syntax_error.set(None);
+                            status.set(None);
+                        },
+                        "{option_mode}"
+                    }
+                }
+            }
+
+            // Controls
+            div {
+                style: "margin-bottom: 15px; display: flex; gap: 10px; align-items: center; flex-shrink: 0;",
+
+                button {
+                    style: "padding: 8px 16px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                    onclick: open_file,
+                    "Open File"
+                }
+
+                if mode() == JsonMode::Format {
+                    select {
+                        style: "padding: 8px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; background-color: white;",
+                        onchange: move |event| {
+                            let selected = JsonIndent::ALL
+                                .into_iter()
+                                .find(|option| option.to_string() == event.value())
+                                .unwrap_or(JsonIndent::TwoSpaces);
+                            indent.set(selected);
+                        },
+
+                        for option_indent in JsonIndent::ALL {
+                            option {
+                                value: "{option_indent}",
+                                selected: indent() == option_indent,
+                                "{option_indent}"
+                            }
+                        }
+                    }
+
+                    label {
+                        style: "display: flex; gap: 6px; align-items: center; font-size: 14px; color: #2c3e50; cursor: pointer;",
+
+                        input {
+                            r#type: "checkbox",
+                            checked: sort_keys(),
+                            onchange: move |event| sort_keys.set(event.checked())
+                        }
+                        "Sort keys"
+                    }
+
+                    button {
+                        style: "padding: 8px 16px; background-color: #3498db; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                        disabled: busy(),
+                        onclick: move |_| run(JsonAction::Format),
+                        "Format"
+                    }
+
+                    button {
+                        style: "padding: 8px 16px; background-color: #8e44ad; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                        disabled: busy(),
+                        onclick: move |_| run(JsonAction::Minify),
+                        "Minify"
+                    }
+
+                    button {
+                        style: "padding: 8px 16px; background-color: #27ae60; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                        disabled: busy(),
+                        onclick: move |_| run(JsonAction::Validate),
+                        "Validate"
+                    }
+                }
+
+                button {
+                    style: "padding: 8px 16px; background-color: #95a5a6; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                    onclick: clear,
+                    "Clear"
+                }
+
+                if busy() {
+                    span {
+                        style: "font-size: 14px; color: #7f8c8d;",
+                        "Working..."
+                    }
+                }
+            }
+
+            if mode() == JsonMode::Diff {
+                DiffPanel { left: input, status }
+            } else {
+                div {
+                    style: "flex: 1; display: flex; gap: 20px; min-height: 0;",
+
+                    // Input section
+                    div {
+                        style: "flex: 1; display: flex; flex-direction: column; min-height: 0;",
+
+                        h3 {
+                            style: "font-size: 16px; margin-bottom: 5px; color: #2c3e50; margin-top: 0;",
+                            "Input"
+                        }
+
+                        textarea {
+                            style: "flex: 1; width: 100%; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 13px; font-family: monospace; resize: none; box-sizing: border-box; white-space: pre; overflow-wrap: normal;",
+                            placeholder: "Paste JSON here...",
+                            spellcheck: false,
+                            value: "{input.read()}",
+                            oninput: move |event| input.set(event.value())
+                        }
+                    }
+
+                    if mode() == JsonMode::Format {
+                        // Output section
+                        div {
+                            style: "flex: 1; display: flex; flex-direction: column; min-height: 0;",
+
+                            div {
+                                style: "display: flex; align-items: center; gap: 10px; margin-bottom: 5px;",
+
+                                h3 {
+                                    style: "font-size: 16px; color: #2c3e50; margin: 0;",
+                                    "Output"
+                                }
+
+                                if !output.read().is_empty() {
+                                    button {
+                                        style: "padding: 4px 8px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                                        onclick: copy_output,
+                                        "📋 Copy"
+                                    }
+                                }
+                            }
+
+                            textarea {
+                                style: "flex: 1; width: 100%; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 13px; font-family: monospace; resize: none; box-sizing: border-box; background-color: #f8f9fa; white-space: pre; overflow-wrap: normal;",
+                                readonly: true,
+                                value: "{output.read()}"
+                            }
+                        }
+                    } else if mode() == JsonMode::Schema {
+                        SchemaPanel { input, status }
+                    } else if mode() == JsonMode::Code {
+                        CodePanel { input, status }
+                    } else {
+                        TreePanel { input, status }
+                    }
+                }
+            }
+
+            if let Some(message) = status.read().as_ref() {
+                div {
+                    style: "margin-top: 10px; padding: 10px; background-color: #e8f5e8; border: 1px solid #4caf50; border-radius: 4px; color: #2e7d32; font-size: 14px; flex-shrink: 0;",
+                    "{message}"
+                }
+            }
+
+            // Syntax error with the offending line highlighted
+            if let Some(err) = syntax_error.read().as_ref() {
+                div {
+                    style: "margin-top: 10px; padding: 10px; background-color: #ffebee; border: 1px solid #f44336; border-radius: 4px; color: #c62828; font-size: 14px; flex-shrink: 0;",
+
+                    div {
+                        style: "margin-bottom: 8px; font-weight: 500;",
+                        "Line {err.line}, column {err.column}: {err.message}"
+                    }
+
+                    div {
+                        style: "font-family: monospace; font-size: 13px; background-color: white; border: 1px solid #ef9a9a; border-radius: 4px; padding: 6px 0; overflow-x: auto;",
+
+                        for line in err.excerpt.iter() {
+                            div {
+                                key: "{line.number}",
+                                style: if line.is_error {
+                                    "display: flex; background-color: #ffcdd2; color: #b71c1c;"
+                                } else {
+                                    "display: flex; color: #7f8c8d;"
+                                },
+
+                                span {
+                                    style: "width: 50px; flex-shrink: 0; padding-right: 10px; text-align: right; user-select: none;",
+                                    "{line.number}"
+                                }
+                                span {
+                                    style: "white-space: pre;",
+                                    "{line.text}"
+                                }
+                            }
+
+                            if line.is_error {
+                                div {
+                                    style: "display: flex; color: #b71c1c; font-weight: bold;",
+                                    span { style: "width: 50px; flex-shrink: 0; padding-right: 10px;" }
+                                    span {
+                                        style: "white-space: pre;",
+                                        "{caret(&line.text, err.column)}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Caret under a 1-based byte column, as serde_json reports it, keeping tabs so it lines up with the source line
+fn caret(text: &str, column: usize) -> String {
+    let padding: String = text
+        .char_indices()
+        .take_while(|(index, _)| *index < column.saturating_sub(1))
+        .map(|(_, c)| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+    format!("{}^", padding)
+}
+
+#[component]
+fn TreePanel(input: Signal<String>, status: Signal<Option<String>>) -> Element {
+    let mut query = use_signal(String::new);
+
+    // Parsing and querying run on blocking tasks and restart whenever their inputs change
+    let document = use_resource(move || async move {
+        let text = input();
+        if text.trim().is_empty() {
+            return None;
+        }
+        tokio::task::spawn_blocking(move || JsonLogic::parse(&text).map(|value| SharedDocument(Arc::new(value))))
+            .await
+            .ok()
+    });
+    let matches = use_resource(move || async move {
+        let path = query();
+        let root = match &*document.read() {
+            Some(Some(Ok(root))) => root.clone(),
+            _ => return None,
+        };
+        if path.trim().is_empty() {
+            return None;
+        }
+        tokio::task::spawn_blocking(move || JsonPathLogic::query(&root.0, &path)).await.ok()
+    });
+
+    let mut copy_path = move |path: String| {
+        if let Ok(mut clipboard) = Clipboard::new() {
+            let _ = clipboard.set_text(path.clone());
+            status.set(Some(format!("Copied {}", path)));
+        }
+    };
+
+    // Matches hold pointers into the shared document, so reading them in place avoids copying the list
+    let document_state = document.read().clone();
+    let match_state = matches.read();
+
+    rsx! {
+        div {
+            style: "flex: 1; display: flex; flex-direction: column; min-height: 0;",
+
+            div {
+                style: "display: flex; align-items: center; gap: 10px; margin-bottom: 5px;",
+
+                h3 {
+                    style: "font-size: 16px; color: #2c3e50; margin: 0;",
+                    "JSONPath"
+                }
+
+                match &*match_state {
+                    Some(Some(Ok(found))) => rsx! {
+                        span {
+                            style: "font-size: 13px; color: #7f8c8d;",
+                            "{found.len()} matches"
+                        }
+                    },
+                    _ => rsx! {},
+                }
+            }
+
+            input {
+                style: "width: 100%; margin-bottom: 10px; padding: 8px 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; font-family: monospace; box-sizing: border-box;",
+                placeholder: "$.store.book[?(@.price < 10)].title",
+                spellcheck: false,
+                value: "{query.read()}",
+                oninput: move |event| query.set(event.value())
+            }
+
+            div {
+                style: "flex: 1; overflow: auto; min-height: 0; padding: 8px; border: 1px solid #bdc3c7; border-radius: 4px; background-color: #f8f9fa; font-family: monospace; font-size: 13px;",
+
+                match (&document_state, &*match_state) {
+                    (None, _) => rsx! {
+                        div { style: "color: #7f8c8d;", "Paste or open a JSON document to browse it." }
+                    },
+                    (Some(None), _) => rsx! {
+                        div { style: "color: #7f8c8d;", "Parsing..." }
+                    },
+                    (Some(Some(Err(JsonError::Syntax { line, column, message }))), _) => rsx! {
+                        div { style: "color: #c62828;", "Line {line}, column {column}: {message}" }
+                    },
+                    (_, Some(Some(Err(JsonPathError::Syntax { position, message })))) => rsx! {
+                        div { style: "color: #c62828;", "JSONPath error at character {position}: {message}" }
+                    },
+                    (Some(Some(Ok(root))), Some(Some(Ok(found)))) => rsx! {
+                        for (index, found) in found.iter().take(MATCH_LIMIT).enumerate() {
+                            div {
+                                key: "{index}",
+                                style: "display: flex; gap: 10px; padding: 4px 0; border-bottom: 1px solid #ecf0f1;",
+
+                                span {
+                                    style: "flex-shrink: 0; color: #2980b9; cursor: pointer;",
+                                    title: "Copy path",
+                                    onclick: {
+                                        let path = found.path.clone();
+                                        move |_| copy_path(path.clone())
+                                    },
+                                    "{found.path}"
+                                }
+                                span {
+                                    style: "color: #2c3e50; overflow: hidden; text-overflow: ellipsis; white-space: nowrap;",
+                                    {root.0.pointer(&found.pointer).map(|value| preview(value, 200)).unwrap_or_default()}
+                                }
+                            }
+                        }
+                        if found.len() > MATCH_LIMIT {
+                            div {
+                                style: "padding-top: 6px; color: #7f8c8d;",
+                                "Showing the first {MATCH_LIMIT} of {found.len()} matches"
+                            }
+                        }
+                    },
+                    (Some(Some(Ok(root))), _) => rsx! {
+                        JsonNode {
+                            document: root.clone(),
+                            pointer: String::new(),
+                            path: "$".to_string(),
+                            label: None,
+                            depth: 0,
+                            on_copy: copy_path,
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn JsonNode(
+    document: SharedDocument,
+    pointer: String,
+    path: String,
+    label: Option<String>,
+    depth: usize,
+    on_copy: EventHandler<String>,
+) -> Element {
+    let mut expanded = use_signal(|| depth < 2);
+    let mut limit = use_signal(|| CHILD_PAGE_SIZE);
+    let Some(value) = document.0.pointer(&pointer) else {
+        return rsx! {};
+    };
+
+    let type_name = JsonLogic::type_name(value);
+    let badge_color = match value {
+        Value::Object(_) => "#8e44ad",
+        Value::Array(_) => "#2980b9",
+        Value::String(_) => "#27ae60",
+        Value::Number(_) => "#e67e22",
+        Value::Bool(_) => "#c0392b",
+        Value::Null => "#7f8c8d",
+    };
+    let children: Vec<PathSegment> = match value {
+        Value::Object(map) => map.keys().map(|key| PathSegment::Key(key.clone())).collect(),
+        Value::Array(items) => (0..items.len()).map(PathSegment::Index).collect(),
+        _ => Vec::new(),
+    };
+    let summary = match value {
+        Value::Object(map) => format!("{{{}}}", map.len()),
+        Value::Array(items) => format!("[{}]", items.len()),
+        scalar => preview(scalar, 120),
+    };
+    let is_container = value.is_object() || value.is_array();
+    let copied_path = path.clone();
+
+    rsx! {
+        div {
+            div {
+                style: "display: flex; align-items: center; gap: 6px; padding: 2px 0; white-space: nowrap;",
+
+                span {
+                    style: "width: 14px; flex-shrink: 0; color: #7f8c8d; cursor: pointer; user-select: none;",
+                    onclick: move |_| expanded.toggle(),
+                    if is_container {
+                        if expanded() { "▾" } else { "▸" }
+                    }
+                }
+
+                if let Some(label) = label {
+                    span { style: "color: #2c3e50; font-weight: 600;", "{label}:" }
+                }
+
+                span {
+                    style: "padding: 0 5px; border-radius: 3px; background-color: {badge_color}; color: white; font-size: 11px;",
+                    "{type_name}"
+                }
+
+                span {
+                    style: if is_container { "color: #7f8c8d;" } else { "color: #2c3e50; overflow: hidden; text-overflow: ellipsis;" },
+                    "{summary}"
+                }
+
+                button {
+                    style: "padding: 0 6px; background: none; border: 1px solid #bdc3c7; border-radius: 3px; color: #7f8c8d; cursor: pointer; font-size: 11px;",
+                    title: "{path}",
+                    onclick: move |_| on_copy.call(copied_path.clone()),
+                    "copy path"
+                }
+            }
+
+            if is_container && expanded() {
+                div {
+                    style: "margin-left: 10px; padding-left: 10px; border-left: 1px dashed #d5dbdb;",
+
+                    for segment in children.iter().take(limit()).cloned() {
+                        JsonNode {
+                            key: "{JsonPathLogic::pointer(std::slice::from_ref(&segment))}",
+                            document: document.clone(),
+                            pointer: format!("{}{}", pointer, JsonPathLogic::pointer(std::slice::from_ref(&segment))),
+                            path: JsonPathLogic::child_path(&path, &segment),
+                            label: match &segment {
+                                PathSegment::Key(key) => key.clone(),
+                                PathSegment::Index(index) => index.to_string(),
+                            },
+                            depth: depth + 1,
+                            on_copy,
+                        }
+                    }
+
+                    if children.len() > limit() {
+                        button {
+                            style: "margin: 4px 0; padding: 2px 8px; background: none; border: 1px solid #bdc3c7; border-radius: 3px; color: #2980b9; cursor: pointer; font-size: 12px;",
+                            onclick: move |_| limit += CHILD_PAGE_SIZE,
+                            "Show {(children.len() - limit()).min(CHILD_PAGE_SIZE)} more of {children.len() - limit()}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn CodePanel(input: Signal<String>, status: Signal<Option<String>>) -> Element {
+    let mut language = use_signal(CodeLanguage::default);
+    let mut root_name = use_signal(|| "Root".to_string());
+
+    // Every document in the input is a sample; fields missing from some of them become optional
+    let generated = use_resource(move || async move {
+        let (text, selected_language, name) = (input(), language(), root_name());
+        if text.trim().is_empty() {
+            return None;
+        }
+        tokio::task::spawn_blocking(move || {
+            JsonLogic::parse_many(&text).map(|samples| CodegenLogic::generate(&samples, &name, selected_language))
+        })
+        .await
+        .ok()
+    });
+
+    let copy_code = move |_| {
+        let Some(Some(Ok(code))) = generated.read().clone() else {
+            return;
+        };
+        if let Ok(mut clipboard) = Clipboard::new() {
+            let _ = clipboard.set_text(code);
+            status.set(Some("Copied to clipboard".to_string()));
+        }
+    };
+
+    let generated_state = generated.read().clone();
+
+    rsx! {
+        div {
+            style: "flex: 1; display: flex; flex-direction: column; min-height: 0;",
+
+            div {
+                style: "display: flex; align-items: center; gap: 10px; margin-bottom: 5px;",
+
+                h3 {
+                    style: "font-size: 16px; color: #2c3e50; margin: 0;",
+                    "Types"
+                }
+
+                select {
+                    style: "padding: 4px 8px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 13px; background-color: white;",
+                    onchange: move |event| {
+                        let selected = CodeLanguage::ALL
+                            .into_iter()
+                            .find(|option| option.to_string() == event.value())
+                            .unwrap_or_default();
+                        language.set(selected);
+                    },
+
+                    for option_language in CodeLanguage::ALL {
+                        option {
+                            value: "{option_language}",
+                            selected: language() == option_language,
+                            "{option_language}"
+                        }
+                    }
+                }
+
+                input {
+                    style: "width: 140px; padding: 4px 8px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 13px; font-family: monospace;",
+                    title: "Name of the top-level type",
+                    placeholder: "Root",
+                    spellcheck: false,
+                    value: "{root_name.read()}",
+                    oninput: move |event| root_name.set(event.value())
+                }
+
+                if matches!(generated_state, Some(Some(Ok(_)))) {
+                    button {
+                        style: "padding: 4px 8px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                        onclick: copy_code,
+                        "📋 Copy"
+                    }
+                }
+            }
+
+            match &generated_state {
+                Some(Some(Err(JsonError::Syntax { line, column, message }))) => rsx! {
+                    div {
+                        style: "flex: 1; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; background-color: #f8f9fa; font-family: monospace; font-size: 13px; color: #c62828;",
+                        "Line {line}, column {column}: {message}"
+                    }
+                },
+                _ => rsx! {
+                    textarea {
+                        style: "flex: 1; width: 100%; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 13px; font-family: monospace; resize: none; box-sizing: border-box; background-color: #f8f9fa; white-space: pre; overflow-wrap: normal;",
+                        readonly: true,
+                        placeholder: "Paste one or more JSON samples to generate Rust, TypeScript or Go types...",
+                        value: match &generated_state {
+                            Some(Some(Ok(code))) => code.clone(),
+                            _ => String::new(),
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// Outcome of validating every input document against the schema
+#[derive(Clone)]
+enum ValidationState {
+    SchemaSyntax(JsonError),
+    InputSyntax(JsonError),
+    InvalidSchema(SchemaError),
+    /// Violations tagged with the 1-based document they came from
+    Done { documents: usize, violations: Vec<(usize, Violation)> },
+}
+
+#[component]
+fn SchemaPanel(input: Signal<String>, status: Signal<Option<String>>) -> Element {
+    let mut schema = use_signal(String::new);
+    let mut infer_error = use_signal(|| None::<String>);
+
+    // Input may hold several whitespace-separated documents, each validated on its own
+    let validation = use_resource(move || async move {
+        let (schema_text, input_text) = (schema(), input());
+        if schema_text.trim().is_empty() || input_text.trim().is_empty() {
+            return None;
+        }
+        tokio::task::spawn_blocking(move || {
+            let schema_value = match JsonLogic::parse(&schema_text) {
+                Ok(value) => value,
+                Err(err) => return ValidationState::SchemaSyntax(err),
+            };
+            let compiled = match JsonSchemaLogic::compile(&schema_value) {
+                Ok(compiled) => compiled,
+                Err(err) => return ValidationState::InvalidSchema(err),
+            };
+            let documents = match JsonLogic::parse_many(&input_text) {
+                Ok(documents) => documents,
+                Err(err) => return ValidationState::InputSyntax(err),
+            };
+            let violations: Vec<(usize, Violation)> = documents
+                .iter()
+                .enumerate()
+                .flat_map(|(index, document)| {
+                    JsonSchemaLogic::validate(&compiled, document).into_iter().map(move |violation| (index + 1, violation))
+                })
+                .collect();
+            ValidationState::Done { documents: documents.len(), violations }
+        })
+        .await
+        .ok()
+    });
+
+    let infer = move |_| async move {
+        let text = input.read().clone();
+        let job = tokio::task::spawn_blocking(move || {
+            JsonLogic::parse_many(&text).map(|samples| (samples.len(), JsonSchemaLogic::infer(&samples)))
+        })
+        .await;
+        match job {
+            Ok(Ok((0, _))) => infer_error.set(Some("Paste or open one or more sample documents first".to_string())),
+            Ok(Ok((count, inferred))) => {
+                infer_error.set(None);
+                schema.set(JsonLogic::to_pretty(&inferred, JsonIndent::TwoSpaces));
+                status.set(Some(format!("Inferred schema from {} sample{}", count, if count == 1 { "" } else { "s" })));
+            }
+            Ok(Err(JsonError::Syntax { line, column, message })) => {
+                infer_error.set(Some(format!("Input, line {}, column {}: {}", line, column, message)));
+            }
+            Err(err) => infer_error.set(Some(format!("Schema task failed: {}", err))),
+        }
+    };
+
+    let open_schema = move |_| async move {
+        let Some(handle) = AsyncFileDialog::new().add_filter("JSON Schema", &["json"]).pick_file().await else {
+            return;
+        };
+        let bytes = handle.read().await;
+        schema.set(String::from_utf8_lossy(&bytes).into_owned());
+        infer_error.set(None);
+        status.set(Some(format!("Loaded {} ({})", handle.file_name(), Base64Logic::format_size(bytes.len() as u64))));
+    };
+
+    let copy_schema = move |_| {
+        if let Ok(mut clipboard) = Clipboard::new() {
+            let _ = clipboard.set_text(schema.read().clone());
+            status.set(Some("Copied schema to clipboard".to_string()));
+        }
+    };
+
+    let validation_state = validation.read().clone();
+
+    rsx! {
+        div {
+            style: "flex: 1; display: flex; flex-direction: column; gap: 10px; min-height: 0;",
+
+            div {
+                style: "flex: 1; display: flex; flex-direction: column; min-height: 0;",
+
+                div {
+                    style: "display: flex; align-items: center; gap: 10px; margin-bottom: 5px;",
+
+                    h3 {
+                        style: "font-size: 16px; color: #2c3e50; margin: 0;",
+                        "Schema"
+                    }
+
+                    button {
+                        style: "padding: 4px 8px; background-color: #3498db; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                        title: "Infer a draft 2020-12 schema that accepts every document in the input",
+                        onclick: infer,
+                        "Infer from input"
+                    }
+
+                    button {
+                        style: "padding: 4px 8px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                        onclick: open_schema,
+                        "Open Schema"
+                    }
+
+                    if !schema.read().is_empty() {
+                        button {
+                            style: "padding: 4px 8px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                            onclick: copy_schema,
+                            "📋 Copy"
+                        }
+                    }
+                }
+
+                if let Some(message) = infer_error.read().as_ref() {
+                    div {
+                        style: "margin-bottom: 5px; font-size: 13px; color: #c62828;",
+                        "{message}"
+                    }
+                }
+
+                textarea {
+                    style: "flex: 1; width: 100%; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 13px; font-family: monospace; resize: none; box-sizing: border-box; white-space: pre; overflow-wrap: normal;",
+                    placeholder: "Paste a JSON Schema, or infer one from the sample documents in the input...",
+                    spellcheck: false,
+                    value: "{schema.read()}",
+                    oninput: move |event| schema.set(event.value())
+                }
+            }
+
+            div {
+                style: "flex: 1; display: flex; flex-direction: column; min-height: 0;",
+
+                h3 {
+                    style: "font-size: 16px; margin-bottom: 5px; color: #2c3e50; margin-top: 0;",
+                    "Validation"
+                }
+
+                div {
+                    style: "flex: 1; overflow: auto; min-height: 0; padding: 8px; border: 1px solid #bdc3c7; border-radius: 4px; background-color: #f8f9fa; font-family: monospace; font-size: 13px;",
+
+                    match &validation_state {
+                        None => rsx! {
+                            div { style: "color: #7f8c8d;", "Validating..." }
+                        },
+                        Some(None) => rsx! {
+                            div { style: "color: #7f8c8d;", "Provide a schema and one or more documents to validate them." }
+                        },
+                        Some(Some(ValidationState::SchemaSyntax(JsonError::Syntax { line, column, message }))) => rsx! {
+                            div { style: "color: #c62828;", "Schema, line {line}, column {column}: {message}" }
+                        },
+                        Some(Some(ValidationState::InputSyntax(JsonError::Syntax { line, column, message }))) => rsx! {
+                            div { style: "color: #c62828;", "Input, line {line}, column {column}: {message}" }
+                        },
+                        Some(Some(ValidationState::InvalidSchema(err))) => rsx! {
+                            div { style: "color: #c62828;", "{err}" }
+                        },
+                        Some(Some(ValidationState::Done { documents, violations })) if violations.is_empty() => rsx! {
+                            div {
+                                style: "color: #2e7d32;",
+                                if *documents == 1 { "Valid" } else { "All {documents} documents are valid" }
+                            }
+                        },
+                        Some(Some(ValidationState::Done { documents, violations })) => rsx! {
+                            div {
+                                style: "padding-bottom: 6px; margin-bottom: 4px; border-bottom: 1px solid #bdc3c7; color: #c62828;",
+                                "{violations.len()} violations"
+                            }
+
+                            for (index, (document, violation)) in violations.iter().take(VIOLATION_LIMIT).enumerate() {
+                                div {
+                                    key: "{index}",
+                                    style: "display: flex; gap: 10px; padding: 4px 0; border-bottom: 1px solid #ecf0f1;",
+
+                                    span {
+                                        style: "flex-shrink: 0; color: #2980b9;",
+                                        title: "Failed keyword: {violation.schema_path}",
+                                        if *documents > 1 { "#{document} " }
+                                        if violation.instance_path.is_empty() { "/" } else { "{violation.instance_path}" }
+                                    }
+                                    span {
+                                        style: "color: #2c3e50; overflow-wrap: anywhere;",
+                                        "{violation.message}"
+                                    }
+                                }
+                            }
+                            if violations.len() > VIOLATION_LIMIT {
+                                div {
+                                    style: "padding-top: 6px; color: #7f8c8d;",
+                                    "Showing the first {VIOLATION_LIMIT} of {violations.len()} violations"
+                                }
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Outcome of comparing the two documents, or which side failed to parse
+#[derive(Clone)]
+enum DiffState {
+    LeftError(JsonError),
+    RightError(JsonError),
+    Done(Vec<DiffEntry>),
+}
+
+#[component]
+fn DiffPanel(left: Signal<String>, status: Signal<Option<String>>) -> Element {
+    let mut right = use_signal(String::new);
+    let mut ignore_array_order = use_signal(|| false);
+    let mut tolerance = use_signal(|| "0".to_string());
+
+    let options = use_memo(move || {
+        let numeric_tolerance = tolerance.read().trim().parse::<f64>().ok().filter(|value| *value >= 0.0 && value.is_finite())?;
+        Some(DiffOptions { ignore_array_order: ignore_array_order(), numeric_tolerance })
+    });
+
+    // Both sides are re-parsed and compared on a blocking task whenever an input or option changes
+    let diff = use_resource(move || async move {
+        let (left_text, right_text) = (left(), right());
+        let options = options()?;
+        if left_text.trim().is_empty() || right_text.trim().is_empty() {
+            return None;
+        }
+        tokio::task::spawn_blocking(move || {
+            let left_value = match JsonLogic::parse(&left_text) {
+                Ok(value) => value,
+                Err(err) => return DiffState::LeftError(err),
+            };
+            match JsonLogic::parse(&right_text) {
+                Ok(right_value) => DiffState::Done(JsonDiffLogic::diff(&left_value, &right_value, options)),
+                Err(err) => DiffState::RightError(err),
+            }
+        })
+        .await
+        .ok()
+    });
+
+    let open_right = move |_| async move {
+        let Some(handle) = AsyncFileDialog::new().add_filter("JSON", &["json"]).pick_file().await else {
+            return;
+        };
+        let bytes = handle.read().await;
+        right.set(String::from_utf8_lossy(&bytes).into_owned());
+        status.set(Some(format!("Loaded {} ({})", handle.file_name(), Base64Logic::format_size(bytes.len() as u64))));
+    };
+
+    let patch_text = move || match &*diff.read() {
+        Some(Some(DiffState::Done(entries))) => Some(JsonLogic::to_pretty(&JsonDiffLogic::to_patch(entries), JsonIndent::TwoSpaces)),
+        _ => None,
+    };
+
+    let copy_patch = move |_| {
+        let Some(text) = patch_text() else {
+            return;
+        };
+        if let Ok(mut clipboard) = Clipboard::new() {
+            let _ = clipboard.set_text(text);
+            status.set(Some("Copied JSON Patch to clipboard".to_string()));
+        }
+    };
+
+    let save_patch = move |_| async move {
+        let Some(text) = patch_text() else {
+            return;
+        };
+        let Some(handle) = AsyncFileDialog::new().add_filter("JSON Patch", &["json"]).set_file_name("diff.patch.json").save_file().await else {
+            return;
+        };
+        match std::fs::write(handle.path(), text) {
+            Ok(()) => status.set(Some(format!("Saved {}", handle.path().display()))),
+            Err(err) => status.set(Some(format!("Failed to save patch: {}", err))),
+        }
+    };
+
+    let diff_state = diff.read().clone();
+    let has_patch = matches!(diff_state, Some(Some(DiffState::Done(_))));
+
+    rsx! {
+        div {
+            style: "flex: 1; display: flex; flex-direction: column; gap: 10px; min-height: 0;",
+
+            // Both documents side by side
+            div {
+                style: "flex: 1; display: flex; gap: 20px; min-height: 0;",
+
+                div {
+                    style: "flex: 1; display: flex; flex-direction: column; min-height: 0;",
+
+                    h3 {
+                        style: "font-size: 16px; margin-bottom: 5px; color: #2c3e50; margin-top: 0;",
+                        "Left (before)"
+                    }
+
+                    textarea {
+                        style: "flex: 1; width: 100%; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 13px; font-family: monospace; resize: none; box-sizing: border-box; white-space: pre; overflow-wrap: normal;",
+                        placeholder: "Paste the original JSON, e.g. the staging response...",
+                        spellcheck: false,
+                        value: "{left.read()}",
+                        oninput: move |event| left.set(event.value())
+                    }
+                }
+
+                div {
+                    style: "flex: 1; display: flex; flex-direction: column; min-height: 0;",
+
+                    div {
+                        style: "display: flex; align-items: center; gap: 10px; margin-bottom: 5px;",
+
+                        h3 {
+                            style: "font-size: 16px; color: #2c3e50; margin: 0;",
+                            "Right (after)"
+                        }
+
+                        button {
+                            style: "padding: 4px 8px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                            onclick: open_right,
+                            "Open File"
+                        }
+                    }
+
+                    textarea {
+                        style: "flex: 1; width: 100%; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 13px; font-family: monospace; resize: none; box-sizing: border-box; white-space: pre; overflow-wrap: normal;",
+                        placeholder: "Paste the JSON to compare against, e.g. the prod response...",
+                        spellcheck: false,
+                        value: "{right.read()}",
+                        oninput: move |event| right.set(event.value())
+                    }
+                }
+            }
+
+            // Comparison options and patch export
+            div {
+                style: "display: flex; gap: 10px; align-items: center; flex-shrink: 0;",
+
+                label {
+                    style: "display: flex; gap: 6px; align-items: center; font-size: 14px; color: #2c3e50; cursor: pointer;",
+
+                    input {
+                        r#type: "checkbox",
+                        checked: ignore_array_order(),
+                        onchange: move |event| ignore_array_order.set(event.checked())
+                    }
+                    "Ignore array order"
+                }
+
+                label {
+                    style: "display: flex; gap: 6px; align-items: center; font-size: 14px; color: #2c3e50;",
+                    "Numeric tolerance"
+
+                    input {
+                        style: if options().is_some() {
+                            "width: 90px; padding: 6px 8px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; font-family: monospace;"
+                        } else {
+                            "width: 90px; padding: 6px 8px; border: 1px solid #f44336; border-radius: 4px; font-size: 14px; font-family: monospace;"
+                        },
+                        value: "{tolerance.read()}",
+                        oninput: move |event| tolerance.set(event.value())
+                    }
+                }
+
+                button {
+                    style: "padding: 8px 16px; background-color: #3498db; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                    disabled: !has_patch,
+                    onclick: copy_patch,
+                    "📋 Copy JSON Patch"
+                }
+
+                button {
+                    style: "padding: 8px 16px; background-color: #27ae60; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                    disabled: !has_patch,
+                    onclick: save_patch,
+                    "Save JSON Patch"
+                }
+            }
+
+            // Differences
+            div {
+                style: "flex: 1; overflow: auto; min-height: 0; padding: 8px; border: 1px solid #bdc3c7; border-radius: 4px; background-color: #f8f9fa; font-family: monospace; font-size: 13px;",
+
+                match &diff_state {
+                    _ if options().is_none() => rsx! {
+                        div { style: "color: #c62828;", "Numeric tolerance must be a non-negative number" }
+                    },
+                    None => rsx! {
+                        div { style: "color: #7f8c8d;", "Comparing..." }
+                    },
+                    Some(None) => rsx! {
+                        div { style: "color: #7f8c8d;", "Paste or open a JSON document on each side to compare them." }
+                    },
+                    Some(Some(DiffState::LeftError(JsonError::Syntax { line, column, message }))) => rsx! {
+                        div { style: "color: #c62828;", "Left, line {line}, column {column}: {message}" }
+                    },
+                    Some(Some(DiffState::RightError(JsonError::Syntax { line, column, message }))) => rsx! {
+                        div { style: "color: #c62828;", "Right, line {line}, column {column}: {message}" }
+                    },
+                    Some(Some(DiffState::Done(entries))) if entries.is_empty() => rsx! {
+                        div { style: "color: #2e7d32;", "No differences" }
+                    },
+                    Some(Some(DiffState::Done(entries))) => {
+                        let (added, removed, changed) = JsonDiffLogic::summary(entries);
+                        rsx! {
+                            div {
+                                style: "padding-bottom: 6px; margin-bottom: 4px; border-bottom: 1px solid #bdc3c7; color: #2c3e50;",
+                                "{added} added · {removed} removed · {changed} changed"
+                            }
+
+                            for (index, entry) in entries.iter().take(DIFF_LIMIT).enumerate() {
+                                div {
+                                    key: "{index}",
+                                    style: "display: flex; gap: 10px; padding: 4px 0; border-bottom: 1px solid #ecf0f1; white-space: nowrap;",
+
+                                    span {
+                                        style: match entry.kind {
+                                            DiffKind::Added => "flex-shrink: 0; width: 70px; color: #27ae60; font-weight: 600;",
+                                            DiffKind::Removed => "flex-shrink: 0; width: 70px; color: #c0392b; font-weight: 600;",
+                                            DiffKind::Changed => "flex-shrink: 0; width: 70px; color: #e67e22; font-weight: 600;",
+                                        },
+                                        "{entry.kind}"
+                                    }
+                                    span {
+                                        style: "flex-shrink: 0; color: #2980b9;",
+                                        "{entry.path}"
+                                    }
+                                    span {
+                                        style: "color: #2c3e50; overflow: hidden; text-overflow: ellipsis;",
+                                        {match (&entry.left, &entry.right) {
+                                            (Some(before), Some(after)) => format!("{} → {}", preview(before, 100), preview(after, 100)),
+                                            (Some(before), None) => preview(before, 200),
+                                            (None, Some(after)) => preview(after, 200),
+                                            (None, None) => String::new(),
+                                        }}
+                                    }
+                                }
+                            }
+                            if entries.len() > DIFF_LIMIT {
+                                div {
+                                    style: "padding-top: 6px; color: #7f8c8d;",
+                                    "Showing the first {DIFF_LIMIT} of {entries.len()} differences"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Compact single-line rendering of a value, cut to `max_chars`
+///
+/// Serialization stops once enough bytes for `max_chars` characters are buffered,
+/// so previewing a large subtree costs no more than previewing a small one.
+fn preview(value: &Value, max_chars: usize) -> String {
+    let mut writer = BoundedWriter { bytes: Vec::new(), limit: max_chars.saturating_mul(4).saturating_add(4) };
+    let _ = serde_json::to_writer(&mut writer, value);
+    let text = String::from_utf8_lossy(&writer.bytes);
+    if text.chars().count() <= max_chars {
+        return text.into_owned();
+    }
+    let truncated: String = text.chars().take(max_chars).collect();
+    format!("{}…", truncated)
+}
+
+/// Collects up to `limit` bytes, then fails the write to abort serialization
+struct BoundedWriter {
+    bytes: Vec<u8>,
+    limit: usize,
+}
+
+impl std::io::Write for BoundedWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let room = self.limit - self.bytes.len();
+        if room == 0 {
+            return Err(std::io::Error::other("preview limit reached"));
+        }
+        let take = buf.len().min(room);
+        self.bytes.extend_from_slice(&buf[..take]);
+        Ok(take)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
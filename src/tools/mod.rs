@@ -7,6 +7,11 @@ pub mod qr_tool;
 pub mod distance_tool;
 pub mod system_design_tool;
 pub mod icon_tool;
+pub mod hash_tool;
+pub mod hmac_tool;
+pub mod url_tool;
+pub mod json_tool;
+pub mod converter_tool;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ToolType {
@@ -17,6 +22,11 @@ pub enum ToolType {
     Distance,
     SystemDesign,
     Icon,
+    Hash,
+    Hmac,
+    Url,
+    Json,
+    Converter,
 }
 
 impl Default for ToolType {
@@ -33,6 +43,11 @@ pub enum Tool {
     Distance(distance_tool::DistanceTool),
     SystemDesign(system_design_tool::SystemDesignTool),
     Icon(icon_tool::IconTool),
+    Hash(hash_tool::HashTool),
+    Hmac(hmac_tool::HmacTool),
+    Url(url_tool::UrlTool),
+    Json(json_tool::JsonTool),
+    Converter(converter_tool::ConverterTool),
 }
 
 impl Tool {
@@ -45,6 +60,11 @@ impl Tool {
             ToolType::Distance => Tool::Distance(distance_tool::DistanceTool::new()),
             ToolType::SystemDesign => Tool::SystemDesign(system_design_tool::SystemDesignTool::new()),
             ToolType::Icon => Tool::Icon(icon_tool::IconTool::new()),
+            ToolType::Hash => Tool::Hash(hash_tool::HashTool::new()),
+            ToolType::Hmac => Tool::Hmac(hmac_tool::HmacTool::new()),
+            ToolType::Url => Tool::Url(url_tool::UrlTool::new()),
+            ToolType::Json => Tool::Json(json_tool::JsonTool::new()),
+            ToolType::Converter => Tool::Converter(converter_tool::ConverterTool::new()),
         }
     }
 
@@ -57,6 +77,11 @@ impl Tool {
             Tool::Distance(tool) => tool.view(),
             Tool::SystemDesign(tool) => tool.view(),
             Tool::Icon(tool) => tool.view(),
+            Tool::Hash(tool) => tool.view(),
+            Tool::Hmac(tool) => tool.view(),
+            Tool::Url(tool) => tool.view(),
+            Tool::Json(tool) => tool.view(),
+            Tool::Converter(tool) => tool.view(),
         }
     }
 }
@@ -1,6 +1,67 @@
 #![allow(non_snake_case)]
 
 use dioxus::prelude::*;
+use arboard::{Clipboard, ImageData};
+use rfd::AsyncFileDialog;
+use std::borrow::Cow;
+use crate::logic::base64_logic::Base64Logic;
+use crate::logic::qr_logic::{DecodedQr, ErrorCorrection, QrError, QrLogic, QrMatrix, QrOptions};
+use crate::logic::qr_payload_logic::{ContactPayload, EventPayload, QrPayloadLogic, WifiPayload, WifiSecurity};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadKind {
+    Text,
+    Wifi,
+    Contact,
+    Email,
+    Sms,
+    Phone,
+    Location,
+    Event,
+}
+
+impl std::fmt::Display for PayloadKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PayloadKind::Text => write!(f, "Text / URL"),
+            PayloadKind::Wifi => write!(f, "Wi-Fi Network"),
+            PayloadKind::Contact => write!(f, "Contact (vCard)"),
+            PayloadKind::Email => write!(f, "Email"),
+            PayloadKind::Sms => write!(f, "SMS"),
+            PayloadKind::Phone => write!(f, "Phone Call"),
+            PayloadKind::Location => write!(f, "Location"),
+            PayloadKind::Event => write!(f, "Calendar Event"),
+        }
+    }
+}
+
+impl PayloadKind {
+    pub const ALL: [PayloadKind; 8] = [
+        PayloadKind::Text,
+        PayloadKind::Wifi,
+        PayloadKind::Contact,
+        PayloadKind::Email,
+        PayloadKind::Sms,
+        PayloadKind::Phone,
+        PayloadKind::Location,
+        PayloadKind::Event,
+    ];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QrMode {
+    Generate,
+    Decode,
+}
+
+impl std::fmt::Display for QrMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QrMode::Generate => write!(f, "Generate"),
+            QrMode::Decode => write!(f, "Decode"),
+        }
+    }
+}
 
 pub struct QrTool;
 
@@ -16,90 +77,908 @@ impl QrTool {
 
 #[component]
 pub fn QrToolView() -> Element {
+    let mut mode = use_signal(|| QrMode::Generate);
+
+    rsx! {
+        div {
+            style: "padding: 20px; height: 100%; display: flex; flex-direction: column; box-sizing: border-box; overflow-y: auto;",
+
+            h1 {
+                style: "font-size: 24px; margin-bottom: 15px; color: #2c3e50; margin-top: 0; flex-shrink: 0;",
+                "QR Code"
+            }
+
+            // Mode tabs
+            div {
+                style: "margin-bottom: 15px; display: flex; gap: 5px; flex-shrink: 0;",
+
+                for tab in [QrMode::Generate, QrMode::Decode] {
+                    button {
+                        style: if mode() == tab {
+                            "padding: 8px 16px; background-color: #76ABAE; color: #222831; border: none; border-radius: 4px; cursor: pointer; font-size: 14px; font-weight: 500;"
+                        } else {
+                            "padding: 8px 16px; background-color: #31363F; color: #EEEEEE; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;"
+                        },
+                        onclick: move |_| mode.set(tab),
+                        "{tab}"
+                    }
+                }
+            }
+
+            match mode() {
+                QrMode::Generate => rsx! { QrGeneratorView {} },
+                QrMode::Decode => rsx! { QrDecoderView {} },
+            }
+        }
+    }
+}
+
+#[component]
+fn QrGeneratorView() -> Element {
+    let defaults = QrOptions::default();
     let mut input = use_signal(String::new);
-    let mut status = use_signal(String::new);
+    let mut error_correction = use_signal(|| defaults.error_correction);
+    let mut quiet_zone = use_signal(|| defaults.quiet_zone);
+    let mut module_size = use_signal(|| defaults.module_size);
+    let mut foreground = use_signal(|| defaults.foreground.clone());
+    let mut background = use_signal(|| defaults.background.clone());
+    let mut preview_data = use_signal(String::new);
+    // What the preview shows, so exports match it even after the text is edited
+    let mut rendered = use_signal(|| None::<(QrMatrix, QrOptions)>);
+    let mut info = use_signal(String::new);
+    let mut error = use_signal(|| None::<String>);
+    let mut status = use_signal(|| None::<String>);
+    let mut payload_kind = use_signal(|| PayloadKind::Text);
+
+    let build = move || -> Result<(QrMatrix, QrOptions), QrError> {
+        let options = QrOptions {
+            error_correction: error_correction(),
+            quiet_zone: quiet_zone(),
+            module_size: module_size(),
+            foreground: foreground.read().clone(),
+            background: background.read().clone(),
+        };
+        let matrix = QrLogic::generate(&input.read(), options.error_correction)?;
+        Ok((matrix, options))
+    };
+
+    let mut render = move || {
+        let result = build().and_then(|(matrix, options)| {
+            let svg = QrLogic::render_svg(&matrix, &options)?;
+            Ok((matrix, options, svg))
+        });
+
+        status.set(None);
+        match result {
+            Ok((matrix, options, svg)) => {
+                let pixels = QrLogic::image_size(&matrix, &options);
+                preview_data.set(Base64Logic::data_uri("image/svg+xml", svg.as_bytes()));
+                info.set(format!(
+                    "Version {} ({}x{} modules), ECC {}, {}x{} pixels",
+                    matrix.version, matrix.width, matrix.width, matrix.error_correction, pixels, pixels
+                ));
+                rendered.set(Some((matrix, options)));
+                error.set(None);
+            }
+            Err(err) => {
+                preview_data.set(String::new());
+                rendered.set(None);
+                info.set(String::new());
+                error.set(Some(format!("{:?}", err)));
+            }
+        }
+    };
+
+    // Re-render with new options once a code has been generated
+    let mut refresh = move || {
+        if !preview_data.read().is_empty() {
+            render();
+        }
+    };
 
     let generate = move |_| {
-        if !input.read().is_empty() {
-            status.set(format!("QR Code would be generated for: '{}'", input.read()));
-        } else {
-            status.set("Please enter text to generate QR code".to_string());
+        render();
+    };
+
+    let save_png = move |_| async move {
+        let Some((matrix, options)) = rendered.read().clone() else {
+            return;
+        };
+        let png = QrLogic::render_image(&matrix, &options).and_then(|image| QrLogic::encode_png(&image));
+        match png {
+            Ok(bytes) => save_file(bytes, "PNG Image", "png", status, error).await,
+            Err(err) => error.set(Some(format!("{:?}", err))),
+        }
+    };
+
+    let save_svg = move |_| async move {
+        let Some((matrix, options)) = rendered.read().clone() else {
+            return;
+        };
+        let svg = QrLogic::render_svg(&matrix, &options);
+        match svg {
+            Ok(svg) => save_file(svg.into_bytes(), "SVG Image", "svg", status, error).await,
+            Err(err) => error.set(Some(format!("{:?}", err))),
+        }
+    };
+
+    let copy_image = move |_| {
+        let Some((matrix, options)) = rendered.read().clone() else {
+            return;
+        };
+        let image = QrLogic::render_image(&matrix, &options);
+        match image {
+            Ok(image) => {
+                let data = ImageData {
+                    width: image.width() as usize,
+                    height: image.height() as usize,
+                    bytes: Cow::Owned(image.into_raw()),
+                };
+                match Clipboard::new().and_then(|mut clipboard| clipboard.set_image(data)) {
+                    Ok(()) => {
+                        error.set(None);
+                        status.set(Some("QR code image copied to clipboard!".to_string()));
+                    }
+                    Err(_) => error.set(Some("Failed to copy image to clipboard".to_string())),
+                }
+            }
+            Err(err) => error.set(Some(format!("{:?}", err))),
+        }
+    };
+
+    let copy_svg = move |_| {
+        let Some((matrix, options)) = rendered.read().clone() else {
+            return;
+        };
+        match QrLogic::render_svg(&matrix, &options) {
+            Ok(svg) => match Clipboard::new().and_then(|mut clipboard| clipboard.set_text(svg)) {
+                Ok(()) => {
+                    error.set(None);
+                    status.set(Some("SVG markup copied to clipboard!".to_string()));
+                }
+                Err(_) => error.set(Some("Failed to copy SVG to clipboard".to_string())),
+            },
+            Err(err) => error.set(Some(format!("{:?}", err))),
+        }
+    };
+
+    let apply_payload = move |payload: Result<String, String>| {
+        match payload {
+            Ok(payload) => {
+                input.set(payload);
+                render();
+            }
+            Err(err) => error.set(Some(err)),
         }
     };
 
     let clear = move |_| {
         input.set(String::new());
-        status.set(String::new());
+        preview_data.set(String::new());
+        rendered.set(None);
+        info.set(String::new());
+        error.set(None);
+        status.set(None);
     };
 
     rsx! {
         div {
-            style: "padding: 20px; height: 100%; display: flex; flex-direction: column; box-sizing: border-box; overflow: hidden;",
-            
-            h1 {
-                style: "font-size: 24px; margin-bottom: 5px; color: #2c3e50; margin-top: 0; flex-shrink: 0;",
-                "QR Code Generator"
-            }
-            
-            p {
-                style: "font-size: 12px; margin-bottom: 20px; color: #95a5a6; flex-shrink: 0;",
-                "Note: QR code generation UI is a placeholder"
+            style: "flex: 1; display: flex; flex-direction: column; min-height: 0;",
+
+            // Payload template
+            div {
+                style: "margin-bottom: 15px; flex-shrink: 0;",
+
+                h3 {
+                    style: "font-size: 16px; margin-bottom: 5px; color: #2c3e50; margin-top: 0;",
+                    "Payload"
+                }
+
+                select {
+                    style: "padding: 8px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; background-color: white;",
+                    onchange: move |event| {
+                        if let Some(kind) = PayloadKind::ALL.into_iter().find(|kind| kind.to_string() == event.value()) {
+                            payload_kind.set(kind);
+                            error.set(None);
+                        }
+                    },
+
+                    for kind in PayloadKind::ALL {
+                        option {
+                            value: "{kind}",
+                            selected: payload_kind() == kind,
+                            "{kind}"
+                        }
+                    }
+                }
+
+                match payload_kind() {
+                    PayloadKind::Text => rsx! {},
+                    PayloadKind::Wifi => rsx! { WifiForm { on_build: apply_payload } },
+                    PayloadKind::Contact => rsx! { ContactForm { on_build: apply_payload } },
+                    PayloadKind::Email => rsx! { EmailForm { on_build: apply_payload } },
+                    PayloadKind::Sms => rsx! { SmsForm { on_build: apply_payload } },
+                    PayloadKind::Phone => rsx! { PhoneForm { on_build: apply_payload } },
+                    PayloadKind::Location => rsx! { LocationForm { on_build: apply_payload } },
+                    PayloadKind::Event => rsx! { EventForm { on_build: apply_payload } },
+                }
             }
-            
+
             // Input section
             div {
-                style: "margin-bottom: 20px; flex-shrink: 0;",
-                
+                style: "margin-bottom: 15px; flex-shrink: 0;",
+
                 h3 {
                     style: "font-size: 16px; margin-bottom: 5px; color: #2c3e50; margin-top: 0;",
-                    "Text to encode"
+                    if payload_kind() == PayloadKind::Text { "Text to encode" } else { "Encoded payload" }
                 }
-                
-                input {
-                    style: "width: calc(100% - 20px); padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; box-sizing: border-box;",
+
+                textarea {
+                    style: "width: calc(100% - 20px); height: 60px; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; font-family: monospace; resize: none; box-sizing: border-box;",
                     placeholder: "Enter text for QR code...",
                     value: "{input.read()}",
                     oninput: move |event| {
                         input.set(event.value());
-                        status.set(String::new());
+                        error.set(None);
+                    }
+                }
+            }
+
+            // Options
+            div {
+                style: "margin-bottom: 15px; display: flex; gap: 15px; flex-wrap: wrap; align-items: flex-end; flex-shrink: 0;",
+
+                div {
+                    h3 {
+                        style: "font-size: 14px; margin-bottom: 5px; color: #2c3e50; margin-top: 0;",
+                        "Error Correction"
+                    }
+
+                    select {
+                        style: "padding: 8px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; background-color: white;",
+                        onchange: move |event| {
+                            if let Some(level) = ErrorCorrection::from_letter(&event.value()) {
+                                error_correction.set(level);
+                                refresh();
+                            }
+                        },
+
+                        for level in ErrorCorrection::ALL {
+                            option {
+                                value: "{level}",
+                                selected: error_correction() == level,
+                                "{level}"
+                            }
+                        }
+                    }
+                }
+
+                div {
+                    h3 {
+                        style: "font-size: 14px; margin-bottom: 5px; color: #2c3e50; margin-top: 0;",
+                        "Quiet Zone"
+                    }
+
+                    input {
+                        r#type: "number",
+                        style: "width: 70px; padding: 8px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px;",
+                        value: "{quiet_zone()}",
+                        min: "0",
+                        max: "{QrLogic::MAX_QUIET_ZONE}",
+                        oninput: move |event| {
+                            if let Ok(value) = event.value().parse::<u32>()
+                                && value <= QrLogic::MAX_QUIET_ZONE
+                            {
+                                quiet_zone.set(value);
+                                refresh();
+                            }
+                        }
+                    }
+                }
+
+                div {
+                    h3 {
+                        style: "font-size: 14px; margin-bottom: 5px; color: #2c3e50; margin-top: 0;",
+                        "Module Size (px)"
+                    }
+
+                    input {
+                        r#type: "number",
+                        style: "width: 70px; padding: 8px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px;",
+                        value: "{module_size()}",
+                        min: "{QrLogic::MIN_MODULE_SIZE}",
+                        max: "{QrLogic::MAX_MODULE_SIZE}",
+                        oninput: move |event| {
+                            if let Ok(value) = event.value().parse::<u32>()
+                                && (QrLogic::MIN_MODULE_SIZE..=QrLogic::MAX_MODULE_SIZE).contains(&value)
+                            {
+                                module_size.set(value);
+                                refresh();
+                            }
+                        }
+                    }
+                }
+
+                div {
+                    h3 {
+                        style: "font-size: 14px; margin-bottom: 5px; color: #2c3e50; margin-top: 0;",
+                        "Foreground"
+                    }
+
+                    input {
+                        r#type: "color",
+                        style: "width: 50px; height: 36px; border: 1px solid #bdc3c7; border-radius: 4px; cursor: pointer;",
+                        value: "{foreground.read()}",
+                        oninput: move |event| {
+                            foreground.set(event.value());
+                            refresh();
+                        }
+                    }
+                }
+
+                div {
+                    h3 {
+                        style: "font-size: 14px; margin-bottom: 5px; color: #2c3e50; margin-top: 0;",
+                        "Background"
+                    }
+
+                    input {
+                        r#type: "color",
+                        style: "width: 50px; height: 36px; border: 1px solid #bdc3c7; border-radius: 4px; cursor: pointer;",
+                        value: "{background.read()}",
+                        oninput: move |event| {
+                            background.set(event.value());
+                            refresh();
+                        }
                     }
                 }
             }
-            
+
             // Buttons
             div {
-                style: "margin-bottom: 20px; display: flex; gap: 10px; flex-shrink: 0;",
-                
+                style: "margin-bottom: 15px; display: flex; gap: 10px; flex-shrink: 0;",
+
                 button {
                     style: "padding: 10px 20px; background-color: #3498db; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
                     onclick: generate,
                     "Generate QR Code"
                 }
-                
+
+                button {
+                    style: "padding: 10px 20px; background-color: #95a5a6; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                    onclick: clear,
+                    "Clear"
+                }
+            }
+
+            // Preview section
+            div {
+                style: "flex: 1; display: flex; flex-direction: column; min-height: 250px; overflow: hidden;",
+
+                div {
+                    style: "display: flex; align-items: center; gap: 10px; margin-bottom: 5px;",
+
+                    h3 {
+                        style: "font-size: 16px; color: #2c3e50; margin: 0;",
+                        "Preview"
+                    }
+
+                    if !preview_data.read().is_empty() {
+                        button {
+                            style: "padding: 4px 8px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                            onclick: copy_image,
+                            "📋 Copy Image"
+                        }
+
+                        button {
+                            style: "padding: 4px 8px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                            onclick: copy_svg,
+                            "📋 Copy SVG"
+                        }
+
+                        button {
+                            style: "padding: 4px 8px; background-color: #3498db; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                            onclick: save_png,
+                            "📥 Save PNG"
+                        }
+
+                        button {
+                            style: "padding: 4px 8px; background-color: #3498db; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                            onclick: save_svg,
+                            "📥 Save SVG"
+                        }
+                    }
+                }
+
+                div {
+                    style: "flex: 1; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; background-color: #f8f9fa; display: flex; align-items: center; justify-content: center; min-height: 0; overflow: auto;",
+
+                    if preview_data.read().is_empty() {
+                        span {
+                            style: "color: #95a5a6; font-size: 14px;",
+                            "QR code will appear here..."
+                        }
+                    } else {
+                        img {
+                            src: "{preview_data.read()}",
+                            alt: "QR Code Preview",
+                            style: "max-width: 100%; max-height: 100%;",
+                        }
+                    }
+                }
+
+                if !info.read().is_empty() {
+                    p {
+                        style: "margin-top: 5px; font-size: 12px; color: #95a5a6; margin-bottom: 0;",
+                        "{info.read()}"
+                    }
+                }
+            }
+
+            // Status message
+            if let Some(msg) = status.read().as_ref() {
+                div {
+                    style: "margin-top: 10px; padding: 10px; background-color: #e8f5e8; border: 1px solid #4caf50; border-radius: 4px; color: #2e7d32; font-size: 14px; flex-shrink: 0;",
+                    "{msg}"
+                }
+            }
+
+            // Error message
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    style: "margin-top: 10px; padding: 10px; background-color: #ffebee; border: 1px solid #f44336; border-radius: 4px; color: #c62828; font-size: 14px; flex-shrink: 0;",
+                    "{err}"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn QrDecoderView() -> Element {
+    let mut results = use_signal(Vec::<DecodedQr>::new);
+    let mut source = use_signal(String::new);
+    let mut error = use_signal(|| None::<String>);
+
+    let mut show = move |decoded: Result<Vec<DecodedQr>, QrError>, from: String| {
+        source.set(from);
+        match decoded {
+            Ok(decoded) => {
+                results.set(decoded);
+                error.set(None);
+            }
+            Err(err) => {
+                results.set(Vec::new());
+                error.set(Some(format!("{:?}", err)));
+            }
+        }
+    };
+
+    let open_file = move |_| async move {
+        let Some(handle) = AsyncFileDialog::new()
+            .add_filter("Images", &["png", "jpg", "jpeg", "gif", "bmp", "webp"])
+            .pick_file()
+            .await
+        else {
+            return;
+        };
+
+        let name = handle.file_name();
+        match std::fs::read(handle.path()) {
+            Ok(bytes) => show(QrLogic::decode_bytes(&bytes), name),
+            Err(err) => error.set(Some(format!("Failed to read file: {}", err))),
+        }
+    };
+
+    let paste_image = move |_| {
+        match Clipboard::new().and_then(|mut clipboard| clipboard.get_image()) {
+            Ok(image) => {
+                let decoded = QrLogic::decode_rgba(image.width as u32, image.height as u32, image.bytes.into_owned());
+                show(decoded, "Clipboard image".to_string());
+            }
+            Err(_) => error.set(Some("No image found in clipboard".to_string())),
+        }
+    };
+
+    let clear = move |_| {
+        results.set(Vec::new());
+        source.set(String::new());
+        error.set(None);
+    };
+
+    rsx! {
+        div {
+            style: "flex: 1; display: flex; flex-direction: column; min-height: 0;",
+
+            p {
+                style: "font-size: 14px; margin-top: 0; margin-bottom: 15px; color: #2c3e50; flex-shrink: 0;",
+                "Decode QR codes from screenshots or photos. Images never leave this machine."
+            }
+
+            // Buttons
+            div {
+                style: "margin-bottom: 15px; display: flex; gap: 10px; flex-shrink: 0;",
+
+                button {
+                    style: "padding: 10px 20px; background-color: #3498db; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                    onclick: open_file,
+                    "📂 Open Image"
+                }
+
+                button {
+                    style: "padding: 10px 20px; background-color: #2ecc71; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                    onclick: paste_image,
+                    "📋 Paste Image"
+                }
+
                 button {
                     style: "padding: 10px 20px; background-color: #95a5a6; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
                     onclick: clear,
                     "Clear"
                 }
             }
-            
-            // Status section
+
+            // Results section
             div {
-                style: "flex: 1; display: flex; flex-direction: column; overflow: hidden;",
-                
-                if !status.read().is_empty() {
+                style: "flex: 1; display: flex; flex-direction: column; gap: 10px; min-height: 0;",
+
+                if !source.read().is_empty() {
+                    h3 {
+                        style: "font-size: 16px; color: #2c3e50; margin: 0;",
+                        "Decoded from {source.read()}"
+                    }
+                }
+
+                if results.read().is_empty() && error.read().is_none() {
                     div {
-                        h3 {
-                            style: "font-size: 16px; margin-bottom: 5px; color: #2c3e50; margin-top: 0;",
-                            "Status"
+                        style: "padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; background-color: #f8f9fa; display: flex; align-items: center; justify-content: center; min-height: 100px;",
+                        span {
+                            style: "color: #95a5a6; font-size: 14px;",
+                            "Decoded content will appear here..."
+                        }
+                    }
+                }
+
+                for (index, decoded) in results.read().iter().enumerate() {
+                    DecodedResult { key: "{index}", decoded: decoded.clone() }
+                }
+            }
+
+            // Error message
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    style: "margin-top: 10px; padding: 10px; background-color: #ffebee; border: 1px solid #f44336; border-radius: 4px; color: #c62828; font-size: 14px; flex-shrink: 0;",
+                    "{err}"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn DecodedResult(decoded: DecodedQr) -> Element {
+    let content = decoded.content.clone();
+
+    let copy_to_clipboard = move |_| {
+        if let Ok(mut clipboard) = Clipboard::new() {
+            let _ = clipboard.set_text(&content);
+        }
+    };
+
+    rsx! {
+        div {
+            style: "display: flex; flex-direction: column; gap: 5px;",
+
+            div {
+                style: "display: flex; align-items: center; gap: 10px;",
+
+                span {
+                    style: "font-size: 12px; color: #95a5a6;",
+                    "Version {decoded.version}, ECC {decoded.error_correction}"
+                }
+
+                button {
+                    style: "padding: 4px 8px; background-color: #34495e; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 12px;",
+                    onclick: copy_to_clipboard,
+                    "📋 Copy"
+                }
+            }
+
+            textarea {
+                style: "width: calc(100% - 20px); height: 80px; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; font-family: monospace; background-color: #f8f9fa; resize: vertical; box-sizing: border-box;",
+                readonly: true,
+                value: "{decoded.content}"
+            }
+        }
+    }
+}
+
+async fn save_file(
+    bytes: Vec<u8>,
+    filter_name: &str,
+    extension: &str,
+    mut status: Signal<Option<String>>,
+    mut error: Signal<Option<String>>,
+) {
+    let Some(handle) = AsyncFileDialog::new()
+        .add_filter(filter_name, &[extension])
+        .set_file_name(format!("qrcode.{}", extension))
+        .save_file()
+        .await
+    else {
+        return;
+    };
+
+    match std::fs::write(handle.path(), bytes) {
+        Ok(()) => {
+            error.set(None);
+            status.set(Some(format!("Saved to {}", handle.path().display())));
+        }
+        Err(err) => error.set(Some(format!("Failed to save file: {}", err))),
+    }
+}
+
+#[component]
+fn PayloadField(label: &'static str, value: Signal<String>, placeholder: &'static str, input_type: Option<&'static str>) -> Element {
+    let mut value = value;
+
+    rsx! {
+        div {
+            style: "flex: 1; min-width: 200px;",
+
+            label {
+                style: "display: block; font-size: 13px; margin-bottom: 3px; color: #2c3e50;",
+                "{label}"
+            }
+
+            input {
+                r#type: input_type.unwrap_or("text"),
+                style: "width: 100%; padding: 8px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; box-sizing: border-box;",
+                placeholder: placeholder,
+                value: "{value.read()}",
+                oninput: move |event| value.set(event.value())
+            }
+        }
+    }
+}
+
+#[component]
+fn PayloadForm(on_build: EventHandler<()>, children: Element) -> Element {
+    rsx! {
+        div {
+            style: "margin-top: 10px; padding: 10px; border: 1px solid #bdc3c7; border-radius: 4px; background-color: #f8f9fa;",
+
+            div {
+                style: "display: flex; gap: 10px; flex-wrap: wrap; margin-bottom: 10px;",
+                {children}
+            }
+
+            button {
+                style: "padding: 8px 16px; background-color: #2ecc71; color: white; border: none; border-radius: 4px; cursor: pointer; font-size: 14px;",
+                onclick: move |_| on_build.call(()),
+                "Build Payload"
+            }
+        }
+    }
+}
+
+#[component]
+fn WifiForm(on_build: EventHandler<Result<String, String>>) -> Element {
+    let ssid = use_signal(String::new);
+    let password = use_signal(String::new);
+    let mut security = use_signal(|| WifiSecurity::Wpa);
+    let mut hidden = use_signal(|| false);
+
+    let build = move |_| {
+        let payload = WifiPayload {
+            ssid: ssid.read().clone(),
+            password: password.read().clone(),
+            security: security(),
+            hidden: hidden(),
+        };
+        on_build.call(QrPayloadLogic::wifi(&payload).map_err(|err| format!("{:?}", err)));
+    };
+
+    rsx! {
+        PayloadForm {
+            on_build: build,
+
+            PayloadField { label: "Network name (SSID)", value: ssid, placeholder: "Office-WiFi" }
+            PayloadField { label: "Password", value: password, placeholder: "Network password" }
+
+            div {
+                style: "flex: 1; min-width: 200px;",
+
+                label {
+                    style: "display: block; font-size: 13px; margin-bottom: 3px; color: #2c3e50;",
+                    "Security"
+                }
+
+                select {
+                    style: "width: 100%; padding: 8px; border: 1px solid #bdc3c7; border-radius: 4px; font-size: 14px; background-color: white;",
+                    onchange: move |event| {
+                        if let Some(selected) = WifiSecurity::ALL.into_iter().find(|s| s.to_string() == event.value()) {
+                            security.set(selected);
                         }
-                        p {
-                            style: "font-size: 14px; color: #2c3e50;",
-                            "{status.read()}"
+                    },
+
+                    for option_security in WifiSecurity::ALL {
+                        option {
+                            value: "{option_security}",
+                            selected: security() == option_security,
+                            "{option_security}"
                         }
                     }
                 }
+
+                label {
+                    style: "display: flex; gap: 5px; align-items: center; font-size: 13px; margin-top: 5px; color: #2c3e50;",
+                    input {
+                        r#type: "checkbox",
+                        checked: hidden(),
+                        onchange: move |event| hidden.set(event.checked())
+                    }
+                    "Hidden network"
+                }
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[component]
+fn ContactForm(on_build: EventHandler<Result<String, String>>) -> Element {
+    let first_name = use_signal(String::new);
+    let last_name = use_signal(String::new);
+    let organization = use_signal(String::new);
+    let title = use_signal(String::new);
+    let phone = use_signal(String::new);
+    let email = use_signal(String::new);
+    let url = use_signal(String::new);
+    let address = use_signal(String::new);
+    let note = use_signal(String::new);
+
+    let build = move |_| {
+        let payload = ContactPayload {
+            first_name: first_name.read().clone(),
+            last_name: last_name.read().clone(),
+            organization: organization.read().clone(),
+            title: title.read().clone(),
+            phone: phone.read().clone(),
+            email: email.read().clone(),
+            url: url.read().clone(),
+            address: address.read().clone(),
+            note: note.read().clone(),
+        };
+        on_build.call(QrPayloadLogic::vcard(&payload).map_err(|err| format!("{:?}", err)));
+    };
+
+    rsx! {
+        PayloadForm {
+            on_build: build,
+
+            PayloadField { label: "First name", value: first_name, placeholder: "Somchai" }
+            PayloadField { label: "Last name", value: last_name, placeholder: "Jaidee" }
+            PayloadField { label: "Organization", value: organization, placeholder: "Company" }
+            PayloadField { label: "Job title", value: title, placeholder: "Engineer" }
+            PayloadField { label: "Phone", value: phone, placeholder: "+66 81 234 5678", input_type: "tel" }
+            PayloadField { label: "Email", value: email, placeholder: "name@example.com", input_type: "email" }
+            PayloadField { label: "Website", value: url, placeholder: "https://example.com" }
+            PayloadField { label: "Address", value: address, placeholder: "Street, City" }
+            PayloadField { label: "Note", value: note, placeholder: "Optional note" }
+        }
+    }
+}
+
+#[component]
+fn EmailForm(on_build: EventHandler<Result<String, String>>) -> Element {
+    let to = use_signal(String::new);
+    let subject = use_signal(String::new);
+    let body = use_signal(String::new);
+
+    let build = move |_| {
+        let payload = QrPayloadLogic::mailto(&to.read(), &subject.read(), &body.read());
+        on_build.call(payload.map_err(|err| format!("{:?}", err)));
+    };
+
+    rsx! {
+        PayloadForm {
+            on_build: build,
+
+            PayloadField { label: "To", value: to, placeholder: "name@example.com", input_type: "email" }
+            PayloadField { label: "Subject", value: subject, placeholder: "Subject" }
+            PayloadField { label: "Body", value: body, placeholder: "Message" }
+        }
+    }
+}
+
+#[component]
+fn SmsForm(on_build: EventHandler<Result<String, String>>) -> Element {
+    let number = use_signal(String::new);
+    let message = use_signal(String::new);
+
+    let build = move |_| {
+        let payload = QrPayloadLogic::sms(&number.read(), &message.read());
+        on_build.call(payload.map_err(|err| format!("{:?}", err)));
+    };
+
+    rsx! {
+        PayloadForm {
+            on_build: build,
+
+            PayloadField { label: "Phone number", value: number, placeholder: "+66 81 234 5678", input_type: "tel" }
+            PayloadField { label: "Message", value: message, placeholder: "Prefilled message" }
+        }
+    }
+}
+
+#[component]
+fn PhoneForm(on_build: EventHandler<Result<String, String>>) -> Element {
+    let number = use_signal(String::new);
+
+    let build = move |_| {
+        on_build.call(QrPayloadLogic::tel(&number.read()).map_err(|err| format!("{:?}", err)));
+    };
+
+    rsx! {
+        PayloadForm {
+            on_build: build,
+
+            PayloadField { label: "Phone number", value: number, placeholder: "+66 81 234 5678", input_type: "tel" }
+        }
+    }
+}
+
+#[component]
+fn LocationForm(on_build: EventHandler<Result<String, String>>) -> Element {
+    let lat = use_signal(String::new);
+    let lon = use_signal(String::new);
+
+    let build = move |_| {
+        let payload = match (lat.read().trim().parse::<f64>(), lon.read().trim().parse::<f64>()) {
+            (Ok(lat), Ok(lon)) => QrPayloadLogic::geo(lat, lon).map_err(|err| format!("{:?}", err)),
+            _ => Err("Please enter valid numeric coordinates".to_string()),
+        };
+        on_build.call(payload);
+    };
+
+    rsx! {
+        PayloadForm {
+            on_build: build,
+
+            PayloadField { label: "Latitude", value: lat, placeholder: "13.7563" }
+            PayloadField { label: "Longitude", value: lon, placeholder: "100.5018" }
+        }
+    }
+}
+
+#[component]
+fn EventForm(on_build: EventHandler<Result<String, String>>) -> Element {
+    let summary = use_signal(String::new);
+    let location = use_signal(String::new);
+    let description = use_signal(String::new);
+    let start = use_signal(String::new);
+    let end = use_signal(String::new);
+
+    let build = move |_| {
+        let payload = EventPayload {
+            summary: summary.read().clone(),
+            location: location.read().clone(),
+            description: description.read().clone(),
+            start: start.read().clone(),
+            end: end.read().clone(),
+        };
+        on_build.call(QrPayloadLogic::event(&payload).map_err(|err| format!("{:?}", err)));
+    };
+
+    rsx! {
+        PayloadForm {
+            on_build: build,
+
+            PayloadField { label: "Title", value: summary, placeholder: "Sprint review" }
+            PayloadField { label: "Location", value: location, placeholder: "Meeting room" }
+            PayloadField { label: "Description", value: description, placeholder: "Agenda" }
+            PayloadField { label: "Start", value: start, placeholder: "", input_type: "datetime-local" }
+            PayloadField { label: "End", value: end, placeholder: "", input_type: "datetime-local" }
+        }
+    }
+}